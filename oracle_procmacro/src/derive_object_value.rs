@@ -0,0 +1,151 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2025 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+use proc_macro::TokenStream;
+use proc_macro2::{Literal, TokenStream as TokenStream2};
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{
+    parse_macro_input, Data, DataStruct, DeriveInput, Field, Fields, Lit, Meta, MetaList,
+    MetaNameValue, NestedMeta,
+};
+
+pub fn derive_object_value(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input);
+    match expand(input) {
+        Ok(output) => output.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let DeriveInput {
+        ident,
+        generics,
+        data,
+        ..
+    } = input;
+
+    if !generics.params.is_empty() || generics.where_clause.is_some() {
+        return Err(syn::Error::new(
+            generics.span(),
+            "ObjectValue cannot be derived for generic structures",
+        ));
+    }
+
+    let named = if let Data::Struct(DataStruct {
+        fields: Fields::Named(named),
+        ..
+    }) = data
+    {
+        named.named
+    } else {
+        return Err(syn::Error::new(
+            ident.span(),
+            "Expected a structure with named fields only",
+        ));
+    };
+
+    let mut get_fields = Vec::new();
+    let mut set_fields = Vec::new();
+    for field in named.iter() {
+        let attrs = Attributes::from_field(field)?;
+        let ident = field.ident.as_ref().unwrap();
+        if attrs.skip {
+            if attrs.rename.is_some() {
+                return Err(syn::Error::new(
+                    field.span(),
+                    "'skip' cannot be used with other attributes",
+                ));
+            }
+            get_fields.push(quote! {
+                #ident: ::std::default::Default::default(),
+            });
+            continue;
+        }
+        let name = Literal::string(
+            &attrs
+                .rename
+                .unwrap_or_else(|| ident.to_string().to_uppercase()),
+        );
+        get_fields.push(quote! {
+            #ident: obj.get(#name)?,
+        });
+        set_fields.push(quote! {
+            obj.set(#name, &self.#ident)?;
+        });
+    }
+
+    Ok(quote! {
+        impl oracle::sql_type::ObjectValue for #ident {
+            fn from_object(obj: &oracle::sql_type::Object) -> oracle::Result<Self> {
+                let result = #ident {
+                    #(#get_fields)*
+                };
+                ::std::result::Result::Ok(result)
+            }
+
+            fn set_to_object(&self, obj: &mut oracle::sql_type::Object) -> oracle::Result<()> {
+                #(#set_fields)*
+                ::std::result::Result::Ok(())
+            }
+        }
+    })
+}
+
+struct Attributes {
+    rename: Option<String>,
+    skip: bool,
+}
+
+impl Attributes {
+    fn from_field(field: &Field) -> syn::Result<Attributes> {
+        let mut rename: Option<String> = None;
+        let mut skip = false;
+
+        for option in field.attrs.iter() {
+            if !option.path.is_ident("object_value") {
+                continue;
+            }
+            let nested = match option.parse_meta()? {
+                Meta::List(MetaList { nested, .. }) => nested,
+                meta => {
+                    return Err(syn::Error::new(
+                        meta.span(),
+                        "Expected #[object_value(...)]",
+                    ))
+                }
+            };
+            for meta in nested.into_iter() {
+                match meta {
+                    NestedMeta::Meta(Meta::Path(ref path)) if path.is_ident("skip") => skip = true,
+                    NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                        ref path, ref lit, ..
+                    })) if path.is_ident("rename") => match lit {
+                        Lit::Str(lit) => rename = Some(lit.value()),
+                        _ => {
+                            return Err(syn::Error::new(
+                                lit.span(),
+                                "Expected a string literal for 'rename'",
+                            ))
+                        }
+                    },
+                    _ => return Err(syn::Error::new(meta.span(), "Unexpected attribute")),
+                }
+            }
+        }
+
+        Ok(Attributes { rename, skip })
+    }
+}
@@ -17,8 +17,8 @@ use proc_macro::TokenStream;
 use proc_macro2::{Group, Literal, Span, TokenStream as TokenStream2, TokenTree};
 use quote::quote;
 use syn::{
-    self, parse_macro_input, Data, DataStruct, DeriveInput, Field, Fields, Lit, Meta, MetaList,
-    MetaNameValue, NestedMeta, Path,
+    self, parse_macro_input, Data, DataStruct, DeriveInput, Field, Fields, GenericArgument, Ident,
+    Lit, Meta, MetaList, MetaNameValue, NestedMeta, Path, PathArguments, Type, TypePath,
 };
 
 pub fn derive_row_value(input: TokenStream) -> TokenStream {
@@ -36,19 +36,61 @@ pub fn derive_row_value(input: TokenStream) -> TokenStream {
                 let attrs = Attributes::from_field(field);
 
                 let ident = field.ident.as_ref().unwrap();
+                if attrs.skip {
+                    if attrs.rename.is_some()
+                        || attrs.with.is_some()
+                        || attrs.flatten
+                        || attrs.default
+                        || attrs.json
+                    {
+                        panic!("'skip' cannot be used with other attributes");
+                    }
+                    return quote! {
+                        #ident: ::std::default::Default::default(),
+                    };
+                }
+                if attrs.flatten {
+                    if attrs.rename.is_some() || attrs.with.is_some() || attrs.default || attrs.json
+                    {
+                        panic!(
+                            "'flatten' cannot be used with 'rename', 'with', 'default' or 'json'"
+                        );
+                    }
+                    return flatten_field(ident, &field.ty);
+                }
                 let param = Literal::string(
                     &attrs
                         .rename
                         .unwrap_or_else(|| ident.to_string().to_uppercase()),
                 );
+                if attrs.json && attrs.with.is_some() {
+                    panic!("'json' cannot be used with 'with'");
+                }
                 let get = if let Some(function_name) = attrs.with {
                     quote! { #function_name(row, #param) }
+                } else if attrs.json {
+                    quote! { oracle::__row_value_json(row, #param, |text| ::serde_json::from_str(text)) }
                 } else {
                     quote! { row.get(#param) }
                 };
 
-                quote! {
-                    #ident: #get?,
+                if attrs.default {
+                    quote! {
+                        #ident: match #get {
+                            ::std::result::Result::Ok(val) => val,
+                            ::std::result::Result::Err(err)
+                                if err.kind() == oracle::ErrorKind::NullValue
+                                    || err.kind() == oracle::ErrorKind::InvalidColumnName =>
+                            {
+                                ::std::default::Default::default()
+                            }
+                            ::std::result::Result::Err(err) => return ::std::result::Result::Err(err),
+                        },
+                    }
+                } else {
+                    quote! {
+                        #ident: #get?,
+                    }
                 }
             })
             .collect()
@@ -69,15 +111,62 @@ pub fn derive_row_value(input: TokenStream) -> TokenStream {
     output.into()
 }
 
+// Gets a field from the whole row by `RowValue::get` of the field type.
+// When the field type is `Option<T>`, `None` is set if `T::get` fails
+// due to a NULL value, which is typical for columns of outer-joined tables.
+fn flatten_field(ident: &Ident, ty: &Type) -> TokenStream2 {
+    if let Some(inner_ty) = option_inner_type(ty) {
+        quote! {
+            #ident: match <#inner_ty as oracle::RowValue>::get(row) {
+                ::std::result::Result::Ok(val) => ::std::option::Option::Some(val),
+                ::std::result::Result::Err(err) if err.kind() == oracle::ErrorKind::NullValue => {
+                    ::std::option::Option::None
+                }
+                ::std::result::Result::Err(err) => return ::std::result::Result::Err(err),
+            },
+        }
+    } else {
+        quote! {
+            #ident: <#ty as oracle::RowValue>::get(row)?,
+        }
+    }
+}
+
+fn option_inner_type(ty: &Type) -> Option<&Type> {
+    let path = match ty {
+        Type::Path(TypePath { qself: None, path }) => path,
+        _ => return None,
+    };
+    let segment = path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 struct Attributes {
     rename: Option<String>,
     with: Option<Path>,
+    flatten: bool,
+    default: bool,
+    skip: bool,
+    json: bool,
 }
 
 impl Attributes {
     fn from_field(field: &Field) -> Attributes {
         let mut rename: Option<String> = None;
         let mut with: Option<Path> = None;
+        let mut flatten = false;
+        let mut default = false;
+        let mut skip = false;
+        let mut json = false;
 
         for option in field.attrs.iter() {
             match option.parse_meta().unwrap() {
@@ -85,7 +174,15 @@ impl Attributes {
                     if path.to_token_stream().to_string() == "row_value" =>
                 {
                     for meta in nested.into_iter() {
-                        if let NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                        if let NestedMeta::Meta(Meta::Path(ref path)) = meta {
+                            match path.to_token_stream().to_string().as_str() {
+                                "flatten" => flatten = true,
+                                "default" => default = true,
+                                "skip" => skip = true,
+                                "json" => json = true,
+                                attr => panic!("Unexpected attribute: '{}'", attr),
+                            }
+                        } else if let NestedMeta::Meta(Meta::NameValue(MetaNameValue {
                             ref path,
                             lit: Lit::Str(ref lit),
                             ..
@@ -111,7 +208,14 @@ impl Attributes {
             }
         }
 
-        Attributes { rename, with }
+        Attributes {
+            rename,
+            with,
+            flatten,
+            default,
+            skip,
+            json,
+        }
     }
 }
 
@@ -0,0 +1,212 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2025 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+use proc_macro::TokenStream;
+use proc_macro2::{Literal, TokenStream as TokenStream2};
+use quote::quote;
+use syn::parse::ParseStream;
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, Data, DataEnum, DeriveInput, Fields, Ident, Lit, Token, Variant};
+
+#[derive(PartialEq)]
+enum Value {
+    Str(String),
+    Int(i64),
+}
+
+pub fn derive_sql_enum(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input);
+    match expand(input) {
+        Ok(output) => output.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let DeriveInput { ident, data, .. } = input;
+
+    let variants = if let Data::Enum(DataEnum { variants, .. }) = data {
+        variants
+    } else {
+        return Err(syn::Error::new(
+            ident.span(),
+            "Expected an enum with unit variants only",
+        ));
+    };
+
+    let mut values: Vec<(&Variant, Value)> = Vec::new();
+    for variant in variants.iter() {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new(
+                variant.span(),
+                "Expected an enum with unit variants only",
+            ));
+        }
+        let value =
+            variant_value(variant)?.unwrap_or_else(|| Value::Str(variant.ident.to_string()));
+        if let Some((other, _)) = values.iter().find(|(_, v)| *v == value) {
+            return Err(syn::Error::new(
+                variant.span(),
+                format!(
+                    "The value of {} is same with {}",
+                    variant.ident, other.ident
+                ),
+            ));
+        }
+        values.push((variant, value));
+    }
+    let is_int = match values.first() {
+        Some((_, Value::Int(_))) => true,
+        Some((_, Value::Str(_))) => false,
+        None => {
+            return Err(syn::Error::new(
+                ident.span(),
+                "Expected an enum with at least one variant",
+            ))
+        }
+    };
+
+    let mut to_values = Vec::new();
+    let mut from_values = Vec::new();
+    for (variant, value) in values.iter() {
+        let lit = match value {
+            Value::Str(s) if !is_int => Literal::string(s),
+            Value::Int(i) if is_int => Literal::i64_suffixed(*i),
+            _ => {
+                return Err(syn::Error::new(
+                    variant.span(),
+                    "All variants must have values of the same type: strings or integers",
+                ))
+            }
+        };
+        let variant = &variant.ident;
+        to_values.push(quote! { #ident::#variant => #lit, });
+        from_values.push(quote! { #lit => ::std::result::Result::Ok(#ident::#variant), });
+    }
+
+    let (value_type, ref_type, get_value, match_value): (
+        TokenStream2,
+        TokenStream2,
+        TokenStream2,
+        TokenStream2,
+    ) = if is_int {
+        (
+            quote! { i64 },
+            quote! { i64 },
+            quote! { val.get::<i64>()? },
+            quote! { value },
+        )
+    } else {
+        (
+            quote! { &'static str },
+            quote! { &str },
+            quote! { val.get::<::std::string::String>()? },
+            quote! { value.as_str() },
+        )
+    };
+
+    let name = Literal::string(&ident.to_string());
+    let output = quote! {
+        impl oracle::sql_type::FromSql for #ident {
+            fn from_sql(val: &oracle::SqlValue) -> oracle::Result<Self> {
+                let value = #get_value;
+                match #match_value {
+                    #(#from_values)*
+                    _ => ::std::result::Result::Err(oracle::Error::new(
+                        oracle::ErrorKind::ParseError,
+                        ::std::format!("unknown value {:?} for {}", value, #name),
+                    )),
+                }
+            }
+        }
+
+        impl oracle::sql_type::ToSqlNull for #ident {
+            fn oratype_for_null(
+                conn: &oracle::Connection,
+            ) -> oracle::Result<oracle::sql_type::OracleType> {
+                <#ref_type as oracle::sql_type::ToSqlNull>::oratype_for_null(conn)
+            }
+        }
+
+        impl oracle::sql_type::ToSql for #ident {
+            fn oratype(
+                &self,
+                conn: &oracle::Connection,
+            ) -> oracle::Result<oracle::sql_type::OracleType> {
+                let value: #value_type = match self {
+                    #(#to_values)*
+                };
+                oracle::sql_type::ToSql::oratype(&value, conn)
+            }
+
+            fn to_sql(&self, val: &mut oracle::SqlValue) -> oracle::Result<()> {
+                let value: #value_type = match self {
+                    #(#to_values)*
+                };
+                oracle::sql_type::ToSql::to_sql(&value, val)
+            }
+        }
+    };
+    Ok(output)
+}
+
+fn variant_value(variant: &Variant) -> syn::Result<Option<Value>> {
+    let mut value = None;
+    for attr in variant.attrs.iter() {
+        if attr.path.is_ident("sql_enum") {
+            value = Some(attr.parse_args_with(parse_value)?);
+        }
+    }
+    Ok(value)
+}
+
+// Parses `value = "string"`, `value = integer` or `value = -integer`.
+fn parse_value(input: ParseStream) -> syn::Result<Value> {
+    let name: Ident = input.parse()?;
+    if name != "value" {
+        return Err(syn::Error::new(
+            name.span(),
+            format!("Unexpected attribute: '{}'", name),
+        ));
+    }
+    input.parse::<Token![=]>()?;
+    let minus = input.parse::<Option<Token![-]>>()?;
+    let lit: Lit = input.parse()?;
+    let value = match lit {
+        Lit::Str(lit) if minus.is_none() => Value::Str(lit.value()),
+        Lit::Int(lit) => {
+            let digits = if minus.is_some() {
+                format!("-{}", lit.base10_digits())
+            } else {
+                lit.base10_digits().to_string()
+            };
+            Value::Int(
+                digits
+                    .parse()
+                    .map_err(|_| syn::Error::new(lit.span(), "Expected an i64 value"))?,
+            )
+        }
+        lit => {
+            return Err(syn::Error::new(
+                lit.span(),
+                "Expected a string or integer value",
+            ))
+        }
+    };
+    input.parse::<Option<Token![,]>>()?;
+    if !input.is_empty() {
+        return Err(input.error("Unexpected tokens after the value"));
+    }
+    Ok(value)
+}
@@ -21,7 +21,9 @@
 
 use proc_macro::TokenStream;
 
+mod derive_object_value;
 mod derive_row_value;
+mod derive_sql_enum;
 mod remove_stmt_lifetime;
 
 #[doc = include_str!("../docs/row_value.md")]
@@ -30,6 +32,18 @@ pub fn derive_row_value(input: TokenStream) -> TokenStream {
     derive_row_value::derive_row_value(input)
 }
 
+#[doc = include_str!("../docs/object_value.md")]
+#[proc_macro_derive(ObjectValue, attributes(object_value))]
+pub fn derive_object_value(input: TokenStream) -> TokenStream {
+    derive_object_value::derive_object_value(input)
+}
+
+#[doc = include_str!("../docs/sql_enum.md")]
+#[proc_macro_derive(SqlEnum, attributes(sql_enum))]
+pub fn derive_sql_enum(input: TokenStream) -> TokenStream {
+    derive_sql_enum::derive_sql_enum(input)
+}
+
 #[doc(hidden)]
 #[proc_macro_attribute]
 pub fn remove_stmt_lifetime(_args: TokenStream, input: TokenStream) -> TokenStream {
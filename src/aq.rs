@@ -39,6 +39,39 @@
 //! # Ok::<(), Error>(())
 //! ```
 //!
+//! ## Object type queue with struct payloads
+//!
+//! ```
+//! # use oracle::Error;
+//! # use oracle::test_util;
+//! # use oracle::aq;
+//! use oracle::sql_type::{Object, ObjectValue};
+//! # let conn = test_util::connect()?;
+//!
+//! #[derive(ObjectValue, Debug, PartialEq)]
+//! struct Book {
+//!     title: String,
+//!     authors: String,
+//!     price: f64,
+//! }
+//!
+//! let objtype = conn.object_type("UDT_BOOK")?;
+//! let mut queue = aq::Queue::<Object>::new(&conn, "BOOK_QUEUE", &objtype)?;
+//!
+//! let book = Book {
+//!     title: "The Caves of Steel".into(),
+//!     authors: "Isaac Asimov".into(),
+//!     price: 19.5,
+//! };
+//! let mut msg = aq::MsgProps::<Object>::new(&conn)?;
+//! msg.set_payload_from(&book, &objtype)?;
+//! queue.enqueue(&msg)?;
+//!
+//! let new_msg = queue.dequeue()?;
+//! assert_eq!(new_msg.payload_as::<Book>()?, book);
+//! # Ok::<(), Error>(())
+//! ```
+//!
 //! ## RAW data queue
 //!
 //! ```
@@ -104,6 +137,7 @@ use crate::chkerr;
 use crate::connection::Conn;
 use crate::sql_type::Object;
 use crate::sql_type::ObjectType;
+use crate::sql_type::ObjectValue;
 use crate::sql_type::OracleType;
 use crate::sql_type::Timestamp;
 use crate::to_rust_slice;
@@ -255,17 +289,56 @@ where
     }
 
     /// Dequeues a single message from the queue.
+    ///
+    /// This returns an error whose kind is [`ErrorKind::NoDataFound`](crate::ErrorKind::NoDataFound)
+    /// when no message is available within the [wait time](DeqOptions::set_wait).
+    /// Use [`Queue::try_dequeue`] to get `None` instead.
     pub fn dequeue(&self) -> Result<MsgProps<T>> {
+        self.try_dequeue()?.ok_or_else(Error::no_data_found)
+    }
+
+    /// Dequeues a single message from the queue if available.
+    ///
+    /// This returns `Ok(None)` when no message is available within the
+    /// [wait time](DeqOptions::set_wait).
+    ///
+    /// # Examples
+    ///
+    /// Dequeue messages for a consumer of a multi-consumer queue.
+    ///
+    /// ```no_run
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # use oracle::aq;
+    /// # use std::time::Duration;
+    /// # let conn = test_util::connect()?;
+    /// let mut queue = aq::Queue::<[u8]>::new(&conn, "MULTI_CONSUMER_QUEUE", &())?;
+    /// let deq_options = queue.deq_options()?;
+    /// deq_options.set_consumer_name("SUBSCRIBER1")?;
+    /// deq_options.set_wait(&Duration::from_secs(5))?;
+    /// while let Some(msg) = queue.try_dequeue()? {
+    ///     println!("{:?}", msg.payload()?);
+    /// }
+    /// // no message arrived in 5 seconds
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn try_dequeue(&self) -> Result<Option<MsgProps<T>>> {
         let mut props = ptr::null_mut();
         chkerr!(self.ctxt(), dpiQueue_deqOne(self.handle(), &mut props));
-        Ok(MsgProps::from_dpi_msg_props(
+        if props.is_null() {
+            return Ok(None);
+        }
+        Ok(Some(MsgProps::from_dpi_msg_props(
             self.conn.clone(),
             DpiMsgProps::new(props),
             self.payload_type.clone(),
-        ))
+        )))
     }
 
     /// Dequeues multiple messages from the queue.
+    ///
+    /// At most `max_size` messages are returned. The returned vector is empty
+    /// when no message is available within the [wait time](DeqOptions::set_wait).
     pub fn dequeue_many(&self, max_size: u32) -> Result<Vec<MsgProps<T>>> {
         let mut num_props = max_size;
         let mut handles = Vec::<DpiMsgProps>::with_capacity(max_size as usize);
@@ -777,6 +850,9 @@ impl DeqOptions {
 
     /// Set the time to wait for a message matching the search
     /// criteria.
+    ///
+    /// Zero means no wait. Durations longer than `u32::MAX` seconds
+    /// mean waiting forever.
     pub fn set_wait(&mut self, val: &Duration) -> Result<()> {
         let secs = val.as_secs().try_into().unwrap_or(u32::MAX);
         chkerr!(self.ctxt(), dpiDeqOptions_setWait(self.handle, secs));
@@ -1142,6 +1218,33 @@ where
         val.set(self)
     }
 
+    /// Sets the recipients of the message.
+    ///
+    /// This overrides the subscribers of a multi-consumer queue. Only the specified
+    /// consumers can dequeue the message by setting their names to
+    /// [`DeqOptions::set_consumer_name`].
+    pub fn set_recipients(&mut self, names: &[&str]) -> Result<()> {
+        let mut recipients = names
+            .iter()
+            .map(|name| {
+                let name = OdpiStr::new(name);
+                dpiMsgRecipient {
+                    name: name.ptr,
+                    nameLength: name.len,
+                }
+            })
+            .collect::<Vec<_>>();
+        chkerr!(
+            self.ctxt(),
+            dpiMsgProps_setRecipients(
+                self.handle(),
+                recipients.as_mut_ptr(),
+                recipients.len() as u32
+            )
+        );
+        Ok(())
+    }
+
     /// Sets the priority assigned to the message.
     ///
     /// A smaller number indicates a higher priority. The priority can
@@ -1152,6 +1255,28 @@ where
     }
 }
 
+impl MsgProps<Object> {
+    /// Returns the payload converted to a type implementing [`ObjectValue`].
+    ///
+    /// This is same with `T::from_object(&self.payload()?)`.
+    pub fn payload_as<T>(&self) -> Result<T>
+    where
+        T: ObjectValue,
+    {
+        T::from_object(&self.payload()?)
+    }
+
+    /// Sets the payload converted from a type implementing [`ObjectValue`].
+    ///
+    /// `objtype` must be the payload type of the queue.
+    pub fn set_payload_from<T>(&mut self, val: &T, objtype: &ObjectType) -> Result<()>
+    where
+        T: ObjectValue,
+    {
+        self.set_payload(&val.to_object(objtype)?)
+    }
+}
+
 impl<T> fmt::Debug for MsgProps<T>
 where
     T: Payload,
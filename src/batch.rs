@@ -32,10 +32,12 @@
 
 use crate::chkerr;
 use crate::error::DPI_ERR_BUFFER_SIZE_TOO_SMALL;
+use crate::observer;
 use crate::private;
 use crate::sql_type::OracleType;
 use crate::sql_type::ToSql;
 use crate::sql_value::BufferRowIndex;
+use crate::statement::log_errors_clause;
 use crate::statement::QueryParams;
 use crate::to_rust_str;
 use crate::Connection;
@@ -54,6 +56,8 @@ use std::mem::MaybeUninit;
 use std::os::raw::c_char;
 use std::ptr;
 use std::slice;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 #[cfg(test)]
 const MINIMUM_TYPE_LENGTH: u32 = 1;
@@ -121,9 +125,16 @@ pub struct BatchBuilder<'conn, 'sql> {
     batch_size: usize,
     with_batch_errors: bool,
     with_row_counts: bool,
+    commit_on_flush: bool,
     query_params: QueryParams,
+    bind_types: Vec<(Box<dyn BatchBindIndex + Send + 'sql>, OracleType)>,
+    log_errors: Option<(String, Option<String>)>,
+    auto_flush_bytes: Option<usize>,
+    on_flush: Option<Arc<FlushCallback>>,
 }
 
+type FlushCallback = dyn Fn(usize, Duration) + Send + Sync;
+
 impl<'conn, 'sql> BatchBuilder<'conn, 'sql> {
     pub(crate) fn new(
         conn: &'conn Connection,
@@ -136,7 +147,12 @@ impl<'conn, 'sql> BatchBuilder<'conn, 'sql> {
             batch_size,
             with_batch_errors: false,
             with_row_counts: false,
-            query_params: QueryParams::new(),
+            commit_on_flush: false,
+            query_params: conn.default_query_params(),
+            bind_types: Vec::new(),
+            log_errors: None,
+            auto_flush_bytes: None,
+            on_flush: None,
         }
     }
 
@@ -152,12 +168,155 @@ impl<'conn, 'sql> BatchBuilder<'conn, 'sql> {
         self
     }
 
+    /// Commits the transaction each time appended rows are sent to the server
+    ///
+    /// Rows are committed when they are sent automatically and when
+    /// [`Batch::execute`] is called, as if [`Connection::set_autocommit`](crate::Connection::set_autocommit)
+    /// were enabled only for this batch. This bounds the amount of redo and
+    /// undo for very large loads. Note that rows sent before an error are
+    /// committed already when the error occurs. When [`BatchBuilder::with_batch_errors`]
+    /// is set, rows without errors are committed.
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// # conn.execute("delete from TestTempTable", &[])?;
+    /// # conn.commit()?;
+    /// let sql_stmt = "insert into TestTempTable values(:1, :2)";
+    /// let mut batch = conn.batch(sql_stmt, 2).commit_on_flush(true).build()?;
+    /// batch.append_row(&[&1, &"first row"])?;
+    /// batch.append_row(&[&2, &"second row"])?; // sent and committed here
+    /// batch.append_row(&[&3, &"third row"])?;
+    /// conn.rollback()?;
+    /// assert_eq!(conn.query_row_as::<i32>("select count(*) from TestTempTable", &[])?, 2);
+    /// # conn.execute("delete from TestTempTable", &[])?;
+    /// # conn.commit()?;
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn commit_on_flush(&mut self, enable: bool) -> &mut BatchBuilder<'conn, 'sql> {
+        self.commit_on_flush = enable;
+        self
+    }
+
+    /// Declares the data type of a bind parameter before any row is appended.
+    ///
+    /// The bind type is determined by the first value set to the parameter
+    /// by default. When the first value is `None`, the type may not
+    /// fit values in later rows. Declaring the type avoids it.
+    /// This is same with calling [`Batch::set_type`] just after the batch is built.
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # use oracle::sql_type::OracleType;
+    /// # let conn = test_util::connect()?;
+    /// # conn.execute("delete from TestTempTable", &[])?;
+    /// let sql_stmt = "insert into TestTempTable values(:intCol, :stringCol)";
+    /// let mut batch = conn
+    ///     .batch(sql_stmt, 100)
+    ///     .bind_type(1, &OracleType::Number(10, 0))
+    ///     .bind_type("stringCol", &OracleType::Varchar2(100))
+    ///     .build()?;
+    /// batch.append_row(&[&1, &None::<String>])?;
+    /// batch.append_row(&[&2, &"two"])?;
+    /// batch.execute()?;
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn bind_type<I>(
+        &mut self,
+        bindidx: I,
+        oratype: &OracleType,
+    ) -> &mut BatchBuilder<'conn, 'sql>
+    where
+        I: BatchBindIndex + Send + 'sql,
+    {
+        self.bind_types.push((Box::new(bindidx), oratype.clone()));
+        self
+    }
+
+    /// Appends the DML error logging clause to the SQL statement.
+    ///
+    /// Rows which cannot be processed are logged into `table` instead of
+    /// making the batch fail. This is suitable for very large loads where
+    /// keeping [batch errors](Batch#error-handling) in memory isn't desirable.
+    /// See [`StatementBuilder::log_errors_into`](crate::StatementBuilder::log_errors_into).
+    pub fn log_errors_into(
+        &mut self,
+        table: &str,
+        tag: Option<&str>,
+    ) -> &mut BatchBuilder<'conn, 'sql> {
+        self.log_errors = Some((table.into(), tag.map(|s| s.into())));
+        self
+    }
+
+    /// Sends appended rows to the server when the total size of their
+    /// values reaches `bytes` in addition to when the number of rows reaches
+    /// the batch size.
+    ///
+    /// This keeps the amount of data sent at a time bounded when rows are
+    /// very wide. The size of a value is its length for character and binary
+    /// data and the size of the internal buffer for other data types.
+    ///
+    /// This has no effect when [`BatchBuilder::with_batch_errors`] is set
+    /// because rows aren't sent until [`Batch::execute`] is called.
+    pub fn auto_flush_bytes(&mut self, bytes: usize) -> &mut BatchBuilder<'conn, 'sql> {
+        self.auto_flush_bytes = Some(bytes);
+        self
+    }
+
+    /// Sets a callback called after appended rows are sent to the server successfully
+    ///
+    /// The arguments are the number of sent rows and time spent to execute
+    /// the statement. It is called both when rows are sent automatically and
+    /// when [`Batch::execute`] is called.
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    /// # let conn = test_util::connect()?;
+    /// # conn.execute("delete from TestTempTable", &[])?;
+    /// let flushed = Arc::new(AtomicUsize::new(0));
+    /// let counter = flushed.clone();
+    /// let sql_stmt = "insert into TestTempTable values(:1, :2)";
+    /// let mut batch = conn
+    ///     .batch(sql_stmt, 100)
+    ///     .auto_flush_bytes(100)
+    ///     .on_flush(move |rows, _elapsed| {
+    ///         counter.fetch_add(rows, Ordering::Relaxed);
+    ///     })
+    ///     .build()?;
+    /// for i in 0..10 {
+    ///     batch.append_row(&[&i, &"x".repeat(30)])?;
+    /// }
+    /// // Some rows were sent before all rows are appended.
+    /// assert!(flushed.load(Ordering::Relaxed) > 0);
+    /// batch.execute()?;
+    /// assert_eq!(flushed.load(Ordering::Relaxed), 10);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn on_flush<F>(&mut self, f: F) -> &mut BatchBuilder<'conn, 'sql>
+    where
+        F: Fn(usize, Duration) + Send + Sync + 'static,
+    {
+        self.on_flush = Some(Arc::new(f));
+        self
+    }
+
     pub fn build(&self) -> Result<Batch<'conn>> {
         let batch_size = u32::try_from(self.batch_size).map_err(|err| {
             Error::out_of_range(format!("too large batch size {}", self.batch_size)).add_source(err)
         })?;
         let conn = self.conn;
-        let sql = OdpiStr::new(self.sql);
+        let sql_with_log_errors;
+        let sql = if let Some((table, tag)) = &self.log_errors {
+            sql_with_log_errors = self.sql.to_string() + &log_errors_clause(table, tag.as_deref())?;
+            OdpiStr::new(&sql_with_log_errors)
+        } else {
+            OdpiStr::new(self.sql)
+        };
         let mut handle: *mut dpiStmt = ptr::null_mut();
         chkerr!(
             conn.ctxt(),
@@ -221,7 +380,7 @@ impl<'conn, 'sql> BatchBuilder<'conn, 'sql> {
                 ));
             }
         };
-        Ok(Batch {
+        let mut batch = Batch {
             conn,
             handle,
             statement_type: StatementType::from_enum(info.statementType),
@@ -233,8 +392,251 @@ impl<'conn, 'sql> BatchBuilder<'conn, 'sql> {
             batch_size,
             with_batch_errors: self.with_batch_errors,
             with_row_counts: self.with_row_counts,
+            commit_on_flush: self.commit_on_flush,
             query_params: self.query_params.clone(),
-        })
+            auto_flush_bytes: self.auto_flush_bytes,
+            pending_bytes: 0,
+            appended_rows: 0,
+            on_flush: self.on_flush.clone(),
+        };
+        for (bindidx, oratype) in &self.bind_types {
+            batch.set_type_internal(&**bindidx, oratype)?;
+        }
+        Ok(batch)
+    }
+}
+
+/// A builder to create a [`Batch`] which upserts rows by a generated `MERGE` statement
+///
+/// This is created by [`Connection::upsert`]. Values of key columns and then
+/// value columns are appended to the batch in order. Rows whose key
+/// columns match are updated. Otherwise, they are inserted.
+///
+/// Table and column names must be simple SQL identifiers such as `EMP`
+/// or `SCOTT.EMP`, or quoted identifiers such as `"Mixed Case"` or
+/// `"Scott"."Emp"`. They are checked to avoid SQL injection.
+///
+/// # Examples
+///
+/// ```
+/// # use oracle::Error;
+/// # use oracle::test_util;
+/// # let conn = test_util::connect()?;
+/// # conn.execute("delete from TestTempTable", &[])?;
+/// let mut batch = conn
+///     .upsert("TestTempTable", 100)
+///     .key_columns(["IntCol"])
+///     .value_columns(["StringCol"])
+///     .build()?;
+/// batch.append_row(&[&1, &"one"])?;
+/// batch.append_row(&[&2, &"two"])?;
+/// batch.execute()?;
+///
+/// // The row with IntCol = 1 is updated and the row with IntCol = 3 is inserted.
+/// batch.append_row(&[&1, &"ONE"])?;
+/// batch.append_row(&[&3, &"three"])?;
+/// batch.execute()?;
+///
+/// let sql = "select IntCol, StringCol from TestTempTable order by IntCol";
+/// let rows = conn.query_as::<(i32, String)>(sql, &[])?.collect::<Result<Vec<_>, _>>()?;
+/// assert_eq!(rows, vec![(1, "ONE".into()), (2, "two".into()), (3, "three".into())]);
+/// # Ok::<(), Error>(())
+/// ```
+pub struct UpsertBuilder<'conn> {
+    conn: &'conn Connection,
+    table: String,
+    key_columns: Vec<String>,
+    value_columns: Vec<String>,
+    batch_size: usize,
+    with_batch_errors: bool,
+    with_row_counts: bool,
+}
+
+impl<'conn> UpsertBuilder<'conn> {
+    pub(crate) fn new(
+        conn: &'conn Connection,
+        table: &str,
+        batch_size: usize,
+    ) -> UpsertBuilder<'conn> {
+        UpsertBuilder {
+            conn,
+            table: table.into(),
+            key_columns: Vec::new(),
+            value_columns: Vec::new(),
+            batch_size,
+            with_batch_errors: false,
+            with_row_counts: false,
+        }
+    }
+
+    /// Sets columns used to find existing rows
+    pub fn key_columns<I, S>(&mut self, columns: I) -> &mut UpsertBuilder<'conn>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.key_columns = columns
+            .into_iter()
+            .map(|s| s.as_ref().to_string())
+            .collect();
+        self
+    }
+
+    /// Sets columns updated for existing rows. Key columns must not be included.
+    /// Otherwise, [`build()`](Self::build) fails.
+    ///
+    /// When no value columns are set, existing rows are left as they are.
+    pub fn value_columns<I, S>(&mut self, columns: I) -> &mut UpsertBuilder<'conn>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.value_columns = columns
+            .into_iter()
+            .map(|s| s.as_ref().to_string())
+            .collect();
+        self
+    }
+
+    /// See ["Error Handling"](Batch#error-handling)
+    pub fn with_batch_errors(&mut self) -> &mut UpsertBuilder<'conn> {
+        self.with_batch_errors = true;
+        self
+    }
+
+    /// See ["Affected Rows"](Batch#affected-rows)
+    pub fn with_row_counts(&mut self) -> &mut UpsertBuilder<'conn> {
+        self.with_row_counts = true;
+        self
+    }
+
+    /// Returns the `MERGE` statement used by the batch
+    pub fn sql(&self) -> Result<String> {
+        upsert_sql(&self.table, &self.key_columns, &self.value_columns)
+    }
+
+    /// Creates a [`Batch`] executing the `MERGE` statement
+    pub fn build(&self) -> Result<Batch<'conn>> {
+        let sql = self.sql()?;
+        let mut builder = BatchBuilder::new(self.conn, &sql, self.batch_size);
+        if self.with_batch_errors {
+            builder.with_batch_errors();
+        }
+        if self.with_row_counts {
+            builder.with_row_counts();
+        }
+        builder.build()
+    }
+}
+
+fn upsert_sql(table: &str, key_columns: &[String], value_columns: &[String]) -> Result<String> {
+    check_identifier(table, true)?;
+    if key_columns.is_empty() {
+        return Err(Error::invalid_argument("no key columns"));
+    }
+    for col in key_columns.iter().chain(value_columns.iter()) {
+        check_identifier(col, false)?;
+    }
+    if let Some(col) = value_columns.iter().find(|col| {
+        key_columns
+            .iter()
+            .any(|key| normalize_identifier(key) == normalize_identifier(col))
+    }) {
+        return Err(Error::invalid_argument(format!(
+            "value column {} is also a key column",
+            col
+        )));
+    }
+    let columns = key_columns
+        .iter()
+        .chain(value_columns.iter())
+        .collect::<Vec<_>>();
+    let source = columns
+        .iter()
+        .enumerate()
+        .map(|(i, col)| format!(":{} {}", i + 1, col))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let on = key_columns
+        .iter()
+        .map(|col| format!("t.{0} = s.{0}", col))
+        .collect::<Vec<_>>()
+        .join(" AND ");
+    let mut sql = format!(
+        "MERGE INTO {} t USING (SELECT {} FROM DUAL) s ON ({})",
+        table, source, on
+    );
+    if !value_columns.is_empty() {
+        let set = value_columns
+            .iter()
+            .map(|col| format!("t.{0} = s.{0}", col))
+            .collect::<Vec<_>>()
+            .join(", ");
+        sql.push_str(" WHEN MATCHED THEN UPDATE SET ");
+        sql.push_str(&set);
+    }
+    let insert_columns = columns
+        .iter()
+        .map(|col| col.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let insert_values = columns
+        .iter()
+        .map(|col| format!("s.{}", col))
+        .collect::<Vec<_>>()
+        .join(", ");
+    sql.push_str(&format!(
+        " WHEN NOT MATCHED THEN INSERT ({}) VALUES ({})",
+        insert_columns, insert_values
+    ));
+    Ok(sql)
+}
+
+// Returns the name stored in the data dictionary. A simple name is
+// converted to upper case and a quoted name is used as it is.
+fn normalize_identifier(name: &str) -> String {
+    if name.starts_with('"') {
+        name[1..name.len() - 1].to_string()
+    } else {
+        name.to_uppercase()
+    }
+}
+
+// Checks whether `name` is an SQL identifier, which is a simple name
+// or a quoted name. A qualified name `schema.name`, whose parts may be
+// quoted, is allowed when `allow_qualified` is true.
+pub(crate) fn check_identifier(name: &str, allow_qualified: bool) -> Result<()> {
+    fn is_valid(part: &str) -> bool {
+        if part.len() >= 2 && part.starts_with('"') && part.ends_with('"') {
+            let inner = &part[1..part.len() - 1];
+            !inner.is_empty() && !inner.contains('"') && !inner.contains('\0')
+        } else {
+            let mut chars = part.chars();
+            chars.next().map_or(false, |c| c.is_alphabetic())
+                && chars.all(|c| c.is_alphanumeric() || matches!(c, '_' | '$' | '#'))
+        }
+    }
+    // the position of the first period outside of quotes
+    let mut quoted = false;
+    let period = name.char_indices().find_map(|(i, c)| match c {
+        '"' => {
+            quoted = !quoted;
+            None
+        }
+        '.' if !quoted => Some(i),
+        _ => None,
+    });
+    let valid = match period {
+        Some(pos) if allow_qualified => is_valid(&name[..pos]) && is_valid(&name[pos + 1..]),
+        _ => is_valid(name),
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::invalid_argument(format!(
+            "invalid SQL identifier {:?}",
+            name
+        )))
     }
 }
 
@@ -445,7 +847,14 @@ pub struct Batch<'conn> {
     batch_size: u32,
     with_batch_errors: bool,
     with_row_counts: bool,
+    commit_on_flush: bool,
     query_params: QueryParams,
+    auto_flush_bytes: Option<usize>,
+    // total size of values in appended rows not sent yet
+    pending_bytes: usize,
+    on_flush: Option<Arc<FlushCallback>>,
+    // number of rows appended since the batch was created
+    appended_rows: u64,
 }
 
 impl Batch<'_> {
@@ -472,11 +881,20 @@ impl Batch<'_> {
     }
 
     fn append_row_common(&mut self) -> Result<()> {
+        self.appended_rows += 1;
+        if self.auto_flush_bytes.is_some() {
+            for bind_value in &self.bind_values {
+                self.pending_bytes += bind_value.data_size();
+            }
+        }
         if self.with_batch_errors {
             self.set_batch_index(self.batch_index + 1);
         } else {
             self.set_batch_index(self.batch_index + 1);
-            if self.batch_index == self.batch_size {
+            let bytes_exceeded = self
+                .auto_flush_bytes
+                .map_or(false, |bytes| self.pending_bytes >= bytes);
+            if self.batch_index == self.batch_size || bytes_exceeded {
                 self.execute()?;
             }
         }
@@ -484,10 +902,12 @@ impl Batch<'_> {
     }
 
     pub fn execute(&mut self) -> Result<()> {
+        let start = Instant::now();
         let result = self.execute_sub();
         // reset all values to null regardless of the result
         let num_rows = self.batch_index;
         self.batch_index = 0;
+        self.pending_bytes = 0;
         for bind_value in &mut self.bind_values {
             for i in 0..num_rows {
                 bind_value.buffer_row_index = BufferRowIndex::Owned(i);
@@ -495,6 +915,11 @@ impl Batch<'_> {
             }
             bind_value.buffer_row_index = BufferRowIndex::Owned(0);
         }
+        if let (Ok(()), Some(on_flush)) = (&result, &self.on_flush) {
+            if num_rows != 0 {
+                on_flush(num_rows as usize, start.elapsed());
+            }
+        }
         result
     }
 
@@ -503,7 +928,7 @@ impl Batch<'_> {
             return Ok(());
         }
         let mut exec_mode = DPI_MODE_EXEC_DEFAULT;
-        if self.conn.autocommit() {
+        if self.conn.autocommit() || self.commit_on_flush {
             exec_mode |= DPI_MODE_EXEC_COMMIT_ON_SUCCESS;
         }
         if self.with_batch_errors {
@@ -512,11 +937,17 @@ impl Batch<'_> {
         if self.with_row_counts {
             exec_mode |= DPI_MODE_EXEC_ARRAY_DML_ROWCOUNTS;
         }
-        chkerr!(
-            self.conn.ctxt(),
-            dpiStmt_executeMany(self.handle, exec_mode, self.batch_index)
-        );
-        self.conn.ctxt().set_warning();
+        let (handle, batch_index) = (self.handle, self.batch_index);
+        let warning = {
+            let _guard = self.conn.conn.round_trip_guard();
+            observer::observe_execute(
+                self.statement_type,
+                self.conn.ctxt(),
+                "dpiStmt_executeMany",
+                || unsafe { dpiStmt_executeMany(handle, exec_mode, batch_index) },
+            )?
+        };
+        self.conn.ctxt().store_warning(warning);
         if self.with_batch_errors {
             let mut errnum = 0;
             chkerr!(
@@ -599,6 +1030,14 @@ impl Batch<'_> {
     where
         I: BatchBindIndex,
     {
+        self.set_type_internal(&bindidx, oratype)
+    }
+
+    fn set_type_internal(
+        &mut self,
+        bindidx: &dyn BatchBindIndex,
+        oratype: &OracleType,
+    ) -> Result<()> {
         let pos = bindidx.idx(self)?;
         if self.bind_types[pos].is_some() {
             return Err(Error::invalid_operation(format!(
@@ -651,10 +1090,41 @@ impl Batch<'_> {
         I: BatchBindIndex,
     {
         let pos = bindidx.idx(self)?;
+        self.bind_at(pos, bindidx, value).map_err(|err| {
+            if err.db_error().is_some() {
+                return err;
+            }
+            // Add the bind parameter and the row to errors caused by the value.
+            let value_type = value
+                .oratype(self.conn)
+                .map_or_else(|_| "unknown type".into(), |t| t.to_string());
+            let expected_type = self.bind_values[pos]
+                .oracle_type()
+                .map_or_else(|_| "unknown type".into(), |t| t.to_string());
+            Error::new(
+                err.kind(),
+                format!(
+                    "failed to set {} to bind parameter :{} ({}) at position {} (one-based) in appended row {} (zero-based): {}",
+                    value_type,
+                    self.bind_names[pos],
+                    expected_type,
+                    pos + 1,
+                    self.appended_rows,
+                    err
+                ),
+            )
+            .add_source(err)
+        })
+    }
+
+    fn bind_at<I>(&mut self, pos: usize, bindidx: I, value: &dyn ToSql) -> Result<()>
+    where
+        I: BatchBindIndex,
+    {
         if self.bind_types[pos].is_none() {
             // When the parameter type has not bee specified yet,
             // assume the type from the value
-            let oratype = value.oratype(self.conn)?;
+            let oratype = self.conn.conn.bind_type(value.oratype(self.conn)?);
             let bind_type = BindType::new(&oratype);
             self.bind_values[pos].init_handle(bind_type.as_oratype().unwrap_or(&oratype))?;
             chkerr!(
@@ -928,6 +1398,24 @@ mod tests {
         check_rows_inserted(&conn, &expected_rows).unwrap();
     }
 
+    #[test]
+    fn batch_with_incompatible_value() -> Result<()> {
+        let conn = test_util::connect()?;
+        let mut batch = conn
+            .batch("insert into TestTempTable values(:1, :2)", 10)
+            .build()?;
+        batch.append_row(&[&1.0, &"one"])?;
+        let err = batch.append_row(&[&"two", &"two"]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ParseError);
+        let msg = err.to_string();
+        assert!(
+            msg.starts_with("failed to set NVARCHAR2(3) to bind parameter :1 (BINARY_DOUBLE) at position 1 (one-based) in appended row 1 (zero-based): "),
+            "{}",
+            msg
+        );
+        Ok(())
+    }
+
     #[test]
     fn batch_with_batch_errors() {
         let conn = test_util::connect().unwrap();
@@ -963,4 +1451,34 @@ mod tests {
         }
         check_rows_inserted(&conn, &expected_rows).unwrap();
     }
+
+    #[test]
+    fn check_upsert_identifier() {
+        assert!(check_identifier("EMP", false).is_ok());
+        assert!(check_identifier("emp_1$#", false).is_ok());
+        assert!(check_identifier("\"Mixed Case\"", false).is_ok());
+        assert!(check_identifier("SCOTT.EMP", true).is_ok());
+        assert!(check_identifier("SCOTT.EMP", false).is_err());
+        assert!(check_identifier("\"S\".\"T\"", true).is_ok());
+        assert!(check_identifier("SCOTT.\"Mixed Case\"", true).is_ok());
+        assert!(check_identifier("\"S\".\"T\"", false).is_err());
+        assert!(check_identifier("\"a.b\"", false).is_ok());
+        assert!(check_identifier("A.B.C", true).is_err());
+        assert!(check_identifier("1EMP", false).is_err());
+        assert!(check_identifier("EMP; DROP TABLE EMP", true).is_err());
+        assert!(check_identifier("\"a\"\"b\"", false).is_err());
+        assert!(check_identifier("", false).is_err());
+    }
+
+    #[test]
+    fn check_upsert_sql() -> Result<()> {
+        let cols = |names: &[&str]| names.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let sql = upsert_sql("TestTempTable", &cols(&["IntCol"]), &cols(&["StringCol"]))?;
+        assert_eq!(sql, "MERGE INTO TestTempTable t USING (SELECT :1 IntCol, :2 StringCol FROM DUAL) s ON (t.IntCol = s.IntCol) WHEN MATCHED THEN UPDATE SET t.StringCol = s.StringCol WHEN NOT MATCHED THEN INSERT (IntCol, StringCol) VALUES (s.IntCol, s.StringCol)");
+        assert!(upsert_sql("TestTempTable", &cols(&[]), &cols(&["StringCol"])).is_err());
+        assert!(upsert_sql("TestTempTable", &cols(&["IntCol"]), &cols(&["INTCOL"])).is_err());
+        assert!(upsert_sql("TestTempTable", &cols(&["IntCol"]), &cols(&["\"INTCOL\""])).is_err());
+        assert!(upsert_sql("TestTempTable", &cols(&["IntCol"]), &cols(&["\"IntCol\""])).is_ok());
+        Ok(())
+    }
 }
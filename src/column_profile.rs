@@ -0,0 +1,218 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2025 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use crate::sql_type::OracleType;
+use crate::Result;
+use crate::SqlValue;
+use std::collections::hash_map::DefaultHasher;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+// The number of bits used to select a register of HyperLogLog
+const HLL_BITS: u32 = 10;
+const HLL_REGISTERS: usize = 1 << HLL_BITS;
+
+/// Statistics of values in a column, collected while rows are fetched
+///
+/// This is a return value of [`ResultSet::column_profiles`] after
+/// [`ResultSet::enable_column_profiling`] is called.
+///
+/// Lengths and distinct counts are not collected for LOB, object, `REF CURSOR`
+/// and vector columns.
+///
+/// [`ResultSet::column_profiles`]: crate::ResultSet::column_profiles
+/// [`ResultSet::enable_column_profiling`]: crate::ResultSet::enable_column_profiling
+#[derive(Clone)]
+pub struct ColumnProfile {
+    rows: u64,
+    null_count: u64,
+    error_count: u64,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    // registers of HyperLogLog. This is empty when values aren't sketched.
+    registers: Vec<u8>,
+}
+
+impl ColumnProfile {
+    pub(crate) fn new(oratype: &OracleType) -> ColumnProfile {
+        let registers = match oratype {
+            OracleType::CLOB
+            | OracleType::NCLOB
+            | OracleType::BLOB
+            | OracleType::BFILE
+            | OracleType::RefCursor
+            | OracleType::Object(_)
+            | OracleType::Json
+            | OracleType::Xml
+            | OracleType::Vector(..) => Vec::new(),
+            _ => vec![0; HLL_REGISTERS],
+        };
+        ColumnProfile {
+            rows: 0,
+            null_count: 0,
+            error_count: 0,
+            min_length: None,
+            max_length: None,
+            registers,
+        }
+    }
+
+    pub(crate) fn update(&mut self, val: &SqlValue) {
+        self.rows += 1;
+        if self.try_update(val).is_err() {
+            self.error_count += 1;
+        }
+    }
+
+    fn try_update(&mut self, val: &SqlValue) -> Result<()> {
+        if val.is_null()? {
+            self.null_count += 1;
+            return Ok(());
+        }
+        if self.registers.is_empty() {
+            return Ok(());
+        }
+        let mut hasher = DefaultHasher::new();
+        let len = match val.oracle_type()? {
+            OracleType::Raw(_) | OracleType::LongRaw => {
+                let bytes = val.get::<Vec<u8>>()?;
+                bytes.hash(&mut hasher);
+                bytes.len()
+            }
+            _ => {
+                let s = val.get::<String>()?;
+                s.hash(&mut hasher);
+                s.chars().count()
+            }
+        };
+        self.add(len, hasher.finish());
+        Ok(())
+    }
+
+    fn add(&mut self, len: usize, hash: u64) {
+        self.min_length = Some(self.min_length.map_or(len, |min| min.min(len)));
+        self.max_length = Some(self.max_length.map_or(len, |max| max.max(len)));
+        let idx = (hash >> (64 - HLL_BITS)) as usize;
+        let rank = ((hash << HLL_BITS) | (1 << (HLL_BITS - 1))).leading_zeros() as u8 + 1;
+        if self.registers[idx] < rank {
+            self.registers[idx] = rank;
+        }
+    }
+
+    /// Returns the number of profiled rows
+    pub fn rows(&self) -> u64 {
+        self.rows
+    }
+
+    /// Returns the number of NULL values
+    pub fn null_count(&self) -> u64 {
+        self.null_count
+    }
+
+    /// Returns the number of values which couldn't be profiled
+    ///
+    /// Such values are counted in [`ColumnProfile::rows`] but not in other
+    /// statistics. A failure of profiling doesn't make the row fetch fail.
+    pub fn error_count(&self) -> u64 {
+        self.error_count
+    }
+
+    /// Returns the minimum length of non-null values
+    ///
+    /// The length is the number of characters for character data types, the number
+    /// of bytes for `RAW` and `LONG RAW`, and the number of characters of the
+    /// string representation for other data types.
+    pub fn min_length(&self) -> Option<usize> {
+        self.min_length
+    }
+
+    /// Returns the maximum length of non-null values
+    ///
+    /// See [`ColumnProfile::min_length`] about the length.
+    pub fn max_length(&self) -> Option<usize> {
+        self.max_length
+    }
+
+    /// Returns an estimated number of distinct non-null values
+    ///
+    /// This is estimated by [HyperLogLog] with 1024 registers. The typical
+    /// error is about 3%. It is `None` when values aren't sketched because
+    /// of their data type.
+    ///
+    /// [HyperLogLog]: https://en.wikipedia.org/wiki/HyperLogLog
+    pub fn distinct_estimate(&self) -> Option<u64> {
+        if self.registers.is_empty() {
+            return None;
+        }
+        let m = HLL_REGISTERS as f64;
+        let mut sum = 0.0;
+        let mut zeros = 0;
+        for &reg in &self.registers {
+            sum += 2f64.powi(-i32::from(reg));
+            if reg == 0 {
+                zeros += 1;
+            }
+        }
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let estimate = alpha * m * m / sum;
+        let estimate = if estimate <= 2.5 * m && zeros > 0 {
+            // linear counting for small cardinalities
+            m * (m / f64::from(zeros)).ln()
+        } else {
+            estimate
+        };
+        Some(estimate.round() as u64)
+    }
+}
+
+impl fmt::Debug for ColumnProfile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ColumnProfile")
+            .field("rows", &self.rows)
+            .field("null_count", &self.null_count)
+            .field("error_count", &self.error_count)
+            .field("min_length", &self.min_length)
+            .field("max_length", &self.max_length)
+            .field("distinct_estimate", &self.distinct_estimate())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn estimate(distinct: u64, repeat: u64) -> u64 {
+        let mut profile = ColumnProfile::new(&OracleType::Number(0, 0));
+        for _ in 0..repeat {
+            for i in 0..distinct {
+                let mut hasher = DefaultHasher::new();
+                i.hash(&mut hasher);
+                profile.add(1, hasher.finish());
+            }
+        }
+        profile.distinct_estimate().unwrap()
+    }
+
+    #[test]
+    fn distinct_estimate() {
+        assert_eq!(estimate(0, 1), 0);
+        for (distinct, repeat) in [(1, 3), (10, 2), (100, 1), (1000, 2), (100000, 1)] {
+            let est = estimate(distinct, repeat) as f64;
+            let error = (est - distinct as f64).abs() / distinct as f64;
+            assert!(error < 0.1, "distinct: {}, estimate: {}", distinct, est);
+        }
+    }
+}
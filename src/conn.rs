@@ -16,12 +16,22 @@
 //! Type definitions for connection
 //!
 //! Some types at the top-level module will move here in future.
+use crate::batch::check_identifier;
+use crate::sql_type::days_from_civil;
+use crate::sql_type::Timestamp;
 use crate::to_rust_str;
 #[cfg(doc)]
 use crate::Connection;
 use crate::Error;
 use crate::Result;
+use crate::Row;
 use odpic_sys::*;
+use std::collections::hash_map::RandomState;
+use std::fmt;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 /// The mode to use when closing connections to the database
 ///
@@ -140,6 +150,520 @@ pub struct Info {
     pub server_type: ServerType,
 }
 
+/// Session identifiers of a connection
+///
+/// This is a return value of [`Connection::session_info()`]. Log these
+/// values to correlate application logs with AWR reports and trace files
+/// on the database server.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionInfo {
+    /// The session identifier
+    ///
+    /// This is the same value returned by the SQL expression
+    /// `SELECT SYS_CONTEXT('USERENV', 'SID') FROM DUAL`.
+    pub sid: u32,
+
+    /// The session serial number, which distinguishes sessions reusing the same SID
+    ///
+    /// This is the same value as `SERIAL#` in `V$SESSION`.
+    pub serial_number: u32,
+
+    /// The Oracle Database instance name associated with the connection
+    ///
+    /// This is the same value returned by the SQL expression
+    /// `SELECT SYS_CONTEXT('USERENV', 'INSTANCE_NAME') FROM DUAL`.
+    pub instance_name: String,
+
+    /// The name of the current container, such as `CDB$ROOT` or a PDB name
+    ///
+    /// This is the same value returned by the SQL expression
+    /// `SELECT SYS_CONTEXT('USERENV', 'CON_NAME') FROM DUAL`.
+    /// It is `None` when the server is older than Oracle 12c, which doesn't
+    /// support multitenant architecture.
+    pub container_name: Option<String>,
+}
+
+/// Server time and round-trip latency
+///
+/// This is a return value of [`Connection::server_time()`].
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServerTime {
+    /// The value of `SYSTIMESTAMP` on the server
+    pub timestamp: Timestamp,
+
+    /// The elapsed time of the query to get `SYSTIMESTAMP`
+    pub round_trip: Duration,
+
+    /// The local time at the midpoint of the round trip
+    pub local_time: SystemTime,
+}
+
+impl ServerTime {
+    /// Returns the difference of the server clock from the local clock in seconds
+    ///
+    /// It is positive when the server clock is ahead. The error is at most
+    /// half of [`ServerTime::round_trip`].
+    pub fn clock_skew(&self) -> f64 {
+        let local = match self.local_time.duration_since(UNIX_EPOCH) {
+            Ok(d) => d.as_secs_f64(),
+            Err(err) => -err.duration().as_secs_f64(),
+        };
+        unix_time(&self.timestamp) - local
+    }
+}
+
+// Returns seconds since the Unix epoch.
+fn unix_time(ts: &Timestamp) -> f64 {
+    let days = days_from_civil(ts.year(), ts.month(), ts.day());
+    let secs = days * 86400
+        + i64::from(ts.hour()) * 3600
+        + i64::from(ts.minute()) * 60
+        + i64::from(ts.second())
+        - i64::from(ts.tz_offset());
+    secs as f64 + f64::from(ts.nanosecond()) / 1e9
+}
+
+/// A row in a DML error logging table
+///
+/// This is a return value of [`Connection::dml_error_log()`].
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DmlErrorLogRecord {
+    /// Oracle error number (`ORA_ERR_NUMBER$`)
+    pub code: i32,
+
+    /// Oracle error message (`ORA_ERR_MESG$`)
+    pub message: String,
+
+    /// Rowid of the row in error for UPDATE and DELETE (`ORA_ERR_ROWID$`)
+    pub rowid: Option<String>,
+
+    /// Operation type (`ORA_ERR_OPTYP$`), `I` for INSERT, `U` for UPDATE and `D` for DELETE
+    pub operation: String,
+
+    /// The tag specified in the error logging clause (`ORA_ERR_TAG$`)
+    pub tag: Option<String>,
+
+    /// Names and values of other columns, which are copied from the row in error
+    pub columns: Vec<(String, Option<String>)>,
+}
+
+impl DmlErrorLogRecord {
+    pub(crate) fn from_row(row: &Row) -> Result<DmlErrorLogRecord> {
+        let mut rec = DmlErrorLogRecord {
+            code: 0,
+            message: String::new(),
+            rowid: None,
+            operation: String::new(),
+            tag: None,
+            columns: Vec::new(),
+        };
+        for (info, val) in row.column_info().iter().zip(row.sql_values()) {
+            match info.name() {
+                "ORA_ERR_NUMBER$" => rec.code = val.get()?,
+                "ORA_ERR_MESG$" => rec.message = val.get::<Option<String>>()?.unwrap_or_default(),
+                "ORA_ERR_ROWID$" => rec.rowid = val.get()?,
+                "ORA_ERR_OPTYP$" => {
+                    rec.operation = val.get::<Option<String>>()?.unwrap_or_default()
+                }
+                "ORA_ERR_TAG$" => rec.tag = val.get()?,
+                name => rec.columns.push((name.to_string(), val.get()?)),
+            }
+        }
+        Ok(rec)
+    }
+}
+
+/// Outcome of a transaction identified by a logical transaction ID
+///
+/// This is a return value of [`Connection::ltxid_outcome()`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LtxidOutcome {
+    /// Whether the transaction was committed
+    pub committed: bool,
+
+    /// Whether the user call which committed the transaction completed.
+    /// It is false when the commit was executed in a PL/SQL block and
+    /// the block didn't complete.
+    pub user_call_completed: bool,
+}
+
+/// Common NLS session parameters
+///
+/// This is a return value of [`Connection::nls_params()`] and an argument
+/// of [`Connection::set_nls_params()`]. `None` fields are left unchanged
+/// by `set_nls_params()`.
+///
+/// These parameters affect conversion between strings and dates or numbers
+/// on the server side, such as `TO_CHAR(date_column)` and binding a string
+/// to a `NUMBER` column. They don't affect values fetched or bound as
+/// [`Timestamp`] or Rust numeric types.
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NlsParams {
+    /// `NLS_DATE_FORMAT` such as `YYYY-MM-DD HH24:MI:SS`
+    pub date_format: Option<String>,
+
+    /// `NLS_NUMERIC_CHARACTERS`, which consists of the decimal character
+    /// and the group separator such as `.,`
+    pub numeric_characters: Option<String>,
+
+    /// `NLS_SORT` such as `BINARY` and `XGERMAN_CI`
+    pub sort: Option<String>,
+}
+
+impl NlsParams {
+    /// Creates a new value whose fields are all `None`
+    pub fn new() -> NlsParams {
+        NlsParams::default()
+    }
+
+    /// Sets `NLS_DATE_FORMAT`
+    pub fn date_format<S>(mut self, fmt: S) -> NlsParams
+    where
+        S: Into<String>,
+    {
+        self.date_format = Some(fmt.into());
+        self
+    }
+
+    /// Sets `NLS_NUMERIC_CHARACTERS`
+    pub fn numeric_characters<S>(mut self, chars: S) -> NlsParams
+    where
+        S: Into<String>,
+    {
+        self.numeric_characters = Some(chars.into());
+        self
+    }
+
+    /// Sets `NLS_SORT`
+    pub fn sort<S>(mut self, sort: S) -> NlsParams
+    where
+        S: Into<String>,
+    {
+        self.sort = Some(sort.into());
+        self
+    }
+
+    // Returns an `ALTER SESSION` statement or `None` when no parameters are set.
+    pub(crate) fn alter_session_sql(&self) -> Result<Option<String>> {
+        let mut sql = String::from("ALTER SESSION SET");
+        if let Some(fmt) = &self.date_format {
+            if fmt.is_empty() || fmt.contains('\'') || fmt.contains('\0') {
+                return Err(Error::invalid_argument(format!(
+                    "invalid NLS_DATE_FORMAT: {:?}",
+                    fmt
+                )));
+            }
+            sql.push_str(&format!(" NLS_DATE_FORMAT = '{}'", fmt));
+        }
+        if let Some(chars) = &self.numeric_characters {
+            let v: Vec<char> = chars.chars().collect();
+            let is_valid = |c: char| !c.is_ascii_digit() && !"+-<>'\0".contains(c);
+            if v.len() != 2 || v[0] == v[1] || !is_valid(v[0]) || !is_valid(v[1]) {
+                return Err(Error::invalid_argument(format!(
+                    "invalid NLS_NUMERIC_CHARACTERS: {:?}",
+                    chars
+                )));
+            }
+            sql.push_str(&format!(" NLS_NUMERIC_CHARACTERS = '{}'", chars));
+        }
+        if let Some(sort) = &self.sort {
+            check_identifier(sort, false)?;
+            sql.push_str(&format!(" NLS_SORT = {}", sort));
+        }
+        if sql.len() == "ALTER SESSION SET".len() {
+            Ok(None)
+        } else {
+            Ok(Some(sql))
+        }
+    }
+}
+
+/// Policy to decide whether a connection should be discarded
+///
+/// This is an argument of [`Connection::is_expired()`]. `None` fields aren't
+/// checked. Applications keeping standalone connections for a long time can
+/// use this to reconnect before sessions are killed by firewalls or the
+/// server's idle timeout.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExpirationPolicy {
+    /// Maximum time since the connection was created
+    pub max_lifetime: Option<Duration>,
+
+    /// Maximum time since the connection was last used
+    pub idle_timeout: Option<Duration>,
+}
+
+impl ExpirationPolicy {
+    /// Creates a new value whose fields are all `None`
+    pub fn new() -> ExpirationPolicy {
+        ExpirationPolicy::default()
+    }
+
+    /// Sets the maximum time since the connection was created
+    pub fn max_lifetime(mut self, dur: Duration) -> ExpirationPolicy {
+        self.max_lifetime = Some(dur);
+        self
+    }
+
+    /// Sets the maximum time since the connection was last used
+    pub fn idle_timeout(mut self, dur: Duration) -> ExpirationPolicy {
+        self.idle_timeout = Some(dur);
+        self
+    }
+}
+
+// ORA- error codes which may disappear by retrying to connect
+const RETRYABLE_CONNECT_ERRORS: &[i32] = &[
+    1033,  // ORACLE initialization or shutdown in progress
+    1034,  // ORACLE not available
+    1089,  // immediate shutdown or close in progress
+    12170, // Connect timeout occurred
+    12514, // Listener does not currently know of service requested
+    12516, // Listener could not find available handler
+    12520, // Listener could not find available handler for requested type of server
+    12521, // Listener does not currently know of instance requested
+    12528, // All appropriate instances are blocking new connections
+    12537, // Connection closed
+    12541, // No listener
+    12547, // Lost contact
+];
+
+type RetryCallback = dyn Fn(u32, &Error, Duration) + Send + Sync;
+
+/// Policy to retry establishing connections with exponential backoff
+///
+/// This is an argument of [`Connector::connect_with_retry()`](crate::Connector::connect_with_retry)
+/// and [`Pool::get_with_retry()`](crate::pool::Pool::get_with_retry).
+/// Only errors which may disappear by waiting, such as `ORA-12514`, `ORA-12541`
+/// and errors whose [`DbError::is_recoverable()`](crate::DbError::is_recoverable)
+/// is true, are retried. Other errors are returned immediately.
+///
+/// The delay before the `n`-th retry is `initial_delay * multiplier^(n-1)`
+/// limited by `max_delay`. When `jitter` is true, a random duration up to the half
+/// of the delay is subtracted so that many clients don't retry at once.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use oracle::Error;
+/// use oracle::conn::RetryPolicy;
+/// use oracle::Connector;
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy::new()
+///     .max_attempts(10)
+///     .initial_delay(Duration::from_millis(200))
+///     .on_retry(|attempt, err, delay| {
+///         eprintln!("connect attempt {} failed: {}; retry in {:?}", attempt, err, delay);
+///     });
+/// let conn = Connector::new("scott", "tiger", "//dbhost/orclpdb").connect_with_retry(&policy)?;
+/// # Ok::<(), Error>(())
+/// ```
+#[non_exhaustive]
+#[derive(Clone)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts including the first one. The default is 5.
+    pub max_attempts: u32,
+
+    /// Delay before the first retry. The default is 500 milliseconds.
+    pub initial_delay: Duration,
+
+    /// Upper limit of delays. The default is 30 seconds.
+    pub max_delay: Duration,
+
+    /// Factor by which the delay grows after each retry. The default is 2.0.
+    pub multiplier: f64,
+
+    /// Whether delays are randomized. The default is true.
+    pub jitter: bool,
+
+    on_retry: Option<Arc<RetryCallback>>,
+}
+
+impl RetryPolicy {
+    /// Creates a new value with default settings
+    pub fn new() -> RetryPolicy {
+        RetryPolicy::default()
+    }
+
+    /// Sets the maximum number of attempts including the first one
+    pub fn max_attempts(mut self, attempts: u32) -> RetryPolicy {
+        self.max_attempts = attempts;
+        self
+    }
+
+    /// Sets the delay before the first retry
+    pub fn initial_delay(mut self, dur: Duration) -> RetryPolicy {
+        self.initial_delay = dur;
+        self
+    }
+
+    /// Sets the upper limit of delays
+    pub fn max_delay(mut self, dur: Duration) -> RetryPolicy {
+        self.max_delay = dur;
+        self
+    }
+
+    /// Sets the factor by which the delay grows after each retry
+    pub fn multiplier(mut self, multiplier: f64) -> RetryPolicy {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Sets whether delays are randomized
+    pub fn jitter(mut self, jitter: bool) -> RetryPolicy {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Sets a function called before waiting for the next attempt
+    ///
+    /// The arguments are the number of failed attempts, the error and the
+    /// delay before the next attempt.
+    pub fn on_retry<F>(mut self, f: F) -> RetryPolicy
+    where
+        F: Fn(u32, &Error, Duration) + Send + Sync + 'static,
+    {
+        self.on_retry = Some(Arc::new(f));
+        self
+    }
+
+    fn is_retryable(err: &Error) -> bool {
+        match err.db_error() {
+            Some(dberr) => {
+                dberr.is_recoverable() || RETRYABLE_CONNECT_ERRORS.contains(&dberr.code())
+            }
+            None => false,
+        }
+    }
+
+    fn delay(&self, retry: u32) -> Duration {
+        let factor = self
+            .multiplier
+            .max(1.0)
+            .powi(retry.min(i32::MAX as u32) as i32);
+        let secs = self.initial_delay.as_secs_f64() * factor;
+        let delay = if secs < self.max_delay.as_secs_f64() {
+            Duration::from_secs_f64(secs)
+        } else {
+            self.max_delay
+        };
+        if self.jitter {
+            let random = RandomState::new().build_hasher().finish();
+            delay - delay.mul_f64((random as f64 / u64::MAX as f64) / 2.0)
+        } else {
+            delay
+        }
+    }
+
+    pub(crate) fn run<T, F>(&self, mut f: F) -> Result<T>
+    where
+        F: FnMut() -> Result<T>,
+    {
+        let mut attempt = 1;
+        loop {
+            match f() {
+                Err(err) if attempt < self.max_attempts && RetryPolicy::is_retryable(&err) => {
+                    let delay = self.delay(attempt - 1);
+                    if let Some(on_retry) = &self.on_retry {
+                        on_retry(attempt, &err, delay);
+                    }
+                    thread::sleep(delay);
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 5,
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            multiplier: 2.0,
+            jitter: true,
+            on_retry: None,
+        }
+    }
+}
+
+impl fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("initial_delay", &self.initial_delay)
+            .field("max_delay", &self.max_delay)
+            .field("multiplier", &self.multiplier)
+            .field("jitter", &self.jitter)
+            .field("on_retry", &self.on_retry.as_ref().map(|_| "Fn"))
+            .finish()
+    }
+}
+
+// Adds Oracle Net parameters such as `expire_time` to `connect_string`.
+//
+// Parameters are appended as query parameters to an Easy Connect string
+// and inserted to each `DESCRIPTION` of a connect descriptor. Net service
+// names are rejected because parameters cannot be added to them.
+pub(crate) fn add_net_params(connect_string: &str, params: &[(&str, String)]) -> Result<String> {
+    if params.is_empty() {
+        return Ok(connect_string.to_string());
+    }
+    let trimmed = connect_string.trim();
+    if trimmed.starts_with('(') {
+        let upper = trimmed.to_ascii_uppercase();
+        let mut result = String::with_capacity(trimmed.len() + 32 * params.len());
+        let mut pos = 0;
+        while let Some(idx) = upper[pos..].find("(DESCRIPTION") {
+            let start = pos + idx + "(DESCRIPTION".len();
+            let rest = &upper[start..];
+            let eq = rest.len() - rest.trim_start().len();
+            if !rest[eq..].starts_with('=') {
+                // such as (DESCRIPTION_LIST=
+                result.push_str(&trimmed[pos..start]);
+                pos = start;
+                continue;
+            }
+            let end = start + eq + 1;
+            result.push_str(&trimmed[pos..end]);
+            for (name, value) in params {
+                result.push_str(&format!("({}={})", name.to_ascii_uppercase(), value));
+            }
+            pos = end;
+        }
+        if pos == 0 {
+            return Err(Error::invalid_argument(format!(
+                "no DESCRIPTION found in connect descriptor {:?}",
+                connect_string
+            )));
+        }
+        result.push_str(&trimmed[pos..]);
+        Ok(result)
+    } else if trimmed.contains(['/', ':', '?']) {
+        let mut result = trimmed.to_string();
+        for (name, value) in params {
+            result.push(if result.contains('?') { '&' } else { '?' });
+            result.push_str(&format!("{}={}", name, value));
+        }
+        Ok(result)
+    } else {
+        Err(Error::invalid_argument(format!(
+            "Oracle Net parameters cannot be added to net service name {:?}. \
+             Use an Easy Connect string or a connect descriptor.",
+            connect_string
+        )))
+    }
+}
+
 impl Info {
     pub(crate) fn from_dpi(info: &dpiConnInfo) -> Result<Info> {
         Ok(Info {
@@ -153,3 +677,165 @@ impl Info {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DbError;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[test]
+    fn retry_policy_delay() {
+        let policy = RetryPolicy::new()
+            .initial_delay(Duration::from_secs(1))
+            .max_delay(Duration::from_secs(5))
+            .jitter(false);
+        assert_eq!(policy.delay(0), Duration::from_secs(1));
+        assert_eq!(policy.delay(1), Duration::from_secs(2));
+        assert_eq!(policy.delay(2), Duration::from_secs(4));
+        assert_eq!(policy.delay(3), Duration::from_secs(5));
+        let policy = policy.jitter(true);
+        for _ in 0..10 {
+            let delay = policy.delay(1);
+            assert!(Duration::from_secs(1) <= delay && delay <= Duration::from_secs(2));
+        }
+    }
+
+    #[test]
+    fn retry_policy_run() {
+        let retries = Arc::new(AtomicU32::new(0));
+        let retries_in_callback = retries.clone();
+        let policy = RetryPolicy::new()
+            .max_attempts(3)
+            .initial_delay(Duration::from_millis(1))
+            .on_retry(move |_, _, _| {
+                retries_in_callback.fetch_add(1, Ordering::SeqCst);
+            });
+        let no_listener = || Error::from_db_error(DbError::new(12541, 0, "ORA-12541", "", ""));
+
+        // retryable errors
+        let mut attempts = 0;
+        let result = policy.run(|| -> Result<()> {
+            attempts += 1;
+            Err(no_listener())
+        });
+        assert_eq!(result.unwrap_err().oci_code(), Some(12541));
+        assert_eq!(attempts, 3);
+        assert_eq!(retries.load(Ordering::SeqCst), 2);
+
+        // succeeds after a retry
+        let mut attempts = 0;
+        let result = policy.run(|| {
+            attempts += 1;
+            if attempts == 1 {
+                Err(no_listener())
+            } else {
+                Ok(attempts)
+            }
+        });
+        assert_eq!(result.unwrap(), 2);
+
+        // non-retryable errors
+        let mut attempts = 0;
+        let result = policy.run(|| -> Result<()> {
+            attempts += 1;
+            Err(Error::from_db_error(DbError::new(
+                1017,
+                0,
+                "ORA-01017",
+                "",
+                "",
+            )))
+        });
+        assert_eq!(result.unwrap_err().oci_code(), Some(1017));
+        assert_eq!(attempts, 1);
+    }
+
+    #[test]
+    fn add_net_params_to_connect_string() -> Result<()> {
+        let params = [("expire_time", "2".to_string())];
+        assert_eq!(
+            add_net_params("localhost/orclpdb", &[])?,
+            "localhost/orclpdb"
+        );
+        assert_eq!(
+            add_net_params("localhost/orclpdb", &params)?,
+            "localhost/orclpdb?expire_time=2"
+        );
+        assert_eq!(
+            add_net_params("tcps://dbhost:1522/orclpdb?retry_count=3", &params)?,
+            "tcps://dbhost:1522/orclpdb?retry_count=3&expire_time=2"
+        );
+        assert_eq!(
+            add_net_params(
+                "(description=(address=(protocol=tcp)(host=dbhost)(port=1521))(connect_data=(service_name=orclpdb)))",
+                &params
+            )?,
+            "(description=(EXPIRE_TIME=2)(address=(protocol=tcp)(host=dbhost)(port=1521))(connect_data=(service_name=orclpdb)))"
+        );
+        assert_eq!(
+            add_net_params(
+                "(DESCRIPTION_LIST=(DESCRIPTION =(ADDRESS=(HOST=a)))(DESCRIPTION=(ADDRESS=(HOST=b))))",
+                &params
+            )?,
+            "(DESCRIPTION_LIST=(DESCRIPTION =(EXPIRE_TIME=2)(ADDRESS=(HOST=a)))(DESCRIPTION=(EXPIRE_TIME=2)(ADDRESS=(HOST=b))))"
+        );
+        assert_eq!(
+            add_net_params(
+                "localhost/orclpdb",
+                &[
+                    ("sdu", "65535".to_string()),
+                    ("tcp.nodelay", "yes".to_string())
+                ]
+            )?,
+            "localhost/orclpdb?sdu=65535&tcp.nodelay=yes"
+        );
+        assert!(add_net_params("orclpdb", &params).is_err());
+        assert!(add_net_params("(ADDRESS=(HOST=a))", &params).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn nls_params_alter_session_sql() -> Result<()> {
+        assert_eq!(NlsParams::new().alter_session_sql()?, None);
+        let params = NlsParams::new()
+            .date_format("YYYY-MM-DD \"T\" HH24:MI:SS")
+            .numeric_characters(",.")
+            .sort("XGERMAN_CI");
+        assert_eq!(
+            params.alter_session_sql()?.unwrap(),
+            "ALTER SESSION SET NLS_DATE_FORMAT = 'YYYY-MM-DD \"T\" HH24:MI:SS' \
+             NLS_NUMERIC_CHARACTERS = ',.' NLS_SORT = XGERMAN_CI"
+        );
+        for params in [
+            NlsParams::new().date_format(""),
+            NlsParams::new().date_format("YYYY' || 'MM"),
+            NlsParams::new().numeric_characters("."),
+            NlsParams::new().numeric_characters(".."),
+            NlsParams::new().numeric_characters(".,;"),
+            NlsParams::new().numeric_characters("1,"),
+            NlsParams::new().numeric_characters(".-"),
+            NlsParams::new().sort("BINARY; DROP TABLE T"),
+        ] {
+            assert!(params.alter_session_sql().is_err(), "{:?}", params);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn server_time_clock_skew() -> Result<()> {
+        // 2024-02-29 12:00:30.5 +09:00 is 1709175630.5 seconds since the epoch.
+        let timestamp =
+            Timestamp::new(2024, 2, 29, 12, 0, 30, 500_000_000)?.and_tz_offset(9 * 3600)?;
+        assert_eq!(unix_time(&timestamp), 1709175630.5);
+        let server_time = ServerTime {
+            timestamp,
+            round_trip: Duration::from_millis(10),
+            local_time: UNIX_EPOCH + Duration::from_secs(1709175628),
+        };
+        assert_eq!(server_time.clock_skew(), 2.5);
+        let timestamp = Timestamp::new(1969, 12, 31, 23, 59, 59, 0)?;
+        assert_eq!(unix_time(&timestamp), -1.0);
+        Ok(())
+    }
+}
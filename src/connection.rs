@@ -13,9 +13,14 @@
 // (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
 //-----------------------------------------------------------------------------
 
+use crate::batch::check_identifier;
 use crate::chkerr;
-use crate::conn::{CloseMode, Info, Purity};
+use crate::conn::{
+    add_net_params, CloseMode, DmlErrorLogRecord, ExpirationPolicy, Info, LtxidOutcome, NlsParams,
+    Purity, RetryPolicy, ServerTime, SessionInfo,
+};
 use crate::error::DPI_ERR_NOT_CONNECTED;
+use crate::observer;
 use crate::oci_attr::data_type::{AttrValue, DataType};
 use crate::oci_attr::handle::ConnHandle;
 use crate::oci_attr::handle::Server;
@@ -26,24 +31,30 @@ use crate::oci_attr::OciAttr;
 use crate::pool::PoolOptions;
 use crate::sql_type::ObjectType;
 use crate::sql_type::ObjectTypeInternal;
+use crate::sql_type::OracleType;
+use crate::sql_type::Timestamp;
 use crate::sql_type::ToSql;
+use crate::to_rust_slice;
 use crate::to_rust_str;
 use crate::AssertSend;
 use crate::AssertSync;
 #[cfg(doc)]
 use crate::Batch;
 use crate::BatchBuilder;
+use crate::ColumnInfo;
 use crate::Context;
 use crate::DpiConn;
 use crate::DpiObjectType;
 use crate::Error;
 use crate::OdpiStr;
+use crate::QueryParams;
 use crate::Result;
 use crate::ResultSet;
 use crate::Row;
 use crate::RowValue;
 use crate::Statement;
 use crate::StatementBuilder;
+use crate::UpsertBuilder;
 use crate::Version;
 use odpic_sys::*;
 use std::borrow::ToOwned;
@@ -51,10 +62,10 @@ use std::collections::HashMap;
 use std::fmt;
 use std::mem::MaybeUninit;
 use std::ptr;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicU8, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::sync::Mutex;
-use std::time::Duration;
+use std::time::{Duration, Instant, SystemTime};
 
 struct ServerStatus;
 const OCI_ATTR_SERVER_STATUS: u32 = 143;
@@ -69,7 +80,7 @@ unsafe impl OciAttr for ServerStatus {
 
 /// Database startup mode
 ///
-/// See [`Connection::startup_database`]
+/// See [`Connection::startup_database`] and [`Connection::startup_database_with_pfile`]
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum StartupMode {
     /// Shuts down a running instance (if there is any) using ABORT before
@@ -81,6 +92,17 @@ pub enum StartupMode {
     Restrict,
 }
 
+fn startup_mode_num(modes: &[StartupMode]) -> dpiStartupMode {
+    let mut mode_num = 0;
+    for mode in modes {
+        mode_num |= match *mode {
+            StartupMode::Force => DPI_MODE_STARTUP_FORCE,
+            StartupMode::Restrict => DPI_MODE_STARTUP_RESTRICT,
+        };
+    }
+    mode_num
+}
+
 /// Database shutdown mode
 ///
 /// See [`Connection::shutdown_database`].
@@ -169,6 +191,125 @@ pub enum ConnStatus {
     Closed,
 }
 
+/// Transaction mode used by [`Connection::begin_transaction`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TransactionMode {
+    /// `SET TRANSACTION READ ONLY`
+    ///
+    /// Queries see data committed before the transaction began.
+    /// DML statements fail.
+    ReadOnly,
+
+    /// `SET TRANSACTION READ WRITE`
+    ///
+    /// This is the default mode of Oracle transactions.
+    ReadWrite,
+
+    /// `SET TRANSACTION ISOLATION LEVEL SERIALIZABLE`
+    ///
+    /// Queries and DML statements see data committed before the transaction
+    /// began and changes made by the transaction itself.
+    Serializable,
+}
+
+impl TransactionMode {
+    fn sql(self) -> &'static str {
+        match self {
+            TransactionMode::ReadOnly => "SET TRANSACTION READ ONLY",
+            TransactionMode::ReadWrite => "SET TRANSACTION READ WRITE",
+            TransactionMode::Serializable => "SET TRANSACTION ISOLATION LEVEL SERIALIZABLE",
+        }
+    }
+}
+
+/// Options of `COMMIT WRITE` used by [`Connection::commit_with`]
+///
+/// The default options are `IMMEDIATE` and `WAIT`, which make the commit
+/// durable as [`Connection::commit`] does by default.
+///
+/// ```
+/// # use oracle::CommitOptions;
+/// let opts = CommitOptions::new().batch(true).nowait(true);
+/// assert_eq!(opts.sql(), "COMMIT WRITE BATCH NOWAIT");
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct CommitOptions {
+    batch: bool,
+    nowait: bool,
+}
+
+impl CommitOptions {
+    /// Creates options of `COMMIT WRITE IMMEDIATE WAIT`
+    pub fn new() -> CommitOptions {
+        CommitOptions::default()
+    }
+
+    /// Buffers redo in memory and writes it with other transactions' redo
+    /// when `batch` is true. Otherwise, the redo is written immediately.
+    pub fn batch(mut self, batch: bool) -> CommitOptions {
+        self.batch = batch;
+        self
+    }
+
+    /// Returns before the redo is written to the online redo log when `nowait` is true.
+    ///
+    /// Committed changes may be lost on an instance failure.
+    pub fn nowait(mut self, nowait: bool) -> CommitOptions {
+        self.nowait = nowait;
+        self
+    }
+
+    /// Returns the SQL statement to commit with the options
+    pub fn sql(&self) -> &'static str {
+        match (self.batch, self.nowait) {
+            (false, false) => "COMMIT WRITE IMMEDIATE WAIT",
+            (false, true) => "COMMIT WRITE IMMEDIATE NOWAIT",
+            (true, false) => "COMMIT WRITE BATCH WAIT",
+            (true, true) => "COMMIT WRITE BATCH NOWAIT",
+        }
+    }
+}
+
+/// How an empty Rust string is bound to a placeholder
+///
+/// Oracle treats a zero-length `VARCHAR2` as `NULL`. So `WHERE col = :1`
+/// never matches when `""` is bound, and a `""` inserted into a `NOT NULL`
+/// column raises `ORA-01400`. This mode makes the conversion explicit.
+/// See [`Connection::set_empty_string_mode`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EmptyStringMode {
+    /// Binds `""` as is. Oracle sees it as `NULL`. This is the default.
+    Null,
+    /// Binds `""` as a single blank typed as `NCHAR(1)`.
+    Blank,
+    /// Returns an error when `""` is bound.
+    Error,
+}
+
+impl Default for EmptyStringMode {
+    fn default() -> EmptyStringMode {
+        EmptyStringMode::Null
+    }
+}
+
+impl EmptyStringMode {
+    fn from_u8(val: u8) -> EmptyStringMode {
+        match val {
+            1 => EmptyStringMode::Blank,
+            2 => EmptyStringMode::Error,
+            _ => EmptyStringMode::Null,
+        }
+    }
+
+    fn to_u8(self) -> u8 {
+        match self {
+            EmptyStringMode::Null => 0,
+            EmptyStringMode::Blank => 1,
+            EmptyStringMode::Error => 2,
+        }
+    }
+}
+
 #[derive(Debug, Default, Clone, PartialEq)]
 pub(crate) struct CommonCreateParamsBuilder {
     events: bool,
@@ -238,6 +379,9 @@ pub struct Connector {
     purity: Option<Purity>,
     connection_class: String,
     app_context: Vec<(String, String, String)>,
+    expire_time: Option<u32>,
+    sdu: Option<u32>,
+    tcp_nodelay: Option<bool>,
     common_params: CommonCreateParamsBuilder,
 }
 
@@ -260,6 +404,9 @@ impl Connector {
             purity: None,
             connection_class: "".into(),
             app_context: vec![],
+            expire_time: None,
+            sdu: None,
+            tcp_nodelay: None,
             common_params: Default::default(),
         }
     }
@@ -299,6 +446,45 @@ impl Connector {
         self
     }
 
+    /// Uses external authentication to connect to the net service name or
+    /// the wallet alias `alias`.
+    ///
+    /// This is same with setting `alias` to the connect string and calling
+    /// `external_auth(true)`. The username must be empty or a proxy user name
+    /// enclosed in square brackets such as `[scott]`. The password must be empty.
+    ///
+    /// How the user is authenticated depends on `SQLNET.AUTHENTICATION_SERVICES`
+    /// in `sqlnet.ora` of the client:
+    ///
+    /// * `(NTS)` authenticates the Windows logon user (SSPI).
+    /// * `(KERBEROS5)` uses the Kerberos ticket of the OS user got by `kinit` or `okinit`.
+    /// * Otherwise, credentials in the secure external password store (wallet)
+    ///   for `alias` or OS authentication are used.
+    ///
+    /// When the Oracle client library cannot use the authentication service,
+    /// the connection fails with errors such as `ORA-12641` (authentication
+    /// service failed to initialize) or `ORA-12638` (credential retrieval failed),
+    /// which are got by [`Error::db_error()`](crate::Error::db_error).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use oracle::*;
+    /// // Connect to "sales_db" in tnsnames.ora as the Windows or Kerberos user
+    /// let conn = Connector::new("", "", "")
+    ///     .external_auth_with_name("sales_db")
+    ///     .connect()?;
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn external_auth_with_name<S>(&mut self, alias: S) -> &mut Connector
+    where
+        S: Into<String>,
+    {
+        self.connect_string = alias.into();
+        self.external_auth = true;
+        self
+    }
+
     /// Sets prelim auth mode to connect to an idle instance.
     ///
     /// See [starting up a database](Connection::startup_database).
@@ -458,21 +644,103 @@ impl Connector {
         self
     }
 
+    /// Sets the interval to send probes checking that the client is alive
+    ///
+    /// This sets the Oracle Net parameter `EXPIRE_TIME` in the connect
+    /// string, which is applied to this connection without editing `sqlnet.ora`.
+    /// Probes keep firewalls and NAT devices from silently dropping idle
+    /// connections. The interval is rounded up to minutes.
+    ///
+    /// The parameter is appended to an Easy Connect string as `?expire_time=n`
+    /// or inserted into each `DESCRIPTION` of a connect descriptor.
+    /// [`Connector::connect`] fails for a net service name, which cannot
+    /// be modified. Easy Connect parameters require Oracle client 19c or later.
+    ///
+    /// See also [`Connection::is_expired`] to discard connections on the client side.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::{Connector, Error};
+    /// # use oracle::test_util;
+    /// # use std::time::Duration;
+    /// # let username = test_util::main_user();
+    /// # let password = test_util::main_password();
+    /// # let connect_string = test_util::connect_string();
+    /// let conn = Connector::new(username, password, connect_string)
+    ///     .expire_time(Duration::from_secs(5 * 60))
+    ///     .sdu(65535)
+    ///     .connect()?;
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn expire_time(&mut self, interval: Duration) -> &mut Connector {
+        let minutes = (interval.as_secs() + 59) / 60;
+        self.expire_time = Some(minutes.try_into().unwrap_or(u32::MAX));
+        self
+    }
+
+    /// Sets the session data unit (SDU) size in bytes
+    ///
+    /// This sets the Oracle Net parameter `SDU` in the connect string
+    /// in the same way as [`Connector::expire_time`]. A larger SDU such as
+    /// 65535 reduces the number of network packets when large rows or LOBs
+    /// are transferred. The actual size is negotiated with the server and
+    /// is limited to the range the client and server versions support.
+    pub fn sdu(&mut self, size: u32) -> &mut Connector {
+        self.sdu = Some(size);
+        self
+    }
+
+    /// Enables or disables `TCP_NODELAY` on the connection's socket
+    ///
+    /// This sets the Oracle Net parameter `TCP.NODELAY` in the connect
+    /// string in the same way as [`Connector::expire_time`]. There is no
+    /// OCI attribute for it. Client versions which don't accept the parameter
+    /// in connect strings use the value in `sqlnet.ora` instead.
+    pub fn tcp_nodelay(&mut self, enable: bool) -> &mut Connector {
+        self.tcp_nodelay = Some(enable);
+        self
+    }
+
     /// Connect an Oracle server using specified parameters
     pub fn connect(&self) -> Result<Connection> {
         let ctxt = Context::new()?;
         let common_params = self.common_params.build(&ctxt);
         let (conn_params, _app_contexts) = self.to_dpi_conn_create_params(&ctxt);
+        let connect_string = add_net_params(&self.connect_string, &self.net_params())?;
         Connection::connect_internal(
             ctxt,
             &self.username,
             &self.password,
-            &self.connect_string,
+            &connect_string,
             common_params,
             conn_params,
         )
     }
 
+    /// Connects an Oracle server and retries on errors which may disappear by waiting
+    ///
+    /// Errors such as `ORA-12514` and `ORA-12541` while the database or the listener
+    /// is starting are retried with exponential backoff according to `policy`.
+    /// See [`RetryPolicy`].
+    pub fn connect_with_retry(&self, policy: &RetryPolicy) -> Result<Connection> {
+        policy.run(|| self.connect())
+    }
+
+    fn net_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(minutes) = self.expire_time {
+            params.push(("expire_time", minutes.to_string()));
+        }
+        if let Some(size) = self.sdu {
+            params.push(("sdu", size.to_string()));
+        }
+        if let Some(enable) = self.tcp_nodelay {
+            params.push(("tcp.nodelay", if enable { "yes" } else { "no" }.to_string()));
+        }
+        params
+    }
+
     fn to_dpi_conn_create_params(
         &self,
         ctxt: &Context,
@@ -521,14 +789,37 @@ impl Connector {
 
 pub(crate) type Conn = Arc<InnerConn>;
 
+type OutputTypeHandler = dyn Fn(&ColumnInfo) -> Option<OracleType> + Send + Sync;
+type InputTypeHandler = dyn Fn(&OracleType) -> Option<OracleType> + Send + Sync;
+
 pub(crate) struct InnerConn {
     ctxt: Context,
     pub(crate) handle: DpiConn,
     pub(crate) autocommit: AtomicBool,
+    empty_string_mode: AtomicU8,
+    pub(crate) strict_conversions: AtomicBool,
+    output_type_handler: Mutex<Option<Arc<OutputTypeHandler>>>,
+    input_type_handler: Mutex<Option<Arc<InputTypeHandler>>>,
+    default_query_params: Mutex<QueryParams>,
     pub(crate) objtype_cache: Mutex<HashMap<String, Arc<ObjectTypeInternal>>>,
+    objtype_search_path: Mutex<Vec<String>>,
+    // SQL hashes keyed by tags passed to `StatementBuilder::tag_with_sql_hash()`
+    sql_hash_tags: Mutex<HashMap<String, u64>>,
     tag: String,
     tag_found: bool,
     is_new_connection: bool,
+    round_trip_count: AtomicUsize,
+    created_at: Instant,
+    // nanoseconds from `created_at` to the start of the last round trip
+    last_used: AtomicU64,
+    drop_on_close: AtomicBool,
+    // set by `Connection::close_with_mode()`
+    closed: AtomicBool,
+    // the generation of the pool when the connection was acquired.
+    // This is `None` for standalone connections.
+    pub(crate) pool_generation: Option<(Arc<AtomicUsize>, usize)>,
+    #[cfg(feature = "check_concurrent_use")]
+    in_round_trip: AtomicBool,
 }
 
 impl InnerConn {
@@ -541,10 +832,25 @@ impl InnerConn {
             ctxt,
             handle: DpiConn::new(handle),
             autocommit: AtomicBool::new(false),
+            empty_string_mode: AtomicU8::new(0),
+            strict_conversions: AtomicBool::new(false),
+            output_type_handler: Mutex::new(None),
+            input_type_handler: Mutex::new(None),
+            default_query_params: Mutex::new(QueryParams::new()),
             objtype_cache: Mutex::new(HashMap::new()),
+            objtype_search_path: Mutex::new(Vec::new()),
+            sql_hash_tags: Mutex::new(HashMap::new()),
             tag: to_rust_str(conn_params.outTag, conn_params.outTagLength),
             tag_found: conn_params.outTagFound != 0,
             is_new_connection: conn_params.outNewSession != 0,
+            round_trip_count: AtomicUsize::new(0),
+            created_at: Instant::now(),
+            last_used: AtomicU64::new(0),
+            drop_on_close: AtomicBool::new(false),
+            closed: AtomicBool::new(false),
+            pool_generation: None,
+            #[cfg(feature = "check_concurrent_use")]
+            in_round_trip: AtomicBool::new(false),
         }
     }
 
@@ -552,14 +858,113 @@ impl InnerConn {
         &self.ctxt
     }
 
+    // Returns the type to define a column, which is changed by the output type handler
+    pub(crate) fn define_type(&self, info: &ColumnInfo) -> OracleType {
+        let handler = match self.output_type_handler.lock() {
+            Ok(guard) => guard.clone(),
+            Err(err) => err.into_inner().clone(),
+        };
+        handler
+            .and_then(|handler| handler(info))
+            .unwrap_or_else(|| info.oracle_type().clone())
+    }
+
+    // Returns the type to bind a value, which is changed by the input type handler
+    pub(crate) fn bind_type(&self, oratype: OracleType) -> OracleType {
+        let handler = match self.input_type_handler.lock() {
+            Ok(guard) => guard.clone(),
+            Err(err) => err.into_inner().clone(),
+        };
+        handler
+            .and_then(|handler| handler(&oratype))
+            .unwrap_or(oratype)
+    }
+
+    /// Marks the connection as in a round trip until the returned guard is dropped.
+    ///
+    /// This increments the value returned by [`Connection::round_trip_count`].
+    /// When the `check_concurrent_use` feature is enabled, this panics if
+    /// another round trip on the connection is in progress.
+    pub(crate) fn round_trip_guard(&self) -> RoundTripGuard<'_> {
+        #[cfg(feature = "check_concurrent_use")]
+        if self
+            .in_round_trip
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            panic!(
+                "concurrent round trips on one connection are detected in {:?}. \
+                 Use a connection per thread or SharedConnection.",
+                std::thread::current()
+            );
+        }
+        self.round_trip_count.fetch_add(1, Ordering::Relaxed);
+        let elapsed = self.created_at.elapsed().as_nanos() as u64;
+        self.last_used.store(elapsed, Ordering::Relaxed);
+        RoundTripGuard { conn: self }
+    }
+
     pub fn autocommit(&self) -> bool {
         self.autocommit.load(Ordering::Relaxed)
     }
 
+    pub fn empty_string_mode(&self) -> EmptyStringMode {
+        EmptyStringMode::from_u8(self.empty_string_mode.load(Ordering::Relaxed))
+    }
+
     pub fn clear_object_type_cache(&self) -> Result<()> {
         self.objtype_cache.lock()?.clear();
         Ok(())
     }
+
+    // Checks whether `tag` was used with SQL text whose hash is `hash`
+    // when it was used before.
+    pub(crate) fn check_sql_hash_tag(&self, tag: &str, hash: u64) -> Result<()> {
+        let mut tags = self.sql_hash_tags.lock()?;
+        match tags.get(tag) {
+            Some(prev) if *prev != hash => Err(Error::invalid_argument(format!(
+                "statement tag {:?} is used for different SQL statements",
+                tag
+            ))),
+            Some(_) => Ok(()),
+            None => {
+                tags.insert(tag.to_string(), hash);
+                Ok(())
+            }
+        }
+    }
+
+    // Returns true when the pooled session must be dropped from the pool
+    // instead of being returned to it.
+    fn should_drop(&self) -> bool {
+        match &self.pool_generation {
+            Some((current, acquired)) => {
+                self.drop_on_close.load(Ordering::Relaxed)
+                    || current.load(Ordering::Relaxed) != *acquired
+            }
+            None => false,
+        }
+    }
+}
+
+impl Drop for InnerConn {
+    fn drop(&mut self) {
+        if self.should_drop() && !self.closed.load(Ordering::Relaxed) {
+            unsafe { dpiConn_close(self.handle.raw(), DPI_MODE_CONN_CLOSE_DROP, ptr::null(), 0) };
+        }
+    }
+}
+
+pub(crate) struct RoundTripGuard<'a> {
+    #[cfg_attr(not(feature = "check_concurrent_use"), allow(dead_code))]
+    conn: &'a InnerConn,
+}
+
+#[cfg(feature = "check_concurrent_use")]
+impl Drop for RoundTripGuard<'_> {
+    fn drop(&mut self) {
+        self.conn.in_round_trip.store(false, Ordering::Release);
+    }
 }
 
 impl fmt::Debug for InnerConn {
@@ -581,6 +986,11 @@ impl fmt::Debug for InnerConn {
 }
 
 /// Connection to an Oracle database
+///
+/// This is `Send` and `Sync`. However calls from multiple threads are
+/// serialized by the Oracle client and share one session and transaction.
+/// Use [`SharedConnection`](crate::SharedConnection) to lock the connection
+/// explicitly or a [connection pool](crate::pool) to use a session per thread.
 pub struct Connection {
     pub(crate) conn: Conn,
 }
@@ -631,6 +1041,46 @@ impl Connection {
         )
     }
 
+    /// Verifies username and password by connecting to an Oracle server
+    /// and closing the connection immediately.
+    ///
+    /// This is for login flows authenticating application users against
+    /// Oracle accounts. No statements are executed and the statement cache
+    /// is disabled to reduce client memory. Use [`Connector`] and close the
+    /// connection got by [`Connector::connect`] to specify additional
+    /// parameters.
+    ///
+    /// The error from the server is returned when the credentials are
+    /// rejected, such as `ORA-01017: invalid username/password; logon denied`,
+    /// `ORA-28000: the account is locked` and `ORA-28001: the password has expired`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use oracle::*;
+    /// match Connection::verify_credentials("scott", "tiger", "") {
+    ///     Ok(()) => println!("authenticated"),
+    ///     Err(err) if err.oci_code() == Some(1017) => println!("invalid username/password"),
+    ///     Err(err) => return Err(err),
+    /// }
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn verify_credentials<U, P, C>(username: U, password: P, connect_string: C) -> Result<()>
+    where
+        U: AsRef<str>,
+        P: AsRef<str>,
+        C: AsRef<str>,
+    {
+        Connector::new(
+            username.as_ref(),
+            password.as_ref(),
+            connect_string.as_ref(),
+        )
+        .stmt_cache_size(0)
+        .connect()?
+        .close()
+    }
+
     fn connect_internal(
         ctxt: Context,
         username: &str,
@@ -643,6 +1093,8 @@ impl Connection {
         let password = OdpiStr::new(password);
         let connect_string = OdpiStr::new(connect_string);
         let mut handle = ptr::null_mut();
+        let observer = observer::current();
+        let start = Instant::now();
         chkerr!(
             &ctxt,
             dpiConn_create(
@@ -660,7 +1112,11 @@ impl Connection {
         );
         ctxt.set_warning();
         conn_params.outNewSession = 1;
-        Ok(Connection::from_dpi_handle(ctxt, handle, &conn_params))
+        let conn = Connection::from_dpi_handle(ctxt, handle, &conn_params);
+        if let Some(observer) = observer {
+            observer.on_connect(&conn, start.elapsed());
+        }
+        Ok(conn)
     }
 
     pub(crate) fn from_conn(conn: Conn) -> Connection {
@@ -693,6 +1149,11 @@ impl Connection {
     }
 
     pub fn close_with_mode(&self, mode: CloseMode) -> Result<()> {
+        let mode = if self.conn.should_drop() {
+            CloseMode::Drop
+        } else {
+            mode
+        };
         let (mode, tag) = match mode {
             CloseMode::Default => (DPI_MODE_CONN_CLOSE_DEFAULT, ""),
             CloseMode::Drop => (DPI_MODE_CONN_CLOSE_DROP, ""),
@@ -703,9 +1164,41 @@ impl Connection {
             self.ctxt(),
             dpiConn_close(self.handle(), mode, tag.ptr, tag.len)
         );
+        self.conn.closed.store(true, Ordering::Relaxed);
+        if let Some(observer) = observer::current() {
+            observer.on_close(self);
+        }
         Ok(())
     }
 
+    /// Marks the connection to be dropped from the connection pool when it is closed
+    ///
+    /// When `enable` is true, the session is dropped from the pool instead of
+    /// being returned to it when [`Connection::close`] is called or the connection
+    /// is dropped, as if [`CloseMode::Drop`] is specified. Use this when the
+    /// session state is known to be unusable, for example after a planned
+    /// maintenance event is notified.
+    ///
+    /// This has no effect on standalone connections. See also [`Pool::invalidate_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use oracle::Error;
+    /// # use oracle::pool::PoolBuilder;
+    /// let pool = PoolBuilder::new("scott", "tiger", "").build()?;
+    /// let conn = pool.get()?;
+    /// conn.set_drop_on_close(true);
+    /// // The session is dropped from the pool here.
+    /// drop(conn);
+    /// # Ok::<(), Error>(())
+    /// ```
+    ///
+    /// [`Pool::invalidate_all`]: crate::pool::Pool::invalidate_all
+    pub fn set_drop_on_close(&self, enable: bool) {
+        self.conn.drop_on_close.store(enable, Ordering::Relaxed);
+    }
+
     /// Creates [`StatementBuilder`][] to create a [`Statement`][]
     ///
     /// # Examples
@@ -796,6 +1289,14 @@ impl Connection {
         BatchBuilder::new(self, sql, max_batch_size)
     }
 
+    /// Creates [UpsertBuilder][], which generates a `MERGE` statement
+    /// inserting or updating rows of `table` and executes it by [`Batch`].
+    ///
+    /// See [`UpsertBuilder`].
+    pub fn upsert<'conn>(&'conn self, table: &str, max_batch_size: usize) -> UpsertBuilder<'conn> {
+        UpsertBuilder::new(self, table, max_batch_size)
+    }
+
     /// Executes a select statement and returns a result set containing [`Row`]s.
     ///
     /// See [Query Methods][].
@@ -836,6 +1337,19 @@ impl Connection {
         Ok(ResultSet::<T>::from_stmt(stmt.stmt))
     }
 
+    /// Executes a select statement and returns all rows as a vector of [`RowValue`]s.
+    ///
+    /// See [`Statement::query_collect()`] and [Query Methods][].
+    ///
+    /// [Query Methods]: https://github.com/kubo/rust-oracle/blob/master/docs/query-methods.md
+    pub fn query_collect<T>(&self, sql: &str, params: &[&dyn ToSql]) -> Result<Vec<T>>
+    where
+        T: RowValue,
+    {
+        let mut stmt = self.statement(sql).build()?;
+        stmt.query_collect(params)
+    }
+
     /// Executes a select statement using named parameters and returns a result set containing [`RowValue`]s.
     ///
     /// See [Query Methods][].
@@ -854,6 +1368,60 @@ impl Connection {
         Ok(ResultSet::<T>::from_stmt(stmt.stmt))
     }
 
+    /// Executes a select statement and returns a result set containing rows in
+    /// the specified page.
+    ///
+    /// `page` is zero-based. The statement is wrapped as
+    /// `select * from (sql) offset n rows fetch next page_size rows only`
+    /// where `n` is `page * page_size`. So `sql` should have an `order by`
+    /// clause to get stable pages.
+    /// This requires Oracle 12.1 or later.
+    ///
+    /// Use [`Connection::query_count`] to get the total number of rows.
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let sql = "select IntCol from TestNumbers order by IntCol";
+    /// let nums = conn
+    ///     .query_page(sql, &[], 1, 4)?
+    ///     .map(|row| row?.get::<_, i32>(0))
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    /// assert_eq!(nums, vec![5, 6, 7, 8]);
+    /// assert_eq!(conn.query_count(sql, &[])?, 10);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn query_page(
+        &self,
+        sql: &str,
+        params: &[&dyn ToSql],
+        page: u64,
+        page_size: u64,
+    ) -> Result<ResultSet<'static, Row>> {
+        if page_size == 0 {
+            return Err(Error::invalid_argument("page_size must not be zero"));
+        }
+        let offset = page
+            .checked_mul(page_size)
+            .ok_or_else(|| Error::out_of_range(format!("page {} is too large", page)))?;
+        let sql = format!(
+            "select * from ({}) offset {} rows fetch next {} rows only",
+            sql, offset, page_size
+        );
+        let mut stmt = self.statement(&sql).max_rows(page_size).build()?;
+        stmt.exec(params, true, "query_page")?;
+        Ok(ResultSet::<Row>::from_stmt(stmt.stmt))
+    }
+
+    /// Gets the number of rows returned by a select statement
+    ///
+    /// The statement is executed as `select count(*) from (sql)`.
+    /// See [`Connection::query_page`].
+    pub fn query_count(&self, sql: &str, params: &[&dyn ToSql]) -> Result<u64> {
+        self.query_row_as(&format!("select count(*) from ({})", sql), params)
+    }
+
     /// Gets one row from a query using positoinal bind parameters.
     ///
     /// See [Query Methods][].
@@ -902,6 +1470,52 @@ impl Connection {
         stmt.query_row_as_named::<T>(params)
     }
 
+    /// Gets at most one row from a query using positoinal bind parameters.
+    ///
+    /// This returns `Ok(None)` when no rows are found and an
+    /// [`ErrorKind::InvalidOperation`](crate::ErrorKind::InvalidOperation) error
+    /// when more than one row is found.
+    ///
+    /// See [Query Methods][].
+    ///
+    /// [Query Methods]: https://github.com/kubo/rust-oracle/blob/master/docs/query-methods.md
+    pub fn query_row_opt(&self, sql: &str, params: &[&dyn ToSql]) -> Result<Option<Row>> {
+        self.query_row_as_opt::<Row>(sql, params)
+    }
+
+    /// Gets at most one row from a query as specified type.
+    ///
+    /// This returns `Ok(None)` when no rows are found and an
+    /// [`ErrorKind::InvalidOperation`](crate::ErrorKind::InvalidOperation) error
+    /// when more than one row is found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::{Error, ErrorKind};
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let sql = "select StringCol from TestStrings where IntCol = :1";
+    /// assert_eq!(conn.query_row_as_opt::<String>(sql, &[&1])?, Some("String 1".to_string()));
+    /// assert_eq!(conn.query_row_as_opt::<String>(sql, &[&100])?, None);
+    ///
+    /// let sql = "select StringCol from TestStrings";
+    /// let err = conn.query_row_as_opt::<String>(sql, &[]).unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::InvalidOperation);
+    /// # Ok::<(), Error>(())
+    /// ```
+    ///
+    /// See [Query Methods][].
+    ///
+    /// [Query Methods]: https://github.com/kubo/rust-oracle/blob/master/docs/query-methods.md
+    pub fn query_row_as_opt<T>(&self, sql: &str, params: &[&dyn ToSql]) -> Result<Option<T>>
+    where
+        T: RowValue,
+    {
+        let mut stmt = self.statement(sql).fetch_array_size(2).build()?;
+        stmt.query_row_as_opt::<T>(params)
+    }
+
     /// Creates a statement, binds values by position and executes it in one call.
     /// It will retunrs `Err` when the statemnet is a select statement.
     ///
@@ -925,6 +1539,26 @@ impl Connection {
         Ok(stmt)
     }
 
+    /// Creates a statement, binds values by position, executes it and returns
+    /// the number of affected rows in one call.
+    ///
+    /// This is the same as `conn.execute(sql, params)?.row_count()`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use oracle::*;
+    /// let conn = Connection::connect("scott", "tiger", "")?;
+    ///
+    /// let count = conn.execute_returning_count("update emp set sal = sal * 1.1 where deptno = :1", &[&10])?;
+    /// println!("{} rows are updated", count);
+    ///
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn execute_returning_count(&self, sql: &str, params: &[&dyn ToSql]) -> Result<u64> {
+        self.statement(sql).build()?.execute_update(params)
+    }
+
     /// Creates a statement, binds values by name and executes it in one call.
     /// It will retunrs `Err` when the statemnet is a select statement.
     ///
@@ -951,18 +1585,139 @@ impl Connection {
 
     /// Commits the current active transaction
     pub fn commit(&self) -> Result<()> {
+        let _guard = self.conn.round_trip_guard();
         chkerr!(self.ctxt(), dpiConn_commit(self.handle()));
         Ok(())
     }
 
-    /// Rolls back the current active transaction
-    pub fn rollback(&self) -> Result<()> {
-        chkerr!(self.ctxt(), dpiConn_rollback(self.handle()));
+    /// Commits the current active transaction with `COMMIT WRITE` options
+    ///
+    /// Relaxing durability by [`CommitOptions::batch`] and [`CommitOptions::nowait`]
+    /// reduces commit latency, for example, in data ingestion where lost
+    /// transactions can be loaded again.
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// use oracle::CommitOptions;
+    /// let conn = test_util::connect()?;
+    /// conn.execute("insert into TestTempTable values(1, 'one')", &[])?;
+    /// conn.commit_with(CommitOptions::new().batch(true).nowait(true))?;
+    /// # conn.execute("delete from TestTempTable", &[])?;
+    /// # conn.commit()?;
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn commit_with(&self, options: CommitOptions) -> Result<()> {
+        self.execute(options.sql(), &[])?;
         Ok(())
     }
 
-    /// Gets autocommit mode.
-    /// It is false by default.
+    /// Gets the logical transaction ID (LTXID) of the connection
+    ///
+    /// The LTXID is used by Transaction Guard to know whether the last
+    /// transaction was committed when the connection is lost while the
+    /// commit outcome is unknown. Get it before committing and pass it to
+    /// [`Connection::ltxid_outcome`] from a new connection after an outage.
+    ///
+    /// It is empty unless the database service is configured for Transaction
+    /// Guard by setting `COMMIT_OUTCOME` to `TRUE`.
+    pub fn ltxid(&self) -> Result<Vec<u8>> {
+        let mut ptr = ptr::null();
+        let mut len = 0;
+        chkerr!(
+            self.ctxt(),
+            dpiConn_getLTXID(self.handle(), &mut ptr, &mut len)
+        );
+        Ok(to_rust_slice(ptr, len).to_vec())
+    }
+
+    /// Gets the outcome of the transaction identified by `ltxid` returned by
+    /// [`Connection::ltxid`] of another connection
+    ///
+    /// This calls `DBMS_APP_CONT.GET_LTXID_OUTCOME`, which requires the
+    /// `EXECUTE` privilege on the package. The procedure also blocks the
+    /// transaction from committing after this call if it was not committed.
+    /// So it is safe to execute the transaction again when
+    /// [`LtxidOutcome::committed`] is false.
+    ///
+    /// ```no_run
+    /// # use oracle::{Connection, Error};
+    /// # let conn = Connection::connect("scott", "tiger", "")?;
+    /// let ltxid = conn.ltxid()?;
+    /// conn.execute("update emp set sal = sal * 1.1 where empno = 7369", &[])?;
+    /// if let Err(err) = conn.commit() {
+    ///     if err.is_connection_usable() != Some(true) {
+    ///         let new_conn = Connection::connect("scott", "tiger", "")?;
+    ///         if !new_conn.ltxid_outcome(&ltxid)?.committed {
+    ///             // The transaction may be executed again.
+    ///         }
+    ///     }
+    /// }
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn ltxid_outcome(&self, ltxid: &[u8]) -> Result<LtxidOutcome> {
+        let mut stmt = self
+            .statement(
+                "declare
+                   committed boolean;
+                   user_call_completed boolean;
+                 begin
+                   dbms_app_cont.get_ltxid_outcome(:1, committed, user_call_completed);
+                   :2 := case when committed then 1 else 0 end;
+                   :3 := case when user_call_completed then 1 else 0 end;
+                 end;",
+            )
+            .build()?;
+        stmt.execute(&[&ltxid, &None::<i32>, &None::<i32>])?;
+        Ok(LtxidOutcome {
+            committed: stmt.bind_value::<_, i32>(2)? != 0,
+            user_call_completed: stmt.bind_value::<_, i32>(3)? != 0,
+        })
+    }
+
+    /// Rolls back the current active transaction
+    pub fn rollback(&self) -> Result<()> {
+        let _guard = self.conn.round_trip_guard();
+        chkerr!(self.ctxt(), dpiConn_rollback(self.handle()));
+        Ok(())
+    }
+
+    /// Begins a new transaction with the specified mode
+    ///
+    /// This must be called before any other statement in the transaction,
+    /// that is, just after connecting or after [`commit`](Connection::commit)
+    /// or [`rollback`](Connection::rollback). Otherwise, `ORA-01453` is returned.
+    /// The transaction ends when it is committed or rolled back.
+    ///
+    /// It fails when autocommit mode is enabled because the transaction
+    /// would end as soon as it begins.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// use oracle::TransactionMode;
+    /// let conn = test_util::connect()?;
+    /// conn.rollback()?;
+    /// conn.begin_transaction(TransactionMode::ReadOnly)?;
+    /// // DML statements fail in read-only transactions.
+    /// assert!(conn.execute("delete from TestTempTable", &[]).is_err());
+    /// conn.rollback()?;
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn begin_transaction(&self, mode: TransactionMode) -> Result<()> {
+        if self.autocommit() {
+            return Err(Error::invalid_operation(
+                "could not begin a transaction in autocommit mode",
+            ));
+        }
+        self.execute(mode.sql(), &[])?;
+        Ok(())
+    }
+
+    /// Gets autocommit mode.
+    /// It is false by default.
     pub fn autocommit(&self) -> bool {
         self.conn.autocommit()
     }
@@ -973,6 +1728,205 @@ impl Connection {
         self.conn.autocommit.store(autocommit, Ordering::Relaxed)
     }
 
+    /// Gets the default query parameters of statements created by this connection.
+    pub fn default_query_params(&self) -> QueryParams {
+        match self.conn.default_query_params.lock() {
+            Ok(params) => params.clone(),
+            Err(err) => err.into_inner().clone(),
+        }
+    }
+
+    /// Sets the default query parameters of statements created by this connection.
+    ///
+    /// This affects statements and batches created by [`Connection::statement`],
+    /// [`Connection::batch`] and methods using them such as [`Connection::query`]
+    /// after this call. See [`QueryParams`] for examples.
+    pub fn set_default_query_params(&mut self, params: QueryParams) {
+        match self.conn.default_query_params.lock() {
+            Ok(mut guard) => *guard = params,
+            Err(err) => *err.into_inner() = params,
+        }
+    }
+
+    /// Gets the mode how an empty string is bound.
+    /// It is [`EmptyStringMode::Null`] by default.
+    pub fn empty_string_mode(&self) -> EmptyStringMode {
+        self.conn.empty_string_mode()
+    }
+
+    /// Sets the mode how an empty `&str` or `String` is bound to placeholders.
+    ///
+    /// This affects values bound after this call through this connection,
+    /// including values in [`Batch`](crate::Batch).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::test_util;
+    /// use oracle::{EmptyStringMode, ErrorKind};
+    /// let conn = test_util::connect()?;
+    ///
+    /// // By default, an empty string is NULL.
+    /// let is_null: bool = conn.query_row_as("select nvl2(:1, 0, 1) from dual", &[&""])?;
+    /// assert!(is_null);
+    ///
+    /// // Bind a single blank instead.
+    /// conn.set_empty_string_mode(EmptyStringMode::Blank);
+    /// let s: String = conn.query_row_as("select :1 || 'x' from dual", &[&""])?;
+    /// assert_eq!(s, " x");
+    ///
+    /// // Reject empty strings.
+    /// conn.set_empty_string_mode(EmptyStringMode::Error);
+    /// let err = conn.query_row_as::<String>("select :1 from dual", &[&""]).unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::InvalidArgument);
+    /// # Ok::<(), oracle::Error>(())
+    /// ```
+    pub fn set_empty_string_mode(&self, mode: EmptyStringMode) {
+        self.conn
+            .empty_string_mode
+            .store(mode.to_u8(), Ordering::Relaxed)
+    }
+
+    /// Gets whether strict numeric conversions are enabled.
+    /// They are disabled by default.
+    pub fn strict_conversions(&self) -> bool {
+        self.conn.strict_conversions.load(Ordering::Relaxed)
+    }
+
+    /// Enables or disables strict numeric conversions.
+    ///
+    /// When they are enabled, getting or setting values through this connection
+    /// fails with an error whose kind is [`ErrorKind::OutOfRange`] instead of
+    /// truncating or rounding the value silently in the following cases.
+    ///
+    /// * A floating-point value with a fractional part is converted to an integer.
+    /// * An integer which isn't exactly representable is converted to `f32` or `f64`.
+    /// * An `f64` value which isn't exactly representable is converted to `f32`.
+    /// * A `NUMBER` value is got as `f32` or `f64` but the float value isn't
+    ///   formatted to the same decimal number, such as `0.12345678901234567891`
+    ///   fetched as `f64`.
+    /// * A signed integer is converted to an unsigned integer or vice versa
+    ///   when it is bound as a native integer.
+    ///
+    /// Integer overflow on getting values is an error regardless of this setting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::test_util;
+    /// use oracle::ErrorKind;
+    /// let conn = test_util::connect()?;
+    /// let sql = "select 0.12345678901234567891 from dual";
+    ///
+    /// // The value is rounded by default.
+    /// let val: f64 = conn.query_row_as(sql, &[])?;
+    /// assert_eq!(val, 0.12345678901234568);
+    ///
+    /// conn.set_strict_conversions(true);
+    /// let err = conn.query_row_as::<f64>(sql, &[]).unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::OutOfRange);
+    ///
+    /// // The value is exactly representable in decimal.
+    /// let val: f64 = conn.query_row_as("select 0.5 from dual", &[])?;
+    /// assert_eq!(val, 0.5);
+    /// # Ok::<(), oracle::Error>(())
+    /// ```
+    ///
+    /// [`ErrorKind::OutOfRange`]: crate::ErrorKind::OutOfRange
+    pub fn set_strict_conversions(&self, enable: bool) {
+        self.conn
+            .strict_conversions
+            .store(enable, Ordering::Relaxed)
+    }
+
+    /// Sets a handler which decides the data type to fetch each column
+    ///
+    /// The handler is called with the column information when a query is
+    /// executed for the first time by a statement. When it returns `Some(oratype)`,
+    /// column values are fetched as `oratype` instead of the column type.
+    /// The Oracle client library converts values to the type.
+    /// [`ColumnInfo::oracle_type`] still returns the column type.
+    ///
+    /// This is useful to apply a policy such as "fetch all `NUMBER` columns
+    /// as strings" without changing each query.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::test_util;
+    /// use oracle::sql_type::OracleType;
+    /// let conn = test_util::connect()?;
+    /// conn.set_output_type_handler(|info| match info.oracle_type() {
+    ///     OracleType::Number(_, _) => Some(OracleType::Varchar2(50)),
+    ///     _ => None,
+    /// });
+    /// let row = conn.query_row("select 123.45 from dual", &[])?;
+    /// assert_eq!(row.sql_values()[0].oracle_type()?, &OracleType::Varchar2(50));
+    /// assert_eq!(row.get::<_, String>(0)?, "123.45");
+    /// conn.clear_output_type_handler();
+    /// # Ok::<(), oracle::Error>(())
+    /// ```
+    pub fn set_output_type_handler<F>(&self, handler: F)
+    where
+        F: Fn(&ColumnInfo) -> Option<OracleType> + Send + Sync + 'static,
+    {
+        let handler: Arc<OutputTypeHandler> = Arc::new(handler);
+        match self.conn.output_type_handler.lock() {
+            Ok(mut guard) => *guard = Some(handler),
+            Err(err) => *err.into_inner() = Some(handler),
+        }
+    }
+
+    /// Removes the handler set by [`Connection::set_output_type_handler`]
+    pub fn clear_output_type_handler(&self) {
+        match self.conn.output_type_handler.lock() {
+            Ok(mut guard) => *guard = None,
+            Err(err) => *err.into_inner() = None,
+        }
+    }
+
+    /// Sets a handler which decides the data type to bind values
+    ///
+    /// The handler is called with the data type decided by [`ToSql::oratype`]
+    /// when a value is bound to a placeholder for the first time by a
+    /// statement or a batch. When it returns `Some(oratype)`, the placeholder
+    /// is bound as `oratype` instead. The value must be convertible to the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::test_util;
+    /// use oracle::sql_type::OracleType;
+    /// let conn = test_util::connect()?;
+    /// // Bind floating-point numbers as NUMBER instead of BINARY_DOUBLE.
+    /// conn.set_input_type_handler(|oratype| match oratype {
+    ///     OracleType::BinaryDouble => Some(OracleType::Number(0, 0)),
+    ///     _ => None,
+    /// });
+    /// let s: String = conn.query_row_as("select dump(:1) from dual", &[&0.5])?;
+    /// assert!(s.starts_with("Typ=2 "));
+    /// conn.clear_input_type_handler();
+    /// # Ok::<(), oracle::Error>(())
+    /// ```
+    pub fn set_input_type_handler<F>(&self, handler: F)
+    where
+        F: Fn(&OracleType) -> Option<OracleType> + Send + Sync + 'static,
+    {
+        let handler: Arc<InputTypeHandler> = Arc::new(handler);
+        match self.conn.input_type_handler.lock() {
+            Ok(mut guard) => *guard = Some(handler),
+            Err(err) => *err.into_inner() = Some(handler),
+        }
+    }
+
+    /// Removes the handler set by [`Connection::set_input_type_handler`]
+    pub fn clear_input_type_handler(&self) {
+        match self.conn.input_type_handler.lock() {
+            Ok(mut guard) => *guard = None,
+            Err(err) => *err.into_inner() = None,
+        }
+    }
+
     /// Cancels execution of running statements in the connection
     ///
     /// # Examples
@@ -1026,6 +1980,10 @@ impl Connection {
     /// Note that the object type is cached in the connection.
     /// However when "CREATE TYPE", "ALTER TYPE" or "DROP TYPE"
     /// is executed, the cache clears.
+    ///
+    /// When `name` isn't qualified by a schema name and isn't found,
+    /// schemas set by [`Connection::set_object_type_search_path`] are
+    /// searched in order.
     pub fn object_type(&self, name: &str) -> Result<ObjectType> {
         {
             let guard = self.conn.objtype_cache.lock()?;
@@ -1035,13 +1993,19 @@ impl Connection {
                 });
             }
         }
-        let s = OdpiStr::new(name);
-        let mut handle = ptr::null_mut();
-        chkerr!(
-            self.ctxt(),
-            dpiConn_getObjectType(self.handle(), s.ptr, s.len, &mut handle)
-        );
-        let res = ObjectType::from_dpi_object_type(self.conn.clone(), DpiObjectType::new(handle));
+        let mut res = self.object_type_uncached(name);
+        if !name.contains('.') {
+            let search_path = self.conn.objtype_search_path.lock()?.clone();
+            for schema in &search_path {
+                match &res {
+                    Err(err) if matches!(err.oci_code(), Some(4043) | Some(22303)) => (),
+                    _ => break,
+                }
+                if let Ok(objtype) = self.object_type_uncached(&format!("{}.{}", schema, name)) {
+                    res = Ok(objtype);
+                }
+            }
+        }
         if let Ok(ref objtype) = res {
             self.conn
                 .objtype_cache
@@ -1051,6 +2015,53 @@ impl Connection {
         res
     }
 
+    fn object_type_uncached(&self, name: &str) -> Result<ObjectType> {
+        let s = OdpiStr::new(name);
+        let mut handle = ptr::null_mut();
+        chkerr!(
+            self.ctxt(),
+            dpiConn_getObjectType(self.handle(), s.ptr, s.len, &mut handle)
+        );
+        ObjectType::from_dpi_object_type(self.conn.clone(), DpiObjectType::new(handle))
+    }
+
+    /// Sets schemas searched by [`Connection::object_type`] for object types
+    /// not found in the current schema
+    ///
+    /// This lets shared type libraries in a common schema resolve without
+    /// schema names. Schemas must be SQL identifiers. Unquoted ones are
+    /// converted to upper case. The object type cache is cleared.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// conn.set_object_type_search_path(&["COMMON", "MDSYS"])?;
+    /// // COMMON.ADDRESS_T when ADDRESS_T isn't in the current schema
+    /// let objtype = conn.object_type("ADDRESS_T")?;
+    /// assert_eq!(objtype.schema(), "COMMON");
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn set_object_type_search_path<S>(&self, schemas: &[S]) -> Result<()>
+    where
+        S: AsRef<str>,
+    {
+        let mut search_path = Vec::with_capacity(schemas.len());
+        for schema in schemas {
+            let schema = schema.as_ref();
+            check_identifier(schema, false)?;
+            search_path.push(if schema.starts_with('"') {
+                schema.to_string()
+            } else {
+                schema.to_uppercase()
+            });
+        }
+        *self.conn.objtype_search_path.lock()? = search_path;
+        self.clear_object_type_cache()
+    }
+
     /// Clear the object type cache in the connection.
     ///
     /// See also [`object_type`](#method.object_type).
@@ -1096,6 +2107,82 @@ impl Connection {
         ))
     }
 
+    /// Quotes `name` as an SQL identifier to embed it in dynamic SQL
+    ///
+    /// This encloses `name` in double quotes, which makes reserved words
+    /// usable as identifiers. Note that quoted identifiers are case sensitive.
+    /// Pass `"EMP"` to refer to a table created as `create table emp ...`.
+    /// Quote each part and join them with `.` to make a qualified name.
+    ///
+    /// This fails when `name` is empty, contains a double quote or a nul
+    /// character, or exceeds the maximum length of identifiers: 128 bytes
+    /// for Oracle 12.2 or later and 30 bytes for others.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// assert_eq!(conn.quote_identifier("TestStrings")?, r#""TestStrings""#);
+    /// assert_eq!(conn.quote_identifier("SELECT")?, r#""SELECT""#);
+    /// assert!(conn.quote_identifier(r#"a" or "b"#).is_err());
+    ///
+    /// let table = format!(
+    ///     "{}.{}",
+    ///     conn.quote_identifier(&test_util::main_user().to_uppercase())?,
+    ///     conn.quote_identifier("TESTSTRINGS")?
+    /// );
+    /// let sql = format!("select count(*) from {}", table);
+    /// assert_eq!(conn.query_row_as::<u32>(&sql, &[])?, 10);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn quote_identifier(&self, name: &str) -> Result<String> {
+        if name.is_empty() {
+            return Err(Error::invalid_argument("empty identifier"));
+        }
+        if name.contains('"') || name.contains('\0') {
+            return Err(Error::invalid_argument(format!(
+                "identifier {:?} contains a double quote or a nul character",
+                name
+            )));
+        }
+        let max_len = if self.server_version()?.0 >= Version::new(12, 2, 0, 0, 0) {
+            128
+        } else {
+            30
+        };
+        if name.len() > max_len {
+            return Err(Error::invalid_argument(format!(
+                "identifier {:?} exceeds {} bytes",
+                name, max_len
+            )));
+        }
+        Ok(format!("\"{}\"", name))
+    }
+
+    /// Quotes `s` as an SQL string literal to embed it in dynamic SQL
+    ///
+    /// This encloses `s` in single quotes and doubles single quotes in it.
+    /// Use bind parameters instead when possible. Literals are required only
+    /// where bind parameters aren't allowed, such as in DDL statements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let lit = conn.quote_literal("O'Reilly");
+    /// assert_eq!(lit, "'O''Reilly'");
+    /// let sql = format!("select {} from dual", lit);
+    /// assert_eq!(conn.query_row_as::<String>(&sql, &[])?, "O'Reilly");
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn quote_literal(&self, s: &str) -> String {
+        format!("'{}'", s.replace('\'', "''"))
+    }
+
     /// Changes the password for the specified user
     pub fn change_password(
         &self,
@@ -1126,8 +2213,9 @@ impl Connection {
     /// It checks the connection by making a network round-trip
     /// between the client and the server.
     ///
-    /// See also [`Connection::status`].
+    /// See also [`Connection::status`] and [`Connection::is_healthy`].
     pub fn ping(&self) -> Result<()> {
+        let _guard = self.conn.round_trip_guard();
         chkerr!(self.ctxt(), dpiConn_ping(self.handle()));
         Ok(())
     }
@@ -1173,6 +2261,40 @@ impl Connection {
         }
     }
 
+    /// Checks whether the connection is usable without network round-trips.
+    ///
+    /// It returns `Ok(false)` when the connection was closed, the
+    /// underlying socket is known to be disconnected or an unrecoverable
+    /// error such as `ORA-03113` occurred in the connection. Otherwise,
+    /// `Ok(true)`. This is cheaper than [`Connection::ping`] and is
+    /// suitable to validate connections before handing them out. However
+    /// there is no guarantee that the next network round-trip will go
+    /// through even when it returns `Ok(true)`.
+    ///
+    /// See also [`Connection::ping`] and [`Connection::status`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// let conn = test_util::connect()?;
+    /// assert_eq!(conn.is_healthy()?, true);
+    /// conn.close()?;
+    /// assert_eq!(conn.is_healthy()?, false);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn is_healthy(&self) -> Result<bool> {
+        let mut is_healthy = 0;
+        if unsafe { dpiConn_getIsHealthy(self.handle(), &mut is_healthy) } == DPI_SUCCESS as i32 {
+            return Ok(is_healthy != 0);
+        }
+        match Error::from_context(self.ctxt()) {
+            err if err.dpi_code() == Some(DPI_ERR_NOT_CONNECTED) => Ok(false),
+            err => Err(err),
+        }
+    }
+
     /// Get the warning when connecting to the database or executing SQL statements.
     ///
     /// This is available to check the following two cases.
@@ -1180,6 +2302,9 @@ impl Connection {
     /// 1. The user account status is in grace period of password expiration. See [Password Change Life Cycle].
     /// 2. A stored procedure is created with PL/SQL compilation errors.
     ///
+    /// The warning is overwritten by any statement executed later.
+    /// Use [`Statement::last_warning`] to get the warning of a specific statement.
+    ///
     /// ```no_run
     /// # use oracle::{Connection, Error};
     /// # use oracle::test_util;
@@ -1398,6 +2523,10 @@ impl Connection {
     }
 
     /// Gets edition associated with the connection
+    ///
+    /// This is the edition specified when the connection was established.
+    /// Use [`Connection::current_edition`] to get the edition changed by
+    /// [`Connection::set_edition`].
     pub fn edition(&self) -> Result<String> {
         let mut s = OdpiStr::new("");
         chkerr!(
@@ -1576,16 +2705,47 @@ impl Connection {
     /// ...
     /// ```
     pub fn startup_database(&self, modes: &[StartupMode]) -> Result<()> {
-        let mut mode_num = 0;
-        for mode in modes {
-            mode_num |= match *mode {
-                StartupMode::Force => DPI_MODE_STARTUP_FORCE,
-                StartupMode::Restrict => DPI_MODE_STARTUP_RESTRICT,
-            };
-        }
         chkerr!(
             self.ctxt(),
-            dpiConn_startupDatabase(self.handle(), mode_num)
+            dpiConn_startupDatabase(self.handle(), startup_mode_num(modes))
+        );
+        Ok(())
+    }
+
+    /// Starts up a database with a parameter file (PFILE)
+    ///
+    /// This corresponds to sqlplus command `startup nomount pfile=...`.
+    /// The `pfile` is a path to a text initialization parameter file
+    /// on the database server. See [`Connection::startup_database`] for
+    /// the connection requirements and what to do after this method.
+    ///
+    /// When `pfile` is empty, this behaves the same as [`Connection::startup_database`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use oracle::*;
+    /// // connect as sysdba with prelim_auth mode
+    /// let conn = Connector::new("sys", "change_on_install", "")
+    ///     .privilege(Privilege::Sysdba)
+    ///     .prelim_auth(true)
+    ///     .connect()?;
+    ///
+    /// // start the instance with the specified parameter file
+    /// conn.startup_database_with_pfile("/u01/app/oracle/dbs/initORCL.ora", &[StartupMode::Restrict])?;
+    /// conn.close()?;
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn startup_database_with_pfile(&self, pfile: &str, modes: &[StartupMode]) -> Result<()> {
+        let pfile = OdpiStr::new(pfile);
+        chkerr!(
+            self.ctxt(),
+            dpiConn_startupDatabaseWithPfile(
+                self.handle(),
+                pfile.ptr,
+                pfile.len,
+                startup_mode_num(modes)
+            )
         );
         Ok(())
     }
@@ -1694,6 +2854,22 @@ impl Connection {
         &self.conn.tag
     }
 
+    /// Gets `NAME=VALUE` pairs in the tag of the connection.
+    ///
+    /// A multi-property tag `LANG=FRENCH;TIME_ZONE=UTC` is split into
+    /// `[("LANG", "FRENCH"), ("TIME_ZONE", "UTC")]`. The value is an empty
+    /// string when a property doesn't contain `=`.
+    ///
+    /// See also [`PoolOptions::tag_properties`].
+    pub fn tag_properties(&self) -> Vec<(&str, &str)> {
+        self.conn
+            .tag
+            .split(';')
+            .filter(|prop| !prop.is_empty())
+            .map(|prop| prop.split_once('=').unwrap_or((prop, "")))
+            .collect()
+    }
+
     /// Gets `true` when the connection is a standalone one
     /// or it is a connection with the specified tag by
     /// [`PoolOptions::tag`].
@@ -1763,6 +2939,415 @@ impl Connection {
         Info::from_dpi(unsafe { &info.assume_init() })
     }
 
+    /// Returns SID, serial number, instance name and container name of the session
+    ///
+    /// They are retrieved by a query, which requires a round trip to the server.
+    /// The serial number is got by `DBMS_DEBUG_JDWP.CURRENT_SESSION_SERIAL`,
+    /// which doesn't require privileges to query `V$SESSION`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let info = conn.session_info()?;
+    /// let sid = conn.query_row_as::<u32>("SELECT SYS_CONTEXT('USERENV', 'SID') FROM DUAL", &[])?;
+    /// assert_eq!(info.sid, sid);
+    /// println!("sid={}, serial#={}", info.sid, info.serial_number);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn session_info(&self) -> Result<SessionInfo> {
+        let con_name = if self.server_version()?.0 >= Version::new(12, 1, 0, 0, 0) {
+            "SYS_CONTEXT('USERENV', 'CON_NAME')"
+        } else {
+            "NULL"
+        };
+        let sql = format!(
+            "SELECT SYS_CONTEXT('USERENV', 'SID'), DBMS_DEBUG_JDWP.CURRENT_SESSION_SERIAL, \
+             SYS_CONTEXT('USERENV', 'INSTANCE_NAME'), {} FROM DUAL",
+            con_name
+        );
+        let (sid, serial_number, instance_name, container_name) =
+            self.query_row_as::<(u32, u32, String, Option<String>)>(&sql, &[])?;
+        Ok(SessionInfo {
+            sid,
+            serial_number,
+            instance_name,
+            container_name,
+        })
+    }
+
+    /// Gets `SYSTIMESTAMP` on the server and measures the round-trip latency
+    ///
+    /// Use [`ServerTime::clock_skew`] to detect the difference between the
+    /// server clock and the local clock.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let server_time = conn.server_time()?;
+    /// println!(
+    ///     "server time: {}, round trip: {:?}, clock skew: {:.3} seconds",
+    ///     server_time.timestamp,
+    ///     server_time.round_trip,
+    ///     server_time.clock_skew()
+    /// );
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn server_time(&self) -> Result<ServerTime> {
+        let mut stmt = self.statement("SELECT SYSTIMESTAMP FROM DUAL").build()?;
+        let start = SystemTime::now();
+        let instant = Instant::now();
+        let timestamp = stmt.query_row_as::<Timestamp>(&[])?;
+        let round_trip = instant.elapsed();
+        Ok(ServerTime {
+            timestamp,
+            round_trip,
+            local_time: start + round_trip / 2,
+        })
+    }
+
+    /// Measures the round-trip latency by [`Connection::ping`]
+    pub fn ping_latency(&self) -> Result<Duration> {
+        let start = Instant::now();
+        self.ping()?;
+        Ok(start.elapsed())
+    }
+
+    /// Gets the approximate number of round trips made by some operations on this connection
+    ///
+    /// Only the following operations are counted: statement executions
+    /// including batch executions, fetches of rows, commits, rollbacks and pings.
+    /// A fetch is counted even when it gets rows prefetched by the execution
+    /// without contacting the server. Other operations making round trips,
+    /// such as LOB operations and object type lookups, aren't counted.
+    /// So this is neither an upper bound nor a lower bound of the actual
+    /// number of round trips.
+    ///
+    /// Compare the values before and after a block of code to detect
+    /// N+1 query patterns or to assert round-trip budgets in tests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let start = conn.round_trip_count();
+    /// conn.ping()?;
+    /// conn.commit()?;
+    /// assert_eq!(conn.round_trip_count() - start, 2);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn round_trip_count(&self) -> u64 {
+        self.conn.round_trip_count.load(Ordering::Relaxed) as u64
+    }
+
+    /// Gets the time when the connection was established or acquired from a pool
+    pub fn created_at(&self) -> Instant {
+        self.conn.created_at
+    }
+
+    /// Gets the time when the connection was last used
+    ///
+    /// This is the start time of the last call counted by
+    /// [`Connection::round_trip_count`], or [`Connection::created_at`]
+    /// when no such calls have been made.
+    pub fn last_used(&self) -> Instant {
+        self.conn.created_at + Duration::from_nanos(self.conn.last_used.load(Ordering::Relaxed))
+    }
+
+    /// Returns `true` when the connection exceeds limits in `policy`
+    ///
+    /// This checks only times recorded on the client side. Use
+    /// [`Connection::ping`] to check whether the connection is alive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// use oracle::conn::ExpirationPolicy;
+    /// use std::time::Duration;
+    ///
+    /// # let mut conn = test_util::connect()?;
+    /// let policy = ExpirationPolicy::new()
+    ///     .max_lifetime(Duration::from_secs(60 * 60))
+    ///     .idle_timeout(Duration::from_secs(5 * 60));
+    /// assert!(!conn.is_expired(&policy));
+    /// if conn.is_expired(&policy) {
+    ///     conn = test_util::connect()?;
+    /// }
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn is_expired(&self, policy: &ExpirationPolicy) -> bool {
+        let now = Instant::now();
+        if let Some(max_lifetime) = policy.max_lifetime {
+            if now.saturating_duration_since(self.created_at()) > max_lifetime {
+                return true;
+            }
+        }
+        if let Some(idle_timeout) = policy.idle_timeout {
+            if now.saturating_duration_since(self.last_used()) > idle_timeout {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Gets common NLS session parameters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let params = conn.nls_params()?;
+    /// assert!(params.date_format.is_some());
+    /// assert_eq!(params.numeric_characters.as_ref().map(|s| s.chars().count()), Some(2));
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn nls_params(&self) -> Result<NlsParams> {
+        let mut params = NlsParams::new();
+        let rows = self.query_as::<(String, Option<String>)>(
+            "SELECT PARAMETER, VALUE FROM NLS_SESSION_PARAMETERS \
+             WHERE PARAMETER IN ('NLS_DATE_FORMAT', 'NLS_NUMERIC_CHARACTERS', 'NLS_SORT')",
+            &[],
+        )?;
+        for row_result in rows {
+            let (name, value) = row_result?;
+            match name.as_str() {
+                "NLS_DATE_FORMAT" => params.date_format = value,
+                "NLS_NUMERIC_CHARACTERS" => params.numeric_characters = value,
+                "NLS_SORT" => params.sort = value,
+                _ => (),
+            }
+        }
+        Ok(params)
+    }
+
+    /// Sets common NLS session parameters by one `ALTER SESSION` statement
+    ///
+    /// Parameters whose values are `None` are left unchanged. Values are
+    /// validated before a round trip and an [`ErrorKind::InvalidArgument`](crate::ErrorKind::InvalidArgument)
+    /// error is returned when any of them is invalid:
+    ///
+    /// * `date_format` must not be empty or contain single quotes.
+    /// * `numeric_characters` must be two distinct characters other than
+    ///   digits, `+`, `-`, `<`, `>` and single quotes.
+    /// * `sort` must be an identifier such as `BINARY` and `XGERMAN_CI`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// use oracle::conn::NlsParams;
+    /// # let conn = test_util::connect()?;
+    ///
+    /// conn.set_nls_params(
+    ///     &NlsParams::new()
+    ///         .date_format("YYYY-MM-DD")
+    ///         .numeric_characters(",."),
+    /// )?;
+    /// let s = conn.query_row_as::<String>(
+    ///     "select TO_CHAR(DATE '2012-03-04') || ' ' || TO_CHAR(1234.5, '9G999D9') from dual",
+    ///     &[],
+    /// )?;
+    /// assert_eq!(s, "2012-03-04  1.234,5");
+    ///
+    /// let params = conn.nls_params()?;
+    /// assert_eq!(params.date_format.as_deref(), Some("YYYY-MM-DD"));
+    /// assert_eq!(params.numeric_characters.as_deref(), Some(",."));
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn set_nls_params(&self, params: &NlsParams) -> Result<()> {
+        if let Some(sql) = params.alter_session_sql()? {
+            self.execute(&sql, &[])?;
+        }
+        Ok(())
+    }
+
+    /// Switches the current container of the session by `ALTER SESSION SET CONTAINER`
+    ///
+    /// `name` must be a PDB name, `CDB$ROOT` or `PDB$SEED`. It must start
+    /// with an alphabetic character and contain only alphanumeric characters,
+    /// `_`, `$` and `#`. Otherwise, an [`ErrorKind::InvalidArgument`](crate::ErrorKind::InvalidArgument)
+    /// error is returned without any round trip.
+    ///
+    /// The object type cache is cleared because object types are defined per container.
+    /// The user needs the `SET CONTAINER` privilege in the target container.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// conn.set_container("PDB1")?;
+    /// assert_eq!(conn.session_info()?.container_name.as_deref(), Some("PDB1"));
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn set_container(&self, name: &str) -> Result<()> {
+        let mut chars = name.chars();
+        let is_valid = chars.next().map_or(false, |c| c.is_ascii_alphabetic())
+            && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '$' | '#'))
+            && name.len() <= 128;
+        if !is_valid {
+            return Err(Error::invalid_argument(format!(
+                "invalid container name {:?}",
+                name
+            )));
+        }
+        self.execute(&format!("ALTER SESSION SET CONTAINER = {}", name), &[])?;
+        self.clear_object_type_cache()
+    }
+
+    /// Sets the SQL translation profile of the session by
+    /// `ALTER SESSION SET SQL_TRANSLATION_PROFILE`
+    ///
+    /// SQL statements written for other databases are translated by the server
+    /// according to the profile created by `DBMS_SQL_TRANSLATOR`. This helps
+    /// applications migrating to Oracle keep their SQL statements while using
+    /// the typed API of this crate. The user needs the `USE` privilege on the
+    /// profile.
+    ///
+    /// `name` must be an SQL identifier optionally qualified by a schema name.
+    /// Otherwise, an [`ErrorKind::InvalidArgument`](crate::ErrorKind::InvalidArgument)
+    /// error is returned without any round trip.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// // begin
+    /// //   dbms_sql_translator.create_profile('MIGRATION_PROFILE');
+    /// //   dbms_sql_translator.register_sql_translation('MIGRATION_PROFILE',
+    /// //     'select top 1 IntCol from TestNumbers',
+    /// //     'select IntCol from TestNumbers fetch first 1 rows only');
+    /// // end;
+    /// conn.set_sql_translation_profile("MIGRATION_PROFILE")?;
+    /// let n: i32 = conn.query_row_as("select top 1 IntCol from TestNumbers", &[])?;
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn set_sql_translation_profile(&self, name: &str) -> Result<()> {
+        check_identifier(name, true)?;
+        self.execute(
+            &format!("ALTER SESSION SET SQL_TRANSLATION_PROFILE = {}", name),
+            &[],
+        )?;
+        Ok(())
+    }
+
+    /// Sets the edition of [Edition-Based Redefinition][] by `ALTER SESSION SET EDITION`
+    ///
+    /// SQL statements executed after this use objects in the edition. This
+    /// lets applications switch to a new edition without reconnecting during
+    /// zero-downtime deployments. The statement fails when a transaction
+    /// is in progress on the session.
+    ///
+    /// `name` must be an SQL identifier. Otherwise, an [`ErrorKind::InvalidArgument`](crate::ErrorKind::InvalidArgument)
+    /// error is returned without any round trip.
+    ///
+    /// [Edition-Based Redefinition]: https://www.oracle.com/pls/topic/lookup?ctx=dblatest&id=GUID-58DE05A0-5DEF-4791-8FA8-F04D11964906
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// conn.set_edition("RELEASE_2")?;
+    /// assert_eq!(conn.current_edition()?, "RELEASE_2");
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn set_edition(&self, name: &str) -> Result<()> {
+        check_identifier(name, false)?;
+        self.execute(&format!("ALTER SESSION SET EDITION = {}", name), &[])?;
+        Ok(())
+    }
+
+    /// Gets the current edition of the session
+    ///
+    /// Unlike [`Connection::edition`], this reflects the edition changed by
+    /// [`Connection::set_edition`]. It requires a round trip.
+    pub fn current_edition(&self) -> Result<String> {
+        self.query_row_as(
+            "SELECT SYS_CONTEXT('USERENV', 'CURRENT_EDITION_NAME') FROM DUAL",
+            &[],
+        )
+    }
+
+    /// Gets the edition in which the object used by the session is actual
+    ///
+    /// This is the `EDITION_NAME` column in `ALL_OBJECTS` for the object,
+    /// which is visible in the current edition. It may be an ancestor of
+    /// the current edition when the object isn't changed in the current one.
+    /// `None` is returned when the object isn't editioned or isn't found.
+    ///
+    /// `object_type` is the `OBJECT_TYPE` column such as `"PACKAGE"` and
+    /// `"PACKAGE BODY"`, which distinguishes objects with the same name.
+    /// `schema`, `name` and `object_type` are compared with the data dictionary
+    /// as they are. Specify them in upper case for unquoted identifiers.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// conn.set_edition("RELEASE_2")?;
+    /// let edition = conn.object_edition("SCOTT", "EMP_PKG", "PACKAGE BODY")?;
+    /// println!("EMP_PKG body used by the session is in {:?}", edition);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn object_edition(
+        &self,
+        schema: &str,
+        name: &str,
+        object_type: &str,
+    ) -> Result<Option<String>> {
+        let mut rows = self.query_as::<Option<String>>(
+            "SELECT EDITION_NAME FROM ALL_OBJECTS \
+             WHERE OWNER = :1 AND OBJECT_NAME = :2 AND OBJECT_TYPE = :3",
+            &[&schema, &name, &object_type],
+        )?;
+        Ok(rows.next().transpose()?.flatten())
+    }
+
+    /// Reads rows logged by the DML error logging clause
+    ///
+    /// `table` is an error logging table specified by
+    /// [`StatementBuilder::log_errors_into`](crate::StatementBuilder::log_errors_into) or
+    /// [`BatchBuilder::log_errors_into`](crate::BatchBuilder::log_errors_into).
+    /// When `tag` is `Some`, only rows logged with the tag are returned.
+    ///
+    /// `table` must be an SQL identifier optionally qualified by a schema name.
+    /// Otherwise, an [`ErrorKind::InvalidArgument`](crate::ErrorKind::InvalidArgument)
+    /// error is returned.
+    pub fn dml_error_log(&self, table: &str, tag: Option<&str>) -> Result<Vec<DmlErrorLogRecord>> {
+        check_identifier(table, true)?;
+        let rows = match tag {
+            Some(tag) => self.query(
+                &format!("SELECT * FROM {} WHERE ORA_ERR_TAG$ = :1", table),
+                &[&tag],
+            )?,
+            None => self.query(&format!("SELECT * FROM {}", table), &[])?,
+        };
+        let mut records = Vec::new();
+        for row in rows {
+            records.push(DmlErrorLogRecord::from_row(&row?)?);
+        }
+        Ok(records)
+    }
+
     /// Gets an OCI handle attribute corresponding to the specified type parameter
     /// See the [`oci_attr` module][crate::oci_attr] for details.
     pub fn oci_attr<T>(&self) -> Result<<<T::DataType as DataType>::Type as ToOwned>::Owned>
@@ -13,6 +13,7 @@
 // (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
 //-----------------------------------------------------------------------------
 
+use crate::error::DPI_ERR_LOAD_LIBRARY;
 #[cfg(doc)]
 use crate::pool::PoolBuilder;
 use crate::util::{os_string_into_ansi_c_string, string_into_c_string};
@@ -23,7 +24,8 @@ use crate::Error;
 use crate::Result;
 use odpic_sys::*;
 use once_cell::sync::OnceCell;
-use std::ffi::{CString, OsString};
+use std::env;
+use std::ffi::{CStr, CString, OsString};
 use std::mem::{self, MaybeUninit};
 use std::os::raw::c_char;
 use std::ptr;
@@ -91,6 +93,66 @@ impl InitParams {
         }
     }
 
+    /// Creates a new initialization parameter from environment variables
+    ///
+    /// The following environment variables are read if they are set.
+    ///
+    /// Environment variable | Method
+    /// ---|---
+    /// `RUST_ORACLE_DRIVER_NAME` | [`InitParams::default_driver_name`]
+    /// `RUST_ORACLE_LOAD_ERROR_URL` | [`InitParams::load_error_url`]
+    /// `RUST_ORACLE_CLIENT_LIB_DIR` | [`InitParams::oracle_client_lib_dir`]
+    /// `RUST_ORACLE_CLIENT_CONFIG_DIR` | [`InitParams::oracle_client_config_dir`]
+    ///
+    /// They make it possible to change the Oracle client location and its
+    /// configuration without changing application code, for example,
+    /// in container images. Note that `ORACLE_HOME`, `TNS_ADMIN`,
+    /// `LD_LIBRARY_PATH` and so on are read by the Oracle client library itself
+    /// and have effect regardless of this method.
+    ///
+    /// # Errors
+    ///
+    /// If `RUST_ORACLE_DRIVER_NAME` or `RUST_ORACLE_LOAD_ERROR_URL` isn't
+    /// valid unicode or any of the environment variables contains null
+    /// characters, an error will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::*;
+    /// // Initialize Oracle client library with parameters from environment variables.
+    /// InitParams::from_env()?.init()?;
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn from_env() -> Result<InitParams> {
+        fn env_string(name: &str) -> Result<Option<String>> {
+            match env::var(name) {
+                Ok(val) => Ok(Some(val)),
+                Err(env::VarError::NotPresent) => Ok(None),
+                Err(err) => Err(Error::invalid_argument(format!(
+                    "environment variable {} is not valid unicode",
+                    name
+                ))
+                .add_source(err)),
+            }
+        }
+
+        let mut params = InitParams::new();
+        if let Some(name) = env_string("RUST_ORACLE_DRIVER_NAME")? {
+            params.default_driver_name(name)?;
+        }
+        if let Some(url) = env_string("RUST_ORACLE_LOAD_ERROR_URL")? {
+            params.load_error_url(url)?;
+        }
+        if let Some(dir) = env::var_os("RUST_ORACLE_CLIENT_LIB_DIR") {
+            params.oracle_client_lib_dir(dir)?;
+        }
+        if let Some(dir) = env::var_os("RUST_ORACLE_CLIENT_CONFIG_DIR") {
+            params.oracle_client_config_dir(dir)?;
+        }
+        Ok(params)
+    }
+
     /// Sets the default driver name to use when creating pools or standalone connections.
     ///
     /// The standard is to set this value to `"<name> : <version>"`, where `<name>`
@@ -153,6 +215,21 @@ impl InitParams {
     /// which is, for example, [CP1252] in English, [CP932] in Japanese.
     /// Otherwise, an error will be returned.
     ///
+    /// # Examples
+    ///
+    /// Use Oracle Instant Client and its configuration files bundled with
+    /// an application instead of ones specified by environment variables.
+    ///
+    /// ```no_run
+    /// # use oracle::*;
+    /// let exe_dir = std::env::current_exe()?.parent().unwrap().to_path_buf();
+    /// InitParams::new()
+    ///     .oracle_client_lib_dir(exe_dir.join("instantclient"))?
+    ///     .oracle_client_config_dir(exe_dir.join("network").join("admin"))?
+    ///     .init()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
     /// [clientlibloading]: https://odpi-c.readthedocs.io/en/latest/user_guide/installation.html#oracle-client-library-loading
     /// [ANSI code page]: https://en.wikipedia.org/wiki/Windows_code_page#ANSI_code_page
     /// [CP1252]: https://en.wikipedia.org/wiki/Windows-1252
@@ -317,7 +394,20 @@ impl Context {
                 last_warning: None,
             })
         } else {
-            Err(Error::from_dpi_error(&unsafe { err.assume_init() }))
+            let err = Error::from_dpi_error(&unsafe { err.assume_init() });
+            match err.db_error() {
+                Some(dberr) if err.dpi_code() == Some(DPI_ERR_LOAD_LIBRARY) => {
+                    let lib_dir = if params.oracleClientLibDir.is_null() {
+                        None
+                    } else {
+                        Some(unsafe { CStr::from_ptr(params.oracleClientLibDir) })
+                    };
+                    let message =
+                        format!("{} ({})", dberr.message(), library_search_paths(lib_dir));
+                    Err(Error::from_db_error(dberr.clone().with_message(message)))
+                }
+                _ => Err(err),
+            }
         }
     }
 
@@ -348,8 +438,13 @@ impl Context {
     // called by Connection, Statement, Batch and Pool to set a warning
     // referred by `Connection::last_warning` later.
     pub fn set_warning(&self) {
+        self.store_warning(DbError::to_warning(self));
+    }
+
+    // same with `set_warning` except that the warning is got by the caller
+    pub fn store_warning(&self, warning: Option<DbError>) {
         if let Some(ref mutex) = self.last_warning {
-            *mutex.lock().unwrap() = DbError::to_warning(self);
+            *mutex.lock().unwrap() = warning;
         }
     }
 
@@ -378,4 +473,81 @@ impl Context {
             params.assume_init()
         }
     }
+
+    #[cfg(feature = "soda")]
+    pub fn soda_oper_options(&self) -> dpiSodaOperOptions {
+        let mut opts = MaybeUninit::uninit();
+        unsafe {
+            dpiContext_initSodaOperOptions(self.context, opts.as_mut_ptr());
+            opts.assume_init()
+        }
+    }
+}
+
+// Describes where the Oracle client library was searched for
+// to help users to fix DPI-1047.
+fn library_search_paths(lib_dir: Option<&CStr>) -> String {
+    if let Some(dir) = lib_dir {
+        return format!(
+            "searched only in oracle_client_lib_dir: {}",
+            dir.to_string_lossy()
+        );
+    }
+    #[cfg(windows)]
+    const ENV_NAMES: &[&str] = &["PATH"];
+    #[cfg(target_os = "macos")]
+    const ENV_NAMES: &[&str] = &["DYLD_LIBRARY_PATH", "ORACLE_HOME"];
+    #[cfg(not(any(windows, target_os = "macos")))]
+    const ENV_NAMES: &[&str] = &["LD_LIBRARY_PATH", "ORACLE_HOME"];
+    let vars = ENV_NAMES
+        .iter()
+        .map(|name| match env::var_os(name) {
+            Some(val) => format!("{}={}", name, val.to_string_lossy()),
+            None => format!("{} is not set", name),
+        })
+        .collect::<Vec<_>>();
+    format!("searched with {}", vars.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn init_params_from_env() -> Result<()> {
+        env::set_var("RUST_ORACLE_DRIVER_NAME", "test driver : 1.0");
+        env::set_var("RUST_ORACLE_LOAD_ERROR_URL", "https://example.com/install");
+        env::set_var("RUST_ORACLE_CLIENT_LIB_DIR", "/opt/oracle/instantclient");
+        env::remove_var("RUST_ORACLE_CLIENT_CONFIG_DIR");
+        let params = InitParams::from_env()?;
+        assert_eq!(
+            params
+                .default_driver_name
+                .as_ref()
+                .map(|s| s.to_str().unwrap()),
+            Some("test driver : 1.0")
+        );
+        assert_eq!(
+            params.load_error_url.as_ref().map(|s| s.to_str().unwrap()),
+            Some("https://example.com/install")
+        );
+        assert_eq!(
+            params
+                .oracle_client_lib_dir
+                .as_ref()
+                .map(|s| s.to_str().unwrap()),
+            Some("/opt/oracle/instantclient")
+        );
+        assert_eq!(params.oracle_client_config_dir, None);
+        Ok(())
+    }
+
+    #[test]
+    fn library_search_paths_with_lib_dir() {
+        let dir = CString::new("/opt/oracle/instantclient").unwrap();
+        assert_eq!(
+            library_search_paths(Some(&dir)),
+            "searched only in oracle_client_lib_dir: /opt/oracle/instantclient"
+        );
+    }
 }
@@ -37,6 +37,15 @@ pub(crate) const DPI_ERR_NOT_CONNECTED: i32 = 1010;
 // DPI-1019: buffer size of %u is too small
 pub(crate) const DPI_ERR_BUFFER_SIZE_TOO_SMALL: i32 = 1019;
 
+// DPI-1047: Cannot locate a %s-bit Oracle Client library: "%s". See %s for help
+pub(crate) const DPI_ERR_LOAD_LIBRARY: i32 = 1047;
+
+// DPI-1067: call timeout of %u ms exceeded with ORA-%05d
+const DPI_ERR_CALL_TIMEOUT: i32 = 1067;
+
+// DPI-1080: connection was closed by ORA-%05d
+const DPI_ERR_CONN_CLOSED: i32 = 1080;
+
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[non_exhaustive]
 /// A list of error categories.
@@ -98,6 +107,14 @@ pub enum ErrorKind {
     /// See ["Error Handling with batch errors"](Batch#error-handling-with-batch-errors)
     BatchErrors,
 
+    /// Error when a call or a connection attempt times out, such as
+    /// `DPI-1067: call timeout of %u ms exceeded`, `ORA-03136: inbound connection timed out`
+    /// and `ORA-03156: OCI call timed out`.
+    ///
+    /// Use [`Error::is_connection_usable`] to decide whether to retry the call
+    /// on the same connection or to reconnect.
+    Timeout,
+
     /// Internal error. When you get this error, please report it with a test case to reproduce it.
     InternalError,
 
@@ -234,6 +251,41 @@ impl Error {
     pub(crate) fn from_dpi_error(err: &dpiErrorInfo) -> Error {
         Error::from_db_error(DbError::from_dpi_error(err))
     }
+
+    /// Returns whether the connection where the error occurred is still usable.
+    ///
+    /// This returns `Some(true)` for call timeouts, after which Oracle client
+    /// libraries reset the call and the connection can be used again.
+    /// This returns `Some(false)` when the error indicates that the session
+    /// is dead or the connection was not established, such as `ORA-03114: not connected to ORACLE`
+    /// and `DPI-1080: connection was closed`. Reconnect in this case.
+    /// Otherwise, this returns `None`, which means that the error doesn't
+    /// tell it. Use [`Connection::ping`] to check it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use oracle::{Connection, Error, ErrorKind};
+    /// # use std::time::Duration;
+    /// let mut conn = Connection::connect("scott", "tiger", "")?;
+    /// conn.set_call_timeout(Some(Duration::from_secs(1)))?;
+    /// match conn.execute("begin dbms_session.sleep(2); end;", &[]) {
+    ///     Err(err) if err.kind() == ErrorKind::Timeout => {
+    ///         if err.is_connection_usable() != Some(true) {
+    ///             conn = Connection::connect("scott", "tiger", "")?;
+    ///         }
+    ///     }
+    ///     result => {
+    ///         result?;
+    ///     }
+    /// }
+    /// # Ok::<(), Error>(())
+    /// ```
+    ///
+    /// [`Connection::ping`]: crate::Connection::ping
+    pub fn is_connection_usable(&self) -> Option<bool> {
+        self.db_error()?.is_connection_usable()
+    }
 }
 
 #[cfg(feature = "struct_error")]
@@ -300,6 +352,11 @@ impl Error {
         } else {
             (ErrorKind::OciError, "OCI")
         };
+        let kind = if dberr.is_timeout() {
+            ErrorKind::Timeout
+        } else {
+            kind
+        };
         Error::new(kind, format!("{} Error: {}", message_prefix, dberr.message)).add_dberr(dberr)
     }
 
@@ -323,6 +380,11 @@ impl Error {
     pub fn oci_code(&self) -> Option<i32> {
         match (self.kind, &self.dberr) {
             (ErrorKind::OciError, Some(dberr)) if dberr.code != 0 => Some(dberr.code),
+            (ErrorKind::Timeout, Some(dberr))
+                if dberr.code != 0 && !dberr.message.starts_with("DPI") =>
+            {
+                Some(dberr.code)
+            }
             _ => None,
         }
     }
@@ -330,7 +392,9 @@ impl Error {
     /// Returns [ODPI-C](https://oracle.github.io/odpi/) error code.
     pub fn dpi_code(&self) -> Option<i32> {
         match (self.kind, &self.dberr) {
-            (ErrorKind::DpiError, Some(dberr)) => dpi_error_in_message(&dberr.message),
+            (ErrorKind::DpiError, Some(dberr)) | (ErrorKind::Timeout, Some(dberr)) => {
+                dpi_error_in_message(&dberr.message)
+            }
             _ => None,
         }
     }
@@ -527,6 +591,7 @@ impl Error {
     /// Returns the corresponding [`ErrorKind`] for this error.
     pub fn kind(&self) -> ErrorKind {
         match self {
+            Error::OciError(err) | Error::DpiError(err) if err.is_timeout() => ErrorKind::Timeout,
             Error::OciError(_) => ErrorKind::OciError,
             Error::DpiError(_) => ErrorKind::DpiError,
             Error::NullValue => ErrorKind::NullValue,
@@ -740,6 +805,7 @@ pub struct DbError {
     message: String,
     fn_name: Cow<'static, str>,
     action: Cow<'static, str>,
+    sql_state: Cow<'static, str>,
     is_recoverable: bool,
     is_warning: bool,
 }
@@ -752,12 +818,17 @@ impl DbError {
             message: to_rust_str(err.message, err.messageLength),
             fn_name: unsafe { CStr::from_ptr(err.fnName) }.to_string_lossy(),
             action: unsafe { CStr::from_ptr(err.action) }.to_string_lossy(),
+            sql_state: unsafe { CStr::from_ptr(err.sqlState) }.to_string_lossy(),
             is_recoverable: err.isRecoverable != 0,
             is_warning: err.isWarning != 0,
         }
     }
 
     pub(crate) fn to_warning(ctxt: &Context) -> Option<DbError> {
+        #[cfg(feature = "trace_dpi_calls")]
+        if let Some(warning) = crate::observer::captured_warning() {
+            return warning;
+        }
         let err = unsafe {
             let mut err = MaybeUninit::uninit();
             dpiContext_getError(ctxt.context, err.as_mut_ptr());
@@ -770,7 +841,13 @@ impl DbError {
         }
     }
 
-    /// Creates a new DbError. Note that its `is_recoverable` and `is_warning` values are always `false`.
+    // Replaces the message keeping other fields such as `sql_state`.
+    pub(crate) fn with_message(self, message: String) -> DbError {
+        DbError { message, ..self }
+    }
+
+    /// Creates a new DbError. Note that its `is_recoverable` and `is_warning` values are always `false`
+    /// and its `sql_state` value is always an empty string.
     pub fn new<M, F, A>(code: i32, offset: u32, message: M, fn_name: F, action: A) -> DbError
     where
         M: Into<String>,
@@ -783,6 +860,7 @@ impl DbError {
             message: message.into(),
             fn_name: fn_name.into(),
             action: action.into(),
+            sql_state: Cow::Borrowed(""),
             is_recoverable: false,
             is_warning: false,
         }
@@ -813,6 +891,90 @@ impl DbError {
         &self.action
     }
 
+    /// The five character SQLSTATE code such as `"42000"` corresponding to the error
+    pub fn sql_state(&self) -> &str {
+        &self.sql_state
+    }
+
+    /// Parses the error message into an error stack.
+    ///
+    /// When an error occurs in PL/SQL, the error message consists of
+    /// multiple lines such as the following.
+    ///
+    /// ```text
+    /// ORA-01476: divisor is equal to zero
+    /// ORA-06512: at "SCOTT.MY_PROC", line 5
+    /// ORA-06512: at line 1
+    /// ```
+    ///
+    /// Each line is returned as an [`ErrorFrame`]. Lines not starting with an
+    /// error code such as `ORA-NNNNN:` are appended to the message of the
+    /// previous frame.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let err = conn
+    ///     .execute("begin raise_application_error(-20001, 'custom error'); end;", &[])
+    ///     .unwrap_err();
+    /// let stack = err.db_error().unwrap().error_stack();
+    /// assert_eq!(stack[0].code(), 20001);
+    /// assert_eq!(stack[0].message(), "custom error");
+    /// assert_eq!(stack[1].code(), 6512);
+    /// assert_eq!(stack[1].line(), Some(1));
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn error_stack(&self) -> Vec<ErrorFrame> {
+        let mut frames: Vec<ErrorFrame> = Vec::new();
+        for line in self.message.lines() {
+            match ErrorFrame::parse(line) {
+                Some(frame) => frames.push(frame),
+                None => match frames.last_mut() {
+                    Some(frame) => {
+                        frame.message.push('\n');
+                        frame.message.push_str(line);
+                    }
+                    None => frames.push(ErrorFrame {
+                        prefix: String::new(),
+                        code: self.code,
+                        message: line.to_string(),
+                        object_name: None,
+                        line: None,
+                    }),
+                },
+            }
+        }
+        frames
+    }
+
+    // Returns true when the error is caused by a timeout.
+    pub(crate) fn is_timeout(&self) -> bool {
+        match dpi_error_in_message(&self.message) {
+            Some(code) => code == DPI_ERR_CALL_TIMEOUT,
+            None => matches!(self.code, 3136 | 3156 | 12170),
+        }
+    }
+
+    // Returns whether the connection where the error occurred is still usable.
+    // The ORA error codes are same with those by which ODPI-C marks sessions as dead.
+    fn is_connection_usable(&self) -> Option<bool> {
+        match dpi_error_in_message(&self.message) {
+            Some(DPI_ERR_CALL_TIMEOUT) => Some(true),
+            Some(DPI_ERR_CONN_CLOSED) | Some(DPI_ERR_NOT_CONNECTED) => Some(false),
+            Some(_) => None,
+            None => match self.code {
+                3156 => Some(true),
+                22 | 28 | 31 | 45 | 378 | 602 | 603 | 609 | 1012 | 1041 | 1043 | 1089 | 1092
+                | 2396 | 3113 | 3114 | 3122 | 3135 | 3136 | 12153 | 12170 | 12537 | 12547
+                | 12570 | 12583 | 27146 | 28511 | 56600 => Some(false),
+                _ => None,
+            },
+        }
+    }
+
     /// A boolean value indicating if the error is recoverable. This always retruns `false` unless both client and server are at release 12.1 or higher.
     pub fn is_recoverable(&self) -> bool {
         self.is_recoverable
@@ -826,6 +988,72 @@ impl DbError {
     }
 }
 
+/// A frame of an error stack returned by [`DbError::error_stack`]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ErrorFrame {
+    prefix: String,
+    code: i32,
+    message: String,
+    object_name: Option<String>,
+    line: Option<u32>,
+}
+
+impl ErrorFrame {
+    // Parses "ORA-06512: at \"SCOTT.MY_PROC\", line 5" and so on.
+    fn parse(line: &str) -> Option<ErrorFrame> {
+        let (head, message) = line.split_once(": ")?;
+        let (prefix, code) = head.split_once('-')?;
+        if prefix.is_empty() || !prefix.bytes().all(|c| c.is_ascii_uppercase()) {
+            return None;
+        }
+        let code = code.parse().ok()?;
+        let (object_name, line) = match message.strip_prefix("at ") {
+            Some(location) => {
+                let (object_name, line) = match location.rsplit_once(", line ") {
+                    Some((name, line)) => (Some(name), line),
+                    None => (None, location.strip_prefix("line ").unwrap_or("")),
+                };
+                let object_name = object_name.map(|name| name.trim_matches('"').to_string());
+                (object_name, line.parse().ok())
+            }
+            None => (None, None),
+        };
+        Some(ErrorFrame {
+            prefix: prefix.to_string(),
+            code,
+            message: message.to_string(),
+            object_name,
+            line,
+        })
+    }
+
+    /// The prefix of the error code such as `"ORA"`, `"PLS"` and `"DPI"`
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// The error code. For example 6512 for `"ORA-06512: at line 1"`
+    pub fn code(&self) -> i32 {
+        self.code
+    }
+
+    /// The error message without the error code
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The name of the PL/SQL object such as `"SCOTT.MY_PROC"` where the error occurred.
+    /// This is `None` for anonymous blocks and frames without location.
+    pub fn object_name(&self) -> Option<&str> {
+        self.object_name.as_deref()
+    }
+
+    /// The line number in the PL/SQL object or anonymous block where the error occurred
+    pub fn line(&self) -> Option<u32> {
+        self.line
+    }
+}
+
 #[cfg(feature = "struct_error")]
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -979,6 +1207,7 @@ fn dpi_error_in_message(message: &str) -> Option<i32> {
     None
 }
 
+#[cfg(not(feature = "trace_dpi_calls"))]
 #[macro_export]
 #[doc(hidden)]
 macro_rules! chkerr {
@@ -998,6 +1227,31 @@ macro_rules! chkerr {
     }};
 }
 
+#[cfg(feature = "trace_dpi_calls")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! chkerr {
+    ($ctxt:expr, $code:expr) => {{
+        let trace = $crate::observer::DpiCallTrace::start(stringify!($code));
+        #[allow(unused_unsafe)]
+        let code = unsafe { $code };
+        // `?` isn't used because the error type may not be inferred in closures.
+        #[allow(clippy::question_mark)]
+        if let Err(err) = trace.end($ctxt, code) {
+            return Err(err);
+        }
+    }};
+    ($ctxt:expr, $code:expr, $cleanup:stmt) => {{
+        let trace = $crate::observer::DpiCallTrace::start(stringify!($code));
+        #[allow(unused_unsafe)]
+        let code = unsafe { $code };
+        if let Err(err) = trace.end($ctxt, code) {
+            $cleanup
+            return Err(err);
+        }
+    }};
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1011,6 +1265,86 @@ mod tests {
         assert_eq!(Some(1234), dpi_error_in_message("DPI-1234: xxx"));
     }
 
+    #[test]
+    fn db_error_with_message() {
+        let dberr = DbError {
+            code: 0,
+            offset: 0,
+            message: "DPI-1047: Cannot locate a 64-bit Oracle Client library".into(),
+            fn_name: "dpiContext_createWithParams".into(),
+            action: "load library".into(),
+            sql_state: "HY000".into(),
+            is_recoverable: true,
+            is_warning: false,
+        };
+        let message = format!("{} (searched in /opt/oracle)", dberr.message());
+        let err = Error::from_db_error(dberr.clone().with_message(message.clone()));
+        let new_dberr = err.db_error().unwrap();
+        assert_eq!(new_dberr.message(), message);
+        assert_eq!(new_dberr.sql_state(), "HY000");
+        assert!(new_dberr.is_recoverable());
+        assert_eq!(new_dberr.fn_name(), dberr.fn_name());
+        assert_eq!(new_dberr.action(), dberr.action());
+        assert_eq!(err.dpi_code(), Some(1047));
+    }
+
+    #[test]
+    fn error_stack() {
+        let dberr = DbError::new(
+            1476,
+            0,
+            "ORA-01476: divisor is equal to zero\nORA-06512: at \"SCOTT.MY_PROC\", line 5\nORA-06512: at line 1",
+            "dpiStmt_execute",
+            "execute",
+        );
+        let stack = dberr.error_stack();
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack[0].prefix(), "ORA");
+        assert_eq!(stack[0].code(), 1476);
+        assert_eq!(stack[0].message(), "divisor is equal to zero");
+        assert_eq!(stack[0].object_name(), None);
+        assert_eq!(stack[0].line(), None);
+        assert_eq!(stack[1].code(), 6512);
+        assert_eq!(stack[1].object_name(), Some("SCOTT.MY_PROC"));
+        assert_eq!(stack[1].line(), Some(5));
+        assert_eq!(stack[2].code(), 6512);
+        assert_eq!(stack[2].object_name(), None);
+        assert_eq!(stack[2].line(), Some(1));
+    }
+
+    #[test]
+    fn timeout_and_connection_usable() {
+        let dberr = |code, message: &str| {
+            Error::from_db_error(DbError::new(code, 0, message, "dpiStmt_execute", "execute"))
+        };
+        let err = dberr(
+            3156,
+            "DPI-1067: call timeout of 1000 ms exceeded with ORA-03156",
+        );
+        assert_eq!(err.kind(), ErrorKind::Timeout);
+        assert_eq!(err.dpi_code(), Some(1067));
+        assert_eq!(err.oci_code(), None);
+        assert_eq!(err.is_connection_usable(), Some(true));
+
+        let err = dberr(3136, "ORA-03136: inbound connection timed out");
+        assert_eq!(err.kind(), ErrorKind::Timeout);
+        assert_eq!(err.oci_code(), Some(3136));
+        assert_eq!(err.is_connection_usable(), Some(false));
+
+        let err = dberr(3114, "DPI-1080: connection was closed by ORA-03114");
+        assert_eq!(err.kind(), ErrorKind::DpiError);
+        assert_eq!(err.is_connection_usable(), Some(false));
+
+        let err = dberr(3114, "ORA-03114: not connected to ORACLE");
+        assert_eq!(err.kind(), ErrorKind::OciError);
+        assert_eq!(err.is_connection_usable(), Some(false));
+
+        let err = dberr(1, "ORA-00001: unique constraint (SCOTT.PK) violated");
+        assert_eq!(err.kind(), ErrorKind::OciError);
+        assert_eq!(err.is_connection_usable(), None);
+        assert_eq!(Error::null_value().is_connection_usable(), None);
+    }
+
     #[test]
     fn new_and_add_source() {
         let err = Error::new(ErrorKind::Other, "custom error");
@@ -0,0 +1,227 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2025 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use crate::sql_type::ToSql;
+use crate::Error;
+use crate::Result;
+
+/// A helper to bind a variable-length list such as values in `IN (...)`
+///
+/// [`InList::expand`] replaces a bind placeholder `:name` in a SQL statement
+/// with `:name_1, :name_2, ..., :name_N` where `N` is the number of values.
+/// Values are never embedded into the SQL text, so they are safe from SQL
+/// injection. Pass [`InList::sql`] and [`InList::params`] to methods
+/// taking named parameters such as [`Connection::query_named`](crate::Connection::query_named).
+///
+/// An empty list is replaced with `NULL`. Note that `col IN (NULL)` and
+/// `col NOT IN (NULL)` match no rows.
+///
+/// The maximum number of expressions in a list is 1000 in Oracle (ORA-01795).
+/// Use a collection type with `TABLE()` for more values.
+///
+/// # Examples
+///
+/// ```
+/// # use oracle::Error;
+/// # use oracle::test_util;
+/// use oracle::InList;
+/// # let conn = test_util::connect()?;
+/// let ids = [1, 3, 5];
+/// let mut list = InList::new("select StringCol from TestStrings where IntCol in (:ids) order by IntCol");
+/// list.expand("ids", &ids)?;
+/// assert_eq!(
+///     list.sql(),
+///     "select StringCol from TestStrings where IntCol in (:ids_1, :ids_2, :ids_3) order by IntCol"
+/// );
+/// let rows = conn
+///     .query_as_named::<String>(list.sql(), &list.params())?
+///     .collect::<Result<Vec<_>, _>>()?;
+/// assert_eq!(rows, ["String 1", "String 3", "String 5"]);
+/// # Ok::<(), Error>(())
+/// ```
+pub struct InList<'a> {
+    sql: String,
+    names: Vec<String>,
+    values: Vec<&'a dyn ToSql>,
+}
+
+impl<'a> InList<'a> {
+    /// Creates a helper for `sql`.
+    pub fn new(sql: &str) -> InList<'a> {
+        InList {
+            sql: sql.into(),
+            names: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    /// Replaces all bind placeholders `:name` with as many placeholders as `values`.
+    ///
+    /// Placeholders in string literals, quoted identifiers and comments are
+    /// ignored. An error is returned when `name` isn't a valid bind name or
+    /// no placeholders are found.
+    pub fn expand<T>(&mut self, name: &str, values: &'a [T]) -> Result<&mut InList<'a>>
+    where
+        T: ToSql,
+    {
+        let name = name.strip_prefix(':').unwrap_or(name);
+        if !is_bind_name(name) {
+            return Err(Error::invalid_argument(format!(
+                "invalid bind name {:?}",
+                name
+            )));
+        }
+        let ranges = find_placeholders(&self.sql, name);
+        if ranges.is_empty() {
+            return Err(Error::invalid_argument(format!(
+                "bind placeholder :{} is not found",
+                name
+            )));
+        }
+        let names = (1..=values.len())
+            .map(|i| format!("{}_{}", name, i))
+            .collect::<Vec<_>>();
+        let replacement = if names.is_empty() {
+            "NULL".to_string()
+        } else {
+            names
+                .iter()
+                .map(|name| format!(":{}", name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let mut sql = String::with_capacity(self.sql.len() + ranges.len() * replacement.len());
+        let mut pos = 0;
+        for (start, end) in ranges {
+            sql.push_str(&self.sql[pos..start]);
+            sql.push_str(&replacement);
+            pos = end;
+        }
+        sql.push_str(&self.sql[pos..]);
+        self.sql = sql;
+        self.names.extend(names);
+        self.values
+            .extend(values.iter().map(|val| val as &dyn ToSql));
+        Ok(self)
+    }
+
+    /// Returns the SQL statement after expansion.
+    pub fn sql(&self) -> &str {
+        &self.sql
+    }
+
+    /// Returns named parameters for the expanded placeholders.
+    ///
+    /// Add parameters for other placeholders to the returned vector if any.
+    pub fn params(&self) -> Vec<(&str, &dyn ToSql)> {
+        self.names
+            .iter()
+            .map(|name| name.as_str())
+            .zip(self.values.iter().copied())
+            .collect()
+    }
+}
+
+fn is_bind_char(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || matches!(b, b'_' | b'$' | b'#')
+}
+
+fn is_bind_name(name: &str) -> bool {
+    name.bytes()
+        .next()
+        .map_or(false, |b| b.is_ascii_alphabetic())
+        && name.bytes().all(is_bind_char)
+}
+
+// Returns the position just after `pat` found from `start`, or the end of `bytes`.
+fn skip_until(bytes: &[u8], start: usize, pat: &[u8]) -> usize {
+    bytes[start.min(bytes.len())..]
+        .windows(pat.len())
+        .position(|w| w == pat)
+        .map_or(bytes.len(), |pos| start + pos + pat.len())
+}
+
+// Returns byte ranges of bind placeholders `:name` in `sql`, skipping
+// string literals, quoted identifiers and comments.
+fn find_placeholders(sql: &str, name: &str) -> Vec<(usize, usize)> {
+    let bytes = sql.as_bytes();
+    let mut ranges = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\'' => i = skip_until(bytes, i + 1, b"'"),
+            b'"' => i = skip_until(bytes, i + 1, b"\""),
+            b'-' if bytes.get(i + 1) == Some(&b'-') => i = skip_until(bytes, i + 2, b"\n"),
+            b'/' if bytes.get(i + 1) == Some(&b'*') => i = skip_until(bytes, i + 2, b"*/"),
+            b':' => {
+                let start = i + 1;
+                let end = start
+                    + bytes[start..]
+                        .iter()
+                        .take_while(|b| is_bind_char(**b))
+                        .count();
+                if sql[start..end].eq_ignore_ascii_case(name) {
+                    ranges.push((i, end));
+                }
+                i = end;
+            }
+            _ => i += 1,
+        }
+    }
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expand_in_list() {
+        let ids = [10, 20];
+        let names = ["a"];
+        let mut list = InList::new(
+            "select * from t where id in (:ids) and name in (:NAMES) -- :ids\n and note = ':ids' /* :ids */ and x = :ids2",
+        );
+        list.expand("ids", &ids).unwrap();
+        list.expand(":names", &names).unwrap();
+        assert_eq!(
+            list.sql(),
+            "select * from t where id in (:ids_1, :ids_2) and name in (:names_1) -- :ids\n and note = ':ids' /* :ids */ and x = :ids2"
+        );
+        let params = list.params();
+        assert_eq!(
+            params.iter().map(|p| p.0).collect::<Vec<_>>(),
+            ["ids_1", "ids_2", "names_1"]
+        );
+    }
+
+    #[test]
+    fn expand_empty_in_list() {
+        let ids: [i32; 0] = [];
+        let mut list = InList::new("select * from t where id in (:ids)");
+        list.expand("ids", &ids).unwrap();
+        assert_eq!(list.sql(), "select * from t where id in (NULL)");
+        assert!(list.params().is_empty());
+    }
+
+    #[test]
+    fn expand_in_list_error() {
+        let ids = [1];
+        let mut list = InList::new("select * from t where id in (:ids)");
+        assert!(list.expand("id", &ids).is_err());
+        assert!(list.expand("ids)--", &ids).is_err());
+        assert!(list.expand("", &ids).is_err());
+    }
+}
@@ -0,0 +1,270 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2025 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+//! Load many rows into a table by array DML
+//!
+//! [`BulkLoader`] inserts rows by [`Batch`]. When the `APPEND_VALUES` hint is
+//! enabled by [`BulkLoadOptions::append_values`], the server writes rows above
+//! the high water mark of the table as direct-path INSERT does.
+//!
+//! Note that OCI direct path loading APIs used by SQL\*Loader aren't
+//! available because ODPI-C doesn't expose them.
+use crate::batch::check_identifier;
+use crate::sql_type::OracleType;
+use crate::sql_type::ToSql;
+use crate::Batch;
+use crate::BatchBindIndex;
+use crate::Connection;
+use crate::Error;
+use crate::Result;
+use std::time::{Duration, Instant};
+
+/// Options of [`BulkLoader`], whose `append_values` option makes it commit after each batch
+///
+/// By default, rows are inserted by conventional INSERT 1000 rows at a time
+/// and nothing is committed by the loader.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BulkLoadOptions {
+    batch_size: usize,
+    append_values: bool,
+}
+
+impl BulkLoadOptions {
+    /// Creates options with the default values
+    pub fn new() -> BulkLoadOptions {
+        BulkLoadOptions {
+            batch_size: 1000,
+            append_values: false,
+        }
+    }
+
+    /// Sets the number of rows sent to the server in one round trip.
+    ///
+    /// Memory to hold bind values is allocated for this number of rows.
+    /// This is `1000` by default.
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Sets whether the `APPEND_VALUES` hint is used.
+    ///
+    /// When it is `true`, the transaction is committed after each batch
+    /// because a table modified by direct-path INSERT cannot be modified
+    /// again in the same transaction. (ORA-12838) Note that it also commits
+    /// changes made by the connection before the loader is used. This is
+    /// `false` by default.
+    pub fn append_values(mut self, append_values: bool) -> Self {
+        self.append_values = append_values;
+        self
+    }
+}
+
+impl Default for BulkLoadOptions {
+    fn default() -> BulkLoadOptions {
+        BulkLoadOptions::new()
+    }
+}
+
+/// Throughput statistics of [`BulkLoader`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct BulkLoadStats {
+    /// The number of inserted rows
+    pub rows: u64,
+    /// The number of round trips to execute INSERT
+    pub batches: u64,
+    /// The time since the loader was created
+    pub elapsed: Duration,
+}
+
+impl BulkLoadStats {
+    /// Returns inserted rows per second
+    pub fn rows_per_second(&self) -> f64 {
+        let secs = self.elapsed.as_secs_f64();
+        if secs > 0.0 {
+            self.rows as f64 / secs
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Loader inserting many rows into a table
+///
+/// # Examples
+///
+/// ```
+/// # use oracle::test_util;
+/// use oracle::io::bulk_load::{BulkLoadOptions, BulkLoader};
+/// let conn = test_util::connect()?;
+/// # conn.execute("delete from TestTempTable", &[])?;
+/// # conn.commit()?;
+/// let options = BulkLoadOptions::new().batch_size(100);
+/// let mut loader = BulkLoader::new(&conn, "TestTempTable", &["IntCol", "StringCol"], &options)?;
+/// for i in 0..250 {
+///     loader.append_row(&[&i, &format!("row {}", i)])?;
+/// }
+/// let stats = loader.finish()?;
+/// assert_eq!(stats.rows, 250);
+/// assert_eq!(stats.batches, 3);
+///
+/// let count = conn.query_row_as::<u64>("select count(*) from TestTempTable", &[])?;
+/// assert_eq!(count, 250);
+/// # Ok::<(), oracle::Error>(())
+/// ```
+pub struct BulkLoader<'conn> {
+    conn: &'conn Connection,
+    batch: Batch<'conn>,
+    batch_size: usize,
+    append_values: bool,
+    pending_rows: usize,
+    rows: u64,
+    batches: u64,
+    start: Instant,
+}
+
+impl<'conn> BulkLoader<'conn> {
+    /// Creates a loader inserting values of `columns` into `table`.
+    ///
+    /// Table and column names must be SQL identifiers. A table name may be
+    /// qualified by a schema name.
+    pub fn new(
+        conn: &'conn Connection,
+        table: &str,
+        columns: &[&str],
+        options: &BulkLoadOptions,
+    ) -> Result<BulkLoader<'conn>> {
+        if options.batch_size == 0 {
+            return Err(Error::invalid_argument("batch size must not be zero"));
+        }
+        let sql = insert_sql(table, columns, options.append_values)?;
+        // One more row than the batch size so that `Batch::append_row`
+        // doesn't execute the statement implicitly.
+        let batch = conn
+            .batch(&sql, options.batch_size.saturating_add(1))
+            .build()?;
+        Ok(BulkLoader {
+            conn,
+            batch,
+            batch_size: options.batch_size,
+            append_values: options.append_values,
+            pending_rows: 0,
+            rows: 0,
+            batches: 0,
+            start: Instant::now(),
+        })
+    }
+
+    /// Sets the type of a column before the first row is appended.
+    ///
+    /// This avoids reallocation of bind buffers when the lengths of
+    /// values in later rows are larger than those in the first row.
+    /// See [`Batch::set_type`].
+    pub fn set_type<I>(&mut self, colidx: I, oratype: &OracleType) -> Result<()>
+    where
+        I: BatchBindIndex,
+    {
+        self.batch.set_type(colidx, oratype)
+    }
+
+    /// Appends a row. Rows are inserted when the number of appended rows
+    /// reaches the batch size.
+    pub fn append_row(&mut self, values: &[&dyn ToSql]) -> Result<()> {
+        self.batch.append_row(values)?;
+        self.pending_rows += 1;
+        if self.pending_rows == self.batch_size {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Inserts pending rows.
+    pub fn flush(&mut self) -> Result<()> {
+        if self.pending_rows == 0 {
+            return Ok(());
+        }
+        let rows = self.pending_rows as u64;
+        // `Batch::execute` discards pending rows even when it fails.
+        self.pending_rows = 0;
+        self.batch.execute()?;
+        self.rows += rows;
+        self.batches += 1;
+        if self.append_values && !self.conn.autocommit() {
+            self.conn.commit()?;
+        }
+        Ok(())
+    }
+
+    /// Returns statistics at this point.
+    pub fn stats(&self) -> BulkLoadStats {
+        BulkLoadStats {
+            rows: self.rows,
+            batches: self.batches,
+            elapsed: self.start.elapsed(),
+        }
+    }
+
+    /// Inserts pending rows and returns the statistics.
+    pub fn finish(mut self) -> Result<BulkLoadStats> {
+        self.flush()?;
+        self.batch.close()?;
+        Ok(self.stats())
+    }
+}
+
+fn insert_sql(table: &str, columns: &[&str], append_values: bool) -> Result<String> {
+    check_identifier(table, true)?;
+    if columns.is_empty() {
+        return Err(Error::invalid_argument("no columns"));
+    }
+    for col in columns {
+        check_identifier(col, false)?;
+    }
+    let placeholders = (1..=columns.len())
+        .map(|i| format!(":{}", i))
+        .collect::<Vec<_>>()
+        .join(", ");
+    Ok(format!(
+        "INSERT {}INTO {} ({}) VALUES ({})",
+        if append_values {
+            "/*+ APPEND_VALUES */ "
+        } else {
+            ""
+        },
+        table,
+        columns.join(", "),
+        placeholders
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bulk_load_insert_sql() {
+        assert_eq!(
+            insert_sql("SCOTT.EMP", &["EMPNO", "ENAME"], true).unwrap(),
+            "INSERT /*+ APPEND_VALUES */ INTO SCOTT.EMP (EMPNO, ENAME) VALUES (:1, :2)"
+        );
+        assert_eq!(
+            insert_sql("EMP", &["EMPNO"], false).unwrap(),
+            "INSERT INTO EMP (EMPNO) VALUES (:1)"
+        );
+        assert!(insert_sql("EMP", &[], true).is_err());
+        assert!(insert_sql("EMP; --", &["EMPNO"], true).is_err());
+    }
+}
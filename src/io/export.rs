@@ -0,0 +1,361 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2025 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+//! Export query results to CSV or [JSON Lines]
+//!
+//! [JSON Lines]: https://jsonlines.org/
+use crate::sql_type::OracleType;
+use crate::sql_type::ToSql;
+use crate::Error;
+use crate::ErrorKind;
+use crate::Result;
+use crate::SqlValue;
+use crate::Statement;
+use std::io::{self, Write};
+
+/// Format of CSV written by [`export_csv`]
+///
+/// The default format follows [RFC 4180]: fields are separated by commas,
+/// quoted by double quotes only when necessary and lines end with CRLF.
+/// The first line contains column names and NULL is written as an empty string.
+///
+/// [RFC 4180]: https://www.rfc-editor.org/rfc/rfc4180
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CsvOptions {
+    delimiter: char,
+    quote: char,
+    header: bool,
+    null_string: String,
+    line_terminator: String,
+}
+
+impl CsvOptions {
+    /// Creates options in the default format
+    pub fn new() -> CsvOptions {
+        CsvOptions {
+            delimiter: ',',
+            quote: '"',
+            header: true,
+            null_string: "".into(),
+            line_terminator: "\r\n".into(),
+        }
+    }
+
+    /// Sets the character separating fields. The default is `,`.
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets the character quoting fields. The default is `"`.
+    pub fn quote(mut self, quote: char) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    /// Sets whether the first line contains column names. The default is `true`.
+    pub fn header(mut self, header: bool) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// Sets the string written for NULL values. The default is an empty string.
+    ///
+    /// A non-NULL value same with this string is quoted to distinguish
+    /// it from NULL.
+    pub fn null_string<S>(mut self, null_string: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.null_string = null_string.into();
+        self
+    }
+
+    /// Sets the string terminating each line. The default is `\r\n`.
+    pub fn line_terminator<S>(mut self, line_terminator: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.line_terminator = line_terminator.into();
+        self
+    }
+
+    fn write_field<W>(&self, writer: &mut W, field: &str) -> io::Result<()>
+    where
+        W: Write,
+    {
+        let needs_quote = field
+            .chars()
+            .any(|c| c == self.delimiter || c == self.quote || c == '\r' || c == '\n')
+            || (!self.null_string.is_empty() && field == self.null_string);
+        if needs_quote {
+            let mut buf = [0u8; 4];
+            let quote = self.quote.encode_utf8(&mut buf);
+            let escaped_quote = quote.repeat(2);
+            write!(
+                writer,
+                "{}{}{}",
+                quote,
+                field.replace(&*quote, &escaped_quote),
+                quote
+            )
+        } else {
+            writer.write_all(field.as_bytes())
+        }
+    }
+
+    fn write_delimiter<W>(&self, writer: &mut W) -> io::Result<()>
+    where
+        W: Write,
+    {
+        let mut buf = [0u8; 4];
+        writer.write_all(self.delimiter.encode_utf8(&mut buf).as_bytes())
+    }
+}
+
+impl Default for CsvOptions {
+    fn default() -> CsvOptions {
+        CsvOptions::new()
+    }
+}
+
+fn io_error(err: io::Error) -> Error {
+    Error::with_source(ErrorKind::Other, err)
+}
+
+/// Executes a query and writes the rows to `writer` in CSV format.
+///
+/// Values are formatted as [`SqlValue::get::<String>()`](SqlValue::get)
+/// does. Fields containing the delimiter, the quote character or line
+/// breaks are quoted. The number of written rows, excluding the header,
+/// is returned.
+///
+/// # Examples
+///
+/// ```
+/// # use oracle::test_util;
+/// use oracle::io::export::{export_csv, CsvOptions};
+/// let conn = test_util::connect()?;
+/// let mut stmt = conn
+///     .statement("select IntCol, StringCol, NullableCol from TestStrings where IntCol between 1 and :1 order by IntCol")
+///     .build()?;
+/// let mut buf = Vec::new();
+/// let nrows = export_csv(&mut stmt, &[&2], &mut buf, &CsvOptions::new().null_string("NULL"))?;
+/// assert_eq!(nrows, 2);
+/// assert_eq!(
+///     String::from_utf8(buf).unwrap(),
+///     "INTCOL,STRINGCOL,NULLABLECOL\r\n\
+///      1,String 1,Nullable 1\r\n\
+///      2,String 2,NULL\r\n"
+/// );
+/// # Ok::<(), oracle::Error>(())
+/// ```
+pub fn export_csv<W>(
+    stmt: &mut Statement,
+    params: &[&dyn ToSql],
+    mut writer: W,
+    options: &CsvOptions,
+) -> Result<u64>
+where
+    W: Write,
+{
+    let rows = stmt.query(params)?;
+    if options.header {
+        for (i, info) in rows.column_info().iter().enumerate() {
+            if i != 0 {
+                options.write_delimiter(&mut writer).map_err(io_error)?;
+            }
+            options
+                .write_field(&mut writer, info.name())
+                .map_err(io_error)?;
+        }
+        writer
+            .write_all(options.line_terminator.as_bytes())
+            .map_err(io_error)?;
+    }
+    let mut nrows = 0;
+    for row in rows {
+        let row = row?;
+        for (i, val) in row.sql_values().iter().enumerate() {
+            if i != 0 {
+                options.write_delimiter(&mut writer).map_err(io_error)?;
+            }
+            if val.is_null()? {
+                writer
+                    .write_all(options.null_string.as_bytes())
+                    .map_err(io_error)?;
+            } else {
+                options
+                    .write_field(&mut writer, &val.get::<String>()?)
+                    .map_err(io_error)?;
+            }
+        }
+        writer
+            .write_all(options.line_terminator.as_bytes())
+            .map_err(io_error)?;
+        nrows += 1;
+    }
+    writer.flush().map_err(io_error)?;
+    Ok(nrows)
+}
+
+/// Executes a query and writes the rows to `writer` in [JSON Lines] format.
+///
+/// Each row is written as a JSON object whose keys are column names.
+/// NULL values are written as `null`. Numeric values are written as JSON
+/// numbers except infinity and NaN, which are written as strings.
+/// PL/SQL booleans are written as `true` or `false`. Other values are
+/// written as JSON strings formatted as [`SqlValue::get::<String>()`](SqlValue::get)
+/// does. The number of written rows is returned.
+///
+/// # Examples
+///
+/// ```
+/// # use oracle::test_util;
+/// use oracle::io::export::export_json_lines;
+/// let conn = test_util::connect()?;
+/// let mut stmt = conn
+///     .statement("select IntCol, StringCol, NullableCol from TestStrings where IntCol between 1 and :1 order by IntCol")
+///     .build()?;
+/// let mut buf = Vec::new();
+/// let nrows = export_json_lines(&mut stmt, &[&2], &mut buf)?;
+/// assert_eq!(nrows, 2);
+/// assert_eq!(
+///     String::from_utf8(buf).unwrap(),
+///     "{\"INTCOL\":1,\"STRINGCOL\":\"String 1\",\"NULLABLECOL\":\"Nullable 1\"}\n\
+///      {\"INTCOL\":2,\"STRINGCOL\":\"String 2\",\"NULLABLECOL\":null}\n"
+/// );
+/// # Ok::<(), oracle::Error>(())
+/// ```
+///
+/// [JSON Lines]: https://jsonlines.org/
+pub fn export_json_lines<W>(
+    stmt: &mut Statement,
+    params: &[&dyn ToSql],
+    mut writer: W,
+) -> Result<u64>
+where
+    W: Write,
+{
+    let rows = stmt.query(params)?;
+    let keys = rows
+        .column_info()
+        .iter()
+        .map(|info| json_string(info.name()))
+        .collect::<Vec<_>>();
+    let mut nrows = 0;
+    for row in rows {
+        let row = row?;
+        let mut line = String::from("{");
+        for (i, val) in row.sql_values().iter().enumerate() {
+            if i != 0 {
+                line.push(',');
+            }
+            line.push_str(&keys[i]);
+            line.push(':');
+            line.push_str(&json_value(val)?);
+        }
+        line.push_str("}\n");
+        writer.write_all(line.as_bytes()).map_err(io_error)?;
+        nrows += 1;
+    }
+    writer.flush().map_err(io_error)?;
+    Ok(nrows)
+}
+
+fn json_value(val: &SqlValue) -> Result<String> {
+    if val.is_null()? {
+        return Ok("null".into());
+    }
+    let s = val.get::<String>()?;
+    Ok(match val.oracle_type()? {
+        OracleType::Number(_, _)
+        | OracleType::Float(_)
+        | OracleType::BinaryFloat
+        | OracleType::BinaryDouble
+        | OracleType::Int64
+        | OracleType::UInt64
+            if is_json_number(&s) =>
+        {
+            s
+        }
+        OracleType::Boolean => s.to_lowercase(),
+        _ => json_string(&s),
+    })
+}
+
+// Checks whether `s` is a JSON number. It filters out `inf`, `-inf` and `NaN`.
+fn is_json_number(s: &str) -> bool {
+    let digits = s.strip_prefix('-').unwrap_or(s);
+    digits.starts_with(|c: char| c.is_ascii_digit())
+        && digits
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '+' | '-'))
+}
+
+fn json_string(s: &str) -> String {
+    let mut result = String::with_capacity(s.len() + 2);
+    result.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if (c as u32) < 0x20 => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_write_field() {
+        let opts = CsvOptions::new().null_string("NULL");
+        let field = |s: &str| {
+            let mut buf = Vec::new();
+            opts.write_field(&mut buf, s).unwrap();
+            String::from_utf8(buf).unwrap()
+        };
+        assert_eq!(field("abc"), "abc");
+        assert_eq!(field("a,b"), "\"a,b\"");
+        assert_eq!(field("a\"b"), "\"a\"\"b\"");
+        assert_eq!(field("a\nb"), "\"a\nb\"");
+        assert_eq!(field("NULL"), "\"NULL\"");
+    }
+
+    #[test]
+    fn json_string_escape() {
+        assert_eq!(json_string("abc"), "\"abc\"");
+        assert_eq!(json_string("a\"b\\c"), "\"a\\\"b\\\\c\"");
+        assert_eq!(json_string("a\nb\u{1}"), "\"a\\nb\\u0001\"");
+    }
+
+    #[test]
+    fn json_number() {
+        assert!(is_json_number("0.5"));
+        assert!(is_json_number("-10"));
+        assert!(is_json_number("1e+300"));
+        assert!(!is_json_number("inf"));
+        assert!(!is_json_number("-inf"));
+        assert!(!is_json_number("NaN"));
+    }
+}
@@ -13,9 +13,12 @@
 // (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
 //-----------------------------------------------------------------------------
 
-//! Type definitions for I/O in characters
+//! Type definitions for I/O in characters and helpers to load and export rows
 use std::io::{Result, SeekFrom};
 
+pub mod bulk_load;
+pub mod export;
+
 /// A cursor which can be moved within a stream of characters.
 ///
 /// This is same with [`Seek`] except positions are numbered in characters, not in bytes.
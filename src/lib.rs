@@ -24,6 +24,7 @@ use std::slice;
 #[cfg(feature = "aq_unstable")]
 pub mod aq;
 mod batch;
+mod column_profile;
 #[allow(dead_code)]
 #[allow(non_camel_case_types)]
 #[allow(non_snake_case)]
@@ -32,12 +33,24 @@ pub mod conn;
 mod connection;
 mod context;
 mod error;
+mod in_list;
 pub mod io;
+#[cfg(feature = "mock")]
+pub mod mock;
+pub mod observer;
 pub mod oci_attr;
+mod owned_row;
 pub mod pool;
 #[cfg(doctest)]
 mod procmacro;
 mod row;
+#[cfg(feature = "async-bridge")]
+mod row_stream;
+#[cfg(feature = "serde")]
+mod serde;
+mod shared_connection;
+#[cfg(feature = "soda")]
+pub mod soda;
 pub mod sql_type;
 mod sql_value;
 mod statement;
@@ -47,28 +60,54 @@ mod version;
 pub use crate::batch::Batch;
 pub use crate::batch::BatchBindIndex;
 pub use crate::batch::BatchBuilder;
+pub use crate::batch::UpsertBuilder;
+pub use crate::column_profile::ColumnProfile;
+pub use crate::connection::CommitOptions;
 pub use crate::connection::ConnStatus;
 pub use crate::connection::Connection;
 pub use crate::connection::Connector;
+pub use crate::connection::EmptyStringMode;
 pub use crate::connection::Privilege;
 pub use crate::connection::ShutdownMode;
 pub use crate::connection::StartupMode;
+pub use crate::connection::TransactionMode;
 use crate::context::Context;
 pub use crate::context::InitParams;
 pub use crate::error::DbError;
 pub use crate::error::Error;
+pub use crate::error::ErrorFrame;
 pub use crate::error::ErrorKind;
 pub use crate::error::ParseOracleTypeError;
+pub use crate::in_list::InList;
+pub use crate::owned_row::FromOwnedValue;
+pub use crate::owned_row::OwnedRow;
+pub use crate::owned_row::OwnedValue;
+pub use crate::row::FetchStats;
 pub use crate::row::ResultSet;
 pub use crate::row::Row;
 pub use crate::row::RowValue;
+#[cfg(feature = "async-bridge")]
+pub use crate::row_stream::RowStream;
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use crate::serde::row_value_json as __row_value_json;
+#[cfg(feature = "serde")]
+pub use crate::serde::SerializableRows;
+pub use crate::shared_connection::SharedConnection;
 pub use crate::sql_value::SqlValue;
 pub use crate::statement::BindIndex;
+pub use crate::statement::BindRecord;
+pub use crate::statement::BindTypeChange;
 pub use crate::statement::ColumnIndex;
 pub use crate::statement::ColumnInfo;
+pub use crate::statement::ExecutionRecord;
+pub use crate::statement::QueryParams;
+pub use crate::statement::ResultSource;
 pub use crate::statement::Statement;
 pub use crate::statement::StatementBuilder;
 pub use crate::statement::StatementType;
+pub use crate::version::client_info;
+pub use crate::version::ClientInfo;
 pub use crate::version::Version;
 pub use oracle_procmacro::RowValue;
 
@@ -163,6 +202,22 @@ define_dpi_data_with_refcount!(ObjectAttr);
 // define DpiQueue wrapping *mut dpiQueue.
 define_dpi_data_with_refcount!(Queue);
 
+// define DpiSodaDb wrapping *mut dpiSodaDb.
+#[cfg(feature = "soda")]
+define_dpi_data_with_refcount!(SodaDb);
+
+// define DpiSodaColl wrapping *mut dpiSodaColl.
+#[cfg(feature = "soda")]
+define_dpi_data_with_refcount!(SodaColl);
+
+// define DpiSodaDoc wrapping *mut dpiSodaDoc.
+#[cfg(feature = "soda")]
+define_dpi_data_with_refcount!(SodaDoc);
+
+// define DpiSodaDocCursor wrapping *mut dpiSodaDocCursor.
+#[cfg(feature = "soda")]
+define_dpi_data_with_refcount!(SodaDocCursor, nosync);
+
 // define DpiObject wrapping *mut dpiObject.
 define_dpi_data_with_refcount!(Object, nosync);
 
@@ -0,0 +1,290 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2025 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+//! Test doubles for database code (available when `mock` feature is enabled.)
+//!
+//! Write database code against [`SqlExecutor`] instead of [`Connection`].
+//! [`Connection`] implements it for production code and [`MockExecutor`]
+//! implements it for unit tests without a live Oracle instance.
+//!
+//! # Examples
+//!
+//! ```
+//! # use oracle::Error;
+//! use oracle::mock::{MockExecutor, SqlExecutor};
+//! use oracle::{OwnedRow, OwnedValue};
+//!
+//! fn employee_name(db: &dyn SqlExecutor, id: i32) -> oracle::Result<Option<String>> {
+//!     let rows = db.query_rows("select ename from emp where empno = :1", &[&id])?;
+//!     match rows.first() {
+//!         Some(row) => row.get("ENAME"),
+//!         None => Ok(None),
+//!     }
+//! }
+//!
+//! let mut db = MockExecutor::new();
+//! db.on_query(
+//!     "select ename from emp where empno = :1",
+//!     vec![OwnedRow::new(&["ENAME"], vec![OwnedValue::String("SCOTT".into())])?],
+//! );
+//! assert_eq!(employee_name(&db, 7788)?, Some("SCOTT".to_string()));
+//! assert_eq!(db.executed_sql(), ["select ename from emp where empno = :1"]);
+//! # Ok::<(), Error>(())
+//! ```
+use crate::sql_type::ToSql;
+use crate::Connection;
+use crate::Error;
+use crate::ErrorKind;
+use crate::OwnedRow;
+use crate::Result;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::sync::Mutex;
+
+/// A trait for executing SQL statements
+///
+/// This is implemented by [`Connection`] and [`MockExecutor`].
+pub trait SqlExecutor {
+    /// Executes a SQL statement and returns the number of affected rows.
+    fn execute_sql(&self, sql: &str, params: &[&dyn ToSql]) -> Result<u64>;
+
+    /// Executes a query and returns all rows.
+    fn query_rows(&self, sql: &str, params: &[&dyn ToSql]) -> Result<Vec<OwnedRow>>;
+
+    /// Commits the current transaction.
+    fn commit(&self) -> Result<()>;
+
+    /// Rolls back the current transaction.
+    fn rollback(&self) -> Result<()>;
+}
+
+impl SqlExecutor for Connection {
+    fn execute_sql(&self, sql: &str, params: &[&dyn ToSql]) -> Result<u64> {
+        self.execute(sql, params)?.row_count()
+    }
+
+    fn query_rows(&self, sql: &str, params: &[&dyn ToSql]) -> Result<Vec<OwnedRow>> {
+        self.query(sql, params)?
+            .map(|row| row?.to_owned_row())
+            .collect()
+    }
+
+    fn commit(&self) -> Result<()> {
+        Connection::commit(self)
+    }
+
+    fn rollback(&self) -> Result<()> {
+        Connection::rollback(self)
+    }
+}
+
+#[derive(Debug)]
+enum Response {
+    RowCount(u64),
+    Rows(Vec<OwnedRow>),
+    Error(ErrorKind, String),
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    responses: HashMap<String, VecDeque<Response>>,
+    executed_sql: Vec<String>,
+}
+
+/// An in-memory [`SqlExecutor`] which returns canned responses
+///
+/// Responses are registered per SQL text. When more than one response is
+/// registered for the same SQL, they are returned in order and the last one
+/// is repeated. Executing SQL without registered responses fails with
+/// an error whose kind is [`ErrorKind::InvalidOperation`].
+///
+/// Executed SQL statements including `COMMIT` and `ROLLBACK` are recorded
+/// and got by [`MockExecutor::executed_sql`]. Bind parameters aren't recorded
+/// because converting them requires a connection.
+#[derive(Default)]
+pub struct MockExecutor {
+    state: Mutex<MockState>,
+}
+
+impl MockExecutor {
+    /// Creates a new mock without responses
+    pub fn new() -> MockExecutor {
+        MockExecutor::default()
+    }
+
+    fn push(&mut self, sql: &str, response: Response) -> &mut MockExecutor {
+        self.state_mut()
+            .responses
+            .entry(sql.to_string())
+            .or_default()
+            .push_back(response);
+        self
+    }
+
+    fn state_mut(&mut self) -> &mut MockState {
+        match self.state.get_mut() {
+            Ok(state) => state,
+            Err(err) => err.into_inner(),
+        }
+    }
+
+    /// Registers the number of affected rows returned by [`SqlExecutor::execute_sql`]
+    pub fn on_execute(&mut self, sql: &str, row_count: u64) -> &mut MockExecutor {
+        self.push(sql, Response::RowCount(row_count))
+    }
+
+    /// Registers rows returned by [`SqlExecutor::query_rows`]
+    pub fn on_query(&mut self, sql: &str, rows: Vec<OwnedRow>) -> &mut MockExecutor {
+        self.push(sql, Response::Rows(rows))
+    }
+
+    /// Registers an error returned when `sql` is executed
+    pub fn on_error<M>(&mut self, sql: &str, kind: ErrorKind, message: M) -> &mut MockExecutor
+    where
+        M: Into<String>,
+    {
+        self.push(sql, Response::Error(kind, message.into()))
+    }
+
+    /// Returns SQL statements executed so far
+    pub fn executed_sql(&self) -> Vec<String> {
+        match self.state.lock() {
+            Ok(state) => state.executed_sql.clone(),
+            Err(err) => err.into_inner().executed_sql.clone(),
+        }
+    }
+
+    fn executed_sql_push(&self, sql: &str) {
+        match self.state.lock() {
+            Ok(mut state) => state.executed_sql.push(sql.to_string()),
+            Err(err) => err.into_inner().executed_sql.push(sql.to_string()),
+        }
+    }
+
+    fn respond<T, F>(&self, sql: &str, f: F) -> Result<T>
+    where
+        F: FnOnce(&Response) -> Option<T>,
+    {
+        let mut state = match self.state.lock() {
+            Ok(state) => state,
+            Err(err) => err.into_inner(),
+        };
+        state.executed_sql.push(sql.to_string());
+        let responses = state
+            .responses
+            .get_mut(sql)
+            .filter(|responses| !responses.is_empty())
+            .ok_or_else(|| Error::invalid_operation(format!("no response for {:?}", sql)))?;
+        if responses.len() > 1 {
+            let response = responses.pop_front().unwrap();
+            respond_with(sql, &response, f)
+        } else {
+            respond_with(sql, &responses[0], f)
+        }
+    }
+}
+
+fn respond_with<T, F>(sql: &str, response: &Response, f: F) -> Result<T>
+where
+    F: FnOnce(&Response) -> Option<T>,
+{
+    if let Response::Error(kind, message) = response {
+        return Err(Error::new(*kind, message.clone()));
+    }
+    f(response)
+        .ok_or_else(|| Error::invalid_operation(format!("response type mismatch for {:?}", sql)))
+}
+
+impl SqlExecutor for MockExecutor {
+    fn execute_sql(&self, sql: &str, _params: &[&dyn ToSql]) -> Result<u64> {
+        self.respond(sql, |response| match response {
+            Response::RowCount(count) => Some(*count),
+            _ => None,
+        })
+    }
+
+    fn query_rows(&self, sql: &str, _params: &[&dyn ToSql]) -> Result<Vec<OwnedRow>> {
+        self.respond(sql, |response| match response {
+            Response::Rows(rows) => Some(rows.clone()),
+            _ => None,
+        })
+    }
+
+    fn commit(&self) -> Result<()> {
+        self.executed_sql_push("COMMIT");
+        Ok(())
+    }
+
+    fn rollback(&self) -> Result<()> {
+        self.executed_sql_push("ROLLBACK");
+        Ok(())
+    }
+}
+
+impl fmt::Debug for MockExecutor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MockExecutor")
+            .field("executed_sql", &self.executed_sql())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OwnedValue;
+
+    #[test]
+    fn mock_executor() -> Result<()> {
+        let mut db = MockExecutor::new();
+        db.on_execute("delete from emp", 3)
+            .on_execute("delete from emp", 0)
+            .on_query(
+                "select empno from emp",
+                vec![
+                    OwnedRow::new(&["EMPNO"], vec![OwnedValue::Int64(7369)])?,
+                    OwnedRow::new(&["EMPNO"], vec![OwnedValue::Null])?,
+                ],
+            )
+            .on_error("drop table emp", ErrorKind::OciError, "ORA-00942");
+        assert_eq!(db.execute_sql("delete from emp", &[])?, 3);
+        assert_eq!(db.execute_sql("delete from emp", &[])?, 0);
+        assert_eq!(db.execute_sql("delete from emp", &[])?, 0);
+        let rows = db.query_rows("select empno from emp", &[])?;
+        assert_eq!(rows[0].get::<_, i32>("empno")?, 7369);
+        assert_eq!(rows[1].get::<_, Option<i32>>(0)?, None);
+        let err = db.execute_sql("drop table emp", &[]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::OciError);
+        let err = db.query_rows("delete from emp", &[]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidOperation);
+        let err = db.execute_sql("select 1 from dual", &[]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidOperation);
+        db.commit()?;
+        assert_eq!(
+            db.executed_sql(),
+            [
+                "delete from emp",
+                "delete from emp",
+                "delete from emp",
+                "select empno from emp",
+                "drop table emp",
+                "delete from emp",
+                "select 1 from dual",
+                "COMMIT",
+            ]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,278 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2025 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+//! Hooks to observe connection lifecycle and statement executions
+//!
+//! Register a [`ConnectionObserver`] by [`set_observer`] to collect metrics
+//! such as the number of connections and execution time uniformly across
+//! an application.
+//!
+//! # Examples
+//!
+//! ```
+//! # use oracle::test_util;
+//! use oracle::observer::{self, ConnectionObserver};
+//! use oracle::{Error, StatementType};
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//! use std::sync::Arc;
+//! use std::time::Duration;
+//!
+//! #[derive(Default)]
+//! struct Metrics {
+//!     executions: AtomicUsize,
+//!     errors: AtomicUsize,
+//! }
+//!
+//! impl ConnectionObserver for Metrics {
+//!     fn on_execute(&self, _stmt_type: StatementType, _elapsed: Duration) {
+//!         self.executions.fetch_add(1, Ordering::Relaxed);
+//!     }
+//!
+//!     fn on_execute_error(&self, _stmt_type: StatementType, _err: &Error, _elapsed: Duration) {
+//!         self.errors.fetch_add(1, Ordering::Relaxed);
+//!     }
+//! }
+//!
+//! let metrics = Arc::new(Metrics::default());
+//! observer::set_observer(metrics.clone());
+//!
+//! let conn = test_util::connect()?;
+//! conn.execute("begin null; end;", &[])?;
+//! assert!(conn.execute("begin raise no_data_found; end;", &[]).is_err());
+//! assert!(metrics.executions.load(Ordering::Relaxed) >= 1);
+//! assert!(metrics.errors.load(Ordering::Relaxed) >= 1);
+//!
+//! observer::clear_observer();
+//! # Ok::<(), Error>(())
+//! ```
+use crate::Connection;
+use crate::Context;
+use crate::DbError;
+use crate::Error;
+use crate::Result;
+use crate::StatementType;
+use odpic_sys::DPI_SUCCESS;
+use once_cell::sync::Lazy;
+#[cfg(feature = "trace_dpi_calls")]
+use std::cell::RefCell;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+static OBSERVER: Lazy<RwLock<Option<Arc<dyn ConnectionObserver>>>> =
+    Lazy::new(|| RwLock::new(None));
+
+/// Callbacks invoked on connection lifecycle events and statement executions
+///
+/// All methods do nothing by default. Implement the methods you need.
+/// They are called in the thread using the connection. Long-running
+/// callbacks block the caller.
+pub trait ConnectionObserver: Send + Sync {
+    /// Called after a standalone connection is established by
+    /// [`Connection::connect`] or [`Connector::connect`](crate::Connector::connect).
+    ///
+    /// `elapsed` is the time spent to establish the connection.
+    /// This isn't called for connections got from a [`Pool`](crate::pool::Pool).
+    fn on_connect(&self, _conn: &Connection, _elapsed: Duration) {}
+
+    /// Called after a connection is closed by [`Connection::close`] or
+    /// [`Connection::close_with_mode`].
+    ///
+    /// This isn't called when a connection is closed by being dropped without
+    /// calling them.
+    fn on_close(&self, _conn: &Connection) {}
+
+    /// Called after a round trip to execute a statement or a batch completes
+    /// successfully.
+    ///
+    /// The warning of the execution is got before this is called. So it is
+    /// kept even when ODPI-C functions are called in this.
+    fn on_execute(&self, _stmt_type: StatementType, _elapsed: Duration) {}
+
+    /// Called when execution of a statement or a batch fails.
+    fn on_execute_error(&self, _stmt_type: StatementType, _err: &Error, _elapsed: Duration) {}
+
+    /// Called before an ODPI-C function is called (available when `trace_dpi_calls`
+    /// feature is enabled.)
+    ///
+    /// `fn_name` is the name of the function such as `dpiStmt_execute`.
+    /// Functions which don't fail such as `dpiStmt_release` aren't traced.
+    /// Counting functions which cause round trips to the server, such as
+    /// `dpiStmt_execute` and `dpiStmt_fetchRows`, helps tests assert the
+    /// number of round trips in a code path.
+    ///
+    /// This and [`ConnectionObserver::on_dpi_call_end`] must not call
+    /// functions in this crate. ODPI-C functions called by them are traced
+    /// again and the callbacks are re-entered.
+    #[cfg(feature = "trace_dpi_calls")]
+    fn on_dpi_call_start(&self, _fn_name: &str) {}
+
+    /// Called after an ODPI-C function returns (available when `trace_dpi_calls`
+    /// feature is enabled.)
+    ///
+    /// `result` is the return code of the function, which is `0` on success
+    /// and `-1` on failure. The error or warning of the function is got before
+    /// this is called. See [`ConnectionObserver::on_dpi_call_start`] about
+    /// restrictions.
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// use oracle::observer::{self, ConnectionObserver};
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    ///
+    /// #[derive(Default)]
+    /// struct ExecuteCounter(AtomicUsize);
+    ///
+    /// impl ConnectionObserver for ExecuteCounter {
+    ///     fn on_dpi_call_end(&self, fn_name: &str, _elapsed: Duration, _result: i32) {
+    ///         if fn_name == "dpiStmt_execute" {
+    ///             self.0.fetch_add(1, Ordering::Relaxed);
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let conn = test_util::connect()?;
+    /// let counter = Arc::new(ExecuteCounter::default());
+    /// observer::set_observer(counter.clone());
+    /// conn.execute("begin null; end;", &[])?;
+    /// observer::clear_observer();
+    /// assert!(counter.0.load(Ordering::Relaxed) >= 1);
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[cfg(feature = "trace_dpi_calls")]
+    fn on_dpi_call_end(&self, _fn_name: &str, _elapsed: Duration, _result: i32) {}
+}
+
+/// Registers the observer used by all connections in the process.
+///
+/// The previously registered observer is replaced.
+pub fn set_observer(observer: Arc<dyn ConnectionObserver>) {
+    *write_lock() = Some(observer);
+}
+
+/// Unregisters the observer.
+pub fn clear_observer() {
+    *write_lock() = None;
+}
+
+fn write_lock() -> std::sync::RwLockWriteGuard<'static, Option<Arc<dyn ConnectionObserver>>> {
+    match OBSERVER.write() {
+        Ok(guard) => guard,
+        Err(err) => err.into_inner(),
+    }
+}
+
+// The lock is released before the observer is called so that
+// callbacks can call `set_observer` without deadlock.
+pub(crate) fn current() -> Option<Arc<dyn ConnectionObserver>> {
+    match OBSERVER.read() {
+        Ok(guard) => guard.clone(),
+        Err(err) => err.into_inner().clone(),
+    }
+}
+
+// Notifies an ODPI-C function call to the observer.
+//
+// `start` is called before the function is called and `end` is called
+// with the return code.
+#[cfg(feature = "trace_dpi_calls")]
+pub(crate) struct DpiCallTrace<'a> {
+    observer: Option<(Arc<dyn ConnectionObserver>, &'a str, Instant)>,
+}
+
+// The warning of the last ODPI-C function notified to the observer. It is
+// got before `on_dpi_call_end` is called because ODPI-C functions called
+// in the callback overwrite the error information of the thread.
+#[cfg(feature = "trace_dpi_calls")]
+thread_local! {
+    static CAPTURED_WARNING: RefCell<Option<Option<DbError>>> = const { RefCell::new(None) };
+}
+
+#[cfg(feature = "trace_dpi_calls")]
+impl<'a> DpiCallTrace<'a> {
+    // `code` is the source text of the function call such as
+    // `dpiStmt_execute(handle, mode, &mut num_query_columns)`.
+    pub(crate) fn start(code: &'a str) -> DpiCallTrace<'a> {
+        DpiCallTrace {
+            observer: current().map(|observer| {
+                let fn_name = code.split('(').next().unwrap_or(code).trim();
+                observer.on_dpi_call_start(fn_name);
+                (observer, fn_name, Instant::now())
+            }),
+        }
+    }
+
+    // Gets the error or the warning of the function returning `code` and
+    // then notifies the observer.
+    pub(crate) fn end(self, ctxt: &Context, code: i32) -> Result<()> {
+        let result = if code == DPI_SUCCESS as i32 {
+            Ok(())
+        } else {
+            Err(Error::from_context(ctxt))
+        };
+        let captured = self.observer.map(|(observer, fn_name, start)| {
+            let warning = result.is_ok().then(|| DbError::to_warning(ctxt)).flatten();
+            observer.on_dpi_call_end(fn_name, start.elapsed(), code);
+            warning
+        });
+        CAPTURED_WARNING.with(|w| *w.borrow_mut() = captured);
+        result
+    }
+}
+
+// Returns the warning got by `DpiCallTrace::end` when the last ODPI-C
+// function was notified to the observer.
+#[cfg(feature = "trace_dpi_calls")]
+pub(crate) fn captured_warning() -> Option<Option<DbError>> {
+    CAPTURED_WARNING.with(|w| w.borrow().clone())
+}
+
+// Calls `f` which executes a statement by the ODPI-C function `fn_name` and
+// notifies the result to the observer.
+//
+// The warning of the execution is returned on success. It is got before the
+// observer is notified because ODPI-C functions called in the callback
+// overwrite the error information of the thread.
+#[cfg_attr(not(feature = "trace_dpi_calls"), allow(unused_variables))]
+pub(crate) fn observe_execute<F>(
+    stmt_type: StatementType,
+    ctxt: &Context,
+    fn_name: &str,
+    f: F,
+) -> Result<Option<DbError>>
+where
+    F: FnOnce() -> i32,
+{
+    let observer = current();
+    let start = observer.as_ref().map(|_| Instant::now());
+    #[cfg(feature = "trace_dpi_calls")]
+    let result = DpiCallTrace::start(fn_name).end(ctxt, f());
+    #[cfg(not(feature = "trace_dpi_calls"))]
+    let result = if f() == DPI_SUCCESS as i32 {
+        Ok(())
+    } else {
+        Err(Error::from_context(ctxt))
+    };
+    let result = result.map(|()| DbError::to_warning(ctxt));
+    if let (Some(observer), Some(start)) = (observer, start) {
+        match result {
+            Ok(_) => observer.on_execute(stmt_type, start.elapsed()),
+            Err(ref err) => observer.on_execute_error(stmt_type, err, start.elapsed()),
+        }
+    }
+    result
+}
@@ -17,12 +17,19 @@
 use crate::chkerr;
 #[cfg(doc)]
 use crate::oci_attr::OciAttr;
+use crate::pool::Pool;
 use crate::to_rust_str;
 use crate::Connection;
 use crate::Context;
 use crate::Error;
 use crate::Result;
 use crate::Statement;
+use odpic_sys::dpi_impl::{
+    DPI_OCI_ATTR_SPOOL_BUSY_COUNT, DPI_OCI_ATTR_SPOOL_GETMODE,
+    DPI_OCI_ATTR_SPOOL_MAX_LIFETIME_SESSION, DPI_OCI_ATTR_SPOOL_MAX_PER_SHARD,
+    DPI_OCI_ATTR_SPOOL_OPEN_COUNT, DPI_OCI_ATTR_SPOOL_STMTCACHESIZE, DPI_OCI_ATTR_SPOOL_TIMEOUT,
+    DPI_OCI_ATTR_SPOOL_WAIT_TIMEOUT,
+};
 use odpic_sys::*;
 use std::borrow::ToOwned;
 use std::convert::TryInto;
@@ -34,6 +41,7 @@ use std::time::Duration;
 enum Handle {
     Conn(*mut dpiConn, u32),
     Stmt(*mut dpiStmt),
+    Pool(*mut dpiPool),
 }
 
 /// Attribute value used in [`DataType`]. You have no need to use this except implementing [`DataType`] for your type.
@@ -60,6 +68,14 @@ impl AttrValue {
         }
     }
 
+    pub(crate) fn from_pool(pool: &Pool, attr_num: u32) -> AttrValue {
+        AttrValue {
+            ctxt: pool.ctxt().clone(),
+            handle: Handle::Pool(pool.handle()),
+            attr_num,
+        }
+    }
+
     fn ctxt(&self) -> &Context {
         &self.ctxt
     }
@@ -78,6 +94,9 @@ impl AttrValue {
                     dpiStmt_setOciAttr(*handle, self.attr_num, ptr, len)
                 );
             }
+            Handle::Pool(handle) => {
+                unsafe { set_pool_attr(self.ctxt(), *handle, self.attr_num, ptr, len)? };
+            }
         }
         Ok(())
     }
@@ -150,6 +169,9 @@ impl AttrValue {
                     dpiStmt_getOciAttr(*handle, self.attr_num, &mut buf, &mut len)
                 );
             }
+            Handle::Pool(handle) => {
+                len = get_pool_attr(self.ctxt(), *handle, self.attr_num, &mut buf)?;
+            }
         }
         Ok((buf, len))
     }
@@ -203,6 +225,90 @@ impl AttrValue {
     }
 }
 
+// ODPI-C doesn't provide functions to access OCI attributes of session pool
+// handles such as dpiConn_getOciAttr() and dpiConn_setOciAttr() for connections.
+// Attributes are mapped to ODPI-C functions accessing them instead.
+fn unsupported_pool_attr(attr_num: u32) -> Error {
+    Error::invalid_operation(format!(
+        "OCI attribute {} of session pool handles is not supported",
+        attr_num
+    ))
+}
+
+fn get_pool_attr(
+    ctxt: &Context,
+    handle: *mut dpiPool,
+    attr_num: u32,
+    buf: &mut dpiDataBuffer,
+) -> Result<u32> {
+    let mut val = 0;
+    match attr_num {
+        DPI_OCI_ATTR_SPOOL_STMTCACHESIZE => {
+            chkerr!(ctxt, dpiPool_getStmtCacheSize(handle, &mut val))
+        }
+        DPI_OCI_ATTR_SPOOL_TIMEOUT => chkerr!(ctxt, dpiPool_getTimeout(handle, &mut val)),
+        DPI_OCI_ATTR_SPOOL_GETMODE => {
+            let mut mode = 0;
+            chkerr!(ctxt, dpiPool_getGetMode(handle, &mut mode));
+            buf.asUint8 = mode;
+            return Ok(1);
+        }
+        DPI_OCI_ATTR_SPOOL_BUSY_COUNT => chkerr!(ctxt, dpiPool_getBusyCount(handle, &mut val)),
+        DPI_OCI_ATTR_SPOOL_OPEN_COUNT => chkerr!(ctxt, dpiPool_getOpenCount(handle, &mut val)),
+        DPI_OCI_ATTR_SPOOL_MAX_LIFETIME_SESSION => {
+            chkerr!(ctxt, dpiPool_getMaxLifetimeSession(handle, &mut val))
+        }
+        DPI_OCI_ATTR_SPOOL_WAIT_TIMEOUT => {
+            chkerr!(ctxt, dpiPool_getWaitTimeout(handle, &mut val))
+        }
+        DPI_OCI_ATTR_SPOOL_MAX_PER_SHARD => {
+            chkerr!(ctxt, dpiPool_getMaxSessionsPerShard(handle, &mut val))
+        }
+        _ => return Err(unsupported_pool_attr(attr_num)),
+    }
+    buf.asUint32 = val;
+    Ok(4)
+}
+
+unsafe fn set_pool_attr(
+    ctxt: &Context,
+    handle: *mut dpiPool,
+    attr_num: u32,
+    ptr: *mut c_void,
+    len: u32,
+) -> Result<()> {
+    if attr_num == DPI_OCI_ATTR_SPOOL_GETMODE {
+        if len != 1 {
+            return Err(Error::invalid_argument(format!(
+                "invalid value length {} for OCI attribute {}",
+                len, attr_num
+            )));
+        }
+        chkerr!(ctxt, dpiPool_setGetMode(handle, *(ptr as *const u8)));
+        return Ok(());
+    }
+    if len != 4 {
+        return Err(Error::invalid_argument(format!(
+            "invalid value length {} for OCI attribute {}",
+            len, attr_num
+        )));
+    }
+    let val = *(ptr as *const u32);
+    match attr_num {
+        DPI_OCI_ATTR_SPOOL_STMTCACHESIZE => chkerr!(ctxt, dpiPool_setStmtCacheSize(handle, val)),
+        DPI_OCI_ATTR_SPOOL_TIMEOUT => chkerr!(ctxt, dpiPool_setTimeout(handle, val)),
+        DPI_OCI_ATTR_SPOOL_MAX_LIFETIME_SESSION => {
+            chkerr!(ctxt, dpiPool_setMaxLifetimeSession(handle, val))
+        }
+        DPI_OCI_ATTR_SPOOL_WAIT_TIMEOUT => chkerr!(ctxt, dpiPool_setWaitTimeout(handle, val)),
+        DPI_OCI_ATTR_SPOOL_MAX_PER_SHARD => {
+            chkerr!(ctxt, dpiPool_setMaxSessionsPerShard(handle, val))
+        }
+        _ => return Err(unsupported_pool_attr(attr_num)),
+    }
+    Ok(())
+}
+
 /// A trait to get and set OCI attributes as rust types. You have no need to use this except implementing [`OciAttr`] for your type.
 #[allow(clippy::missing_safety_doc)]
 pub unsafe trait DataType {
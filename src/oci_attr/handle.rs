@@ -16,6 +16,8 @@
 //! The module defines types to be set to the associate type [`OciAttr::HandleType`]
 #[cfg(doc)]
 use crate::oci_attr::OciAttr;
+#[cfg(doc)]
+use crate::pool::Pool;
 use crate::private;
 #[cfg(doc)]
 use crate::Connection;
@@ -34,6 +36,9 @@ pub trait ConnHandle: HandleType {
     const HANDLE_TYPE: u32;
 }
 
+/// OCI handle type related to `Pool` to restrict the type parameters of [`Pool::oci_attr`] and [`Pool::set_oci_attr`]
+pub trait PoolHandle: HandleType {}
+
 /// [`HandleType`] for [Service Context Handle Attributes]
 ///
 /// [service context handle attributes]: https://www.oracle.com/pls/topic/lookup?ctx=dblatest&id=GUID-D8EE68EB-7E38-4068-B06E-DF5686379E5E
@@ -86,3 +91,16 @@ pub struct Stmt {
 }
 impl private::Sealed for Stmt {}
 impl HandleType for Stmt {}
+
+/// [`HandleType`] for Session Pool Handle Attributes
+///
+/// Note that ODPI-C doesn't provide a generic way to access attributes of
+/// session pool handles. Only attributes which ODPI-C accesses internally
+/// are supported. See [`Pool::oci_attr`].
+#[derive(Debug)]
+pub struct SPool {
+    _unused: [usize; 0],
+}
+impl private::Sealed for SPool {}
+impl HandleType for SPool {}
+impl PoolHandle for SPool {}
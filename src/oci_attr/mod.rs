@@ -22,10 +22,10 @@
 //!
 //! [here]: https://www.oracle.com/pls/topic/lookup?ctx=dblatest&id=GUID-CB59C987-07E7-42D4-ADDF-96142CBD3D11
 use crate::oci_attr::data_type::{DataType, DurationUsecU64, MaxStringSize};
-#[cfg(any(doc, test))]
-use crate::oci_attr::handle::Server;
 use crate::oci_attr::handle::{HandleType, Session, Stmt, SvcCtx};
 #[cfg(any(doc, test))]
+use crate::oci_attr::handle::{SPool, Server};
+#[cfg(any(doc, test))]
 use crate::oci_attr::mode::Write;
 use crate::oci_attr::mode::{Mode, Read, ReadWrite};
 #[cfg(doc)]
@@ -39,7 +39,7 @@ pub mod mode;
 
 #[allow(clippy::missing_safety_doc)]
 pub unsafe trait OciAttr {
-    /// [`SvcCtx`], [`Session`], [`Server`] or [`Stmt`].
+    /// [`SvcCtx`], [`Session`], [`Server`], [`Stmt`] or [`SPool`].
     /// Other handle and descriptor types are unsupported.
     type HandleType: HandleType;
 
@@ -323,6 +323,7 @@ unsafe impl OciAttr for Statement {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::pool::PoolBuilder;
     use crate::test_util;
     use crate::Result;
 
@@ -350,6 +351,30 @@ mod tests {
         const ATTR_NUM: u32 = 366;
     }
 
+    struct SPoolTimeout;
+    unsafe impl OciAttr for SPoolTimeout {
+        type HandleType = SPool;
+        type Mode = ReadWrite;
+        type DataType = u32;
+        const ATTR_NUM: u32 = 308;
+    }
+
+    struct SPoolBusyCount;
+    unsafe impl OciAttr for SPoolBusyCount {
+        type HandleType = SPool;
+        type Mode = Read;
+        type DataType = u32;
+        const ATTR_NUM: u32 = 310;
+    }
+
+    struct SPoolMaxUseSession;
+    unsafe impl OciAttr for SPoolMaxUseSession {
+        type HandleType = SPool;
+        type Mode = Read;
+        type DataType = u32;
+        const ATTR_NUM: u32 = 580;
+    }
+
     #[test]
     fn read_write_svcctx_u32_attr() -> Result<()> {
         let mut conn = test_util::connect()?;
@@ -378,4 +403,21 @@ mod tests {
         assert_eq!(module, "test module name");
         Ok(())
     }
+
+    #[test]
+    fn read_write_spool_u32_attr() -> Result<()> {
+        let mut pool = PoolBuilder::new(
+            test_util::main_user(),
+            test_util::main_password(),
+            test_util::connect_string(),
+        )
+        .build()?;
+        pool.set_oci_attr::<SPoolTimeout>(&120)?;
+        assert_eq!(pool.oci_attr::<SPoolTimeout>()?, 120);
+        assert_eq!(pool.timeout()?.as_secs(), 120);
+        let _conn = pool.get()?;
+        assert_eq!(pool.oci_attr::<SPoolBusyCount>()?, 1);
+        assert!(pool.oci_attr::<SPoolMaxUseSession>().is_err());
+        Ok(())
+    }
 }
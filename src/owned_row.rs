@@ -0,0 +1,462 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2025 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use crate::sql_type::{IntervalDS, IntervalYM, OracleType, Timestamp, ToSql, ToSqlNull};
+use crate::AssertSend;
+use crate::AssertSync;
+use crate::ColumnIndex;
+use crate::ColumnInfo;
+use crate::Connection;
+use crate::Error;
+use crate::Result;
+use crate::Row;
+use crate::SqlValue;
+use std::convert::TryFrom;
+use std::fmt;
+use std::sync::Arc;
+
+/// A column value detached from fetch buffers
+///
+/// This is an element of [`OwnedRow`].
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum OwnedValue {
+    /// NULL value
+    Null,
+    /// Integer value of a `NUMBER` column whose scale is zero
+    Int64(i64),
+    /// Unsigned integer value
+    UInt64(u64),
+    /// Other `NUMBER` and `FLOAT` values as decimal strings to keep precision
+    Number(String),
+    /// `BINARY_FLOAT` value
+    Float(f32),
+    /// `BINARY_DOUBLE` value
+    Double(f64),
+    /// `BOOLEAN` value
+    Boolean(bool),
+    /// Character data such as `VARCHAR2`, `CHAR`, `CLOB` and `ROWID`
+    String(String),
+    /// Binary data such as `RAW`, `BLOB` and `BFILE`
+    Bytes(Vec<u8>),
+    /// `DATE` and `TIMESTAMP` values
+    Timestamp(Timestamp),
+    /// `INTERVAL DAY TO SECOND` value
+    IntervalDS(IntervalDS),
+    /// `INTERVAL YEAR TO MONTH` value
+    IntervalYM(IntervalYM),
+}
+
+impl OwnedValue {
+    /// Copies the value of `val`.
+    ///
+    /// Object types, collections, ref cursors and other types which refer to
+    /// the connection cannot be detached. An error is returned for them.
+    pub fn from_sql_value(val: &SqlValue) -> Result<OwnedValue> {
+        if val.is_null()? {
+            return Ok(OwnedValue::Null);
+        }
+        Ok(match val.oracle_type()? {
+            OracleType::Int64 => OwnedValue::Int64(val.get()?),
+            OracleType::UInt64 => OwnedValue::UInt64(val.get()?),
+            OracleType::Number(_, _) | OracleType::Float(_) => OwnedValue::Number(val.get()?),
+            OracleType::BinaryFloat => OwnedValue::Float(val.get()?),
+            OracleType::BinaryDouble => OwnedValue::Double(val.get()?),
+            OracleType::Boolean => OwnedValue::Boolean(val.get()?),
+            OracleType::Varchar2(_)
+            | OracleType::NVarchar2(_)
+            | OracleType::Char(_)
+            | OracleType::NChar(_)
+            | OracleType::Rowid
+            | OracleType::Long
+            | OracleType::CLOB
+            | OracleType::NCLOB => OwnedValue::String(val.get()?),
+            OracleType::Raw(_) | OracleType::LongRaw | OracleType::BLOB | OracleType::BFILE => {
+                OwnedValue::Bytes(val.get()?)
+            }
+            OracleType::Date
+            | OracleType::Timestamp(_)
+            | OracleType::TimestampTZ(_)
+            | OracleType::TimestampLTZ(_) => OwnedValue::Timestamp(val.get()?),
+            OracleType::IntervalDS(_, _) => OwnedValue::IntervalDS(val.get()?),
+            OracleType::IntervalYM(_) => OwnedValue::IntervalYM(val.get()?),
+            oratype => {
+                return Err(Error::invalid_type_conversion(
+                    oratype.to_string(),
+                    "OwnedValue",
+                ))
+            }
+        })
+    }
+
+    /// Returns `true` when the value is NULL.
+    pub fn is_null(&self) -> bool {
+        matches!(self, OwnedValue::Null)
+    }
+
+    /// Converts the value to the specified type.
+    pub fn get<T>(&self) -> Result<T>
+    where
+        T: FromOwnedValue,
+    {
+        T::from_owned_value(self)
+    }
+
+    // Oracle type which a value of this variant is typically fetched from
+    fn oracle_type(&self) -> OracleType {
+        match self {
+            OwnedValue::Null => OracleType::Varchar2(0),
+            OwnedValue::Int64(_) => OracleType::Int64,
+            OwnedValue::UInt64(_) => OracleType::UInt64,
+            OwnedValue::Number(_) => OracleType::Number(0, -127),
+            OwnedValue::Float(_) => OracleType::BinaryFloat,
+            OwnedValue::Double(_) => OracleType::BinaryDouble,
+            OwnedValue::Boolean(_) => OracleType::Boolean,
+            OwnedValue::String(s) => OracleType::Varchar2(s.len() as u32),
+            OwnedValue::Bytes(b) => OracleType::Raw(b.len() as u32),
+            OwnedValue::Timestamp(_) => OracleType::Timestamp(9),
+            OwnedValue::IntervalDS(_) => OracleType::IntervalDS(9, 9),
+            OwnedValue::IntervalYM(_) => OracleType::IntervalYM(9),
+        }
+    }
+
+    fn type_name(&self) -> &'static str {
+        match self {
+            OwnedValue::Null => "Null",
+            OwnedValue::Int64(_) => "Int64",
+            OwnedValue::UInt64(_) => "UInt64",
+            OwnedValue::Number(_) => "Number",
+            OwnedValue::Float(_) => "Float",
+            OwnedValue::Double(_) => "Double",
+            OwnedValue::Boolean(_) => "Boolean",
+            OwnedValue::String(_) => "String",
+            OwnedValue::Bytes(_) => "Bytes",
+            OwnedValue::Timestamp(_) => "Timestamp",
+            OwnedValue::IntervalDS(_) => "IntervalDS",
+            OwnedValue::IntervalYM(_) => "IntervalYM",
+        }
+    }
+
+    fn invalid_conversion<T>(&self, to_type: &str) -> Result<T> {
+        Err(match self {
+            OwnedValue::Null => Error::null_value(),
+            _ => Error::invalid_type_conversion(self.type_name(), to_type),
+        })
+    }
+}
+
+impl fmt::Display for OwnedValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OwnedValue::Null => write!(f, "NULL"),
+            OwnedValue::Int64(n) => write!(f, "{}", n),
+            OwnedValue::UInt64(n) => write!(f, "{}", n),
+            OwnedValue::Number(s) => write!(f, "{}", s),
+            OwnedValue::Float(n) => write!(f, "{}", n),
+            OwnedValue::Double(n) => write!(f, "{}", n),
+            OwnedValue::Boolean(b) => write!(f, "{}", b),
+            OwnedValue::String(s) => write!(f, "{}", s),
+            OwnedValue::Bytes(bytes) => {
+                for b in bytes {
+                    write!(f, "{:02X}", b)?;
+                }
+                Ok(())
+            }
+            OwnedValue::Timestamp(ts) => write!(f, "{}", ts),
+            OwnedValue::IntervalDS(it) => write!(f, "{}", it),
+            OwnedValue::IntervalYM(it) => write!(f, "{}", it),
+        }
+    }
+}
+
+impl ToSql for OwnedValue {
+    fn oratype(&self, conn: &Connection) -> Result<OracleType> {
+        match self {
+            OwnedValue::Null => <&str>::oratype_for_null(conn),
+            OwnedValue::Int64(n) => n.oratype(conn),
+            OwnedValue::UInt64(n) => n.oratype(conn),
+            OwnedValue::Number(_) => Ok(OracleType::Number(0, 0)),
+            OwnedValue::Float(n) => n.oratype(conn),
+            OwnedValue::Double(n) => n.oratype(conn),
+            OwnedValue::Boolean(b) => b.oratype(conn),
+            OwnedValue::String(s) => s.oratype(conn),
+            OwnedValue::Bytes(bytes) => bytes.oratype(conn),
+            OwnedValue::Timestamp(ts) => ts.oratype(conn),
+            OwnedValue::IntervalDS(it) => it.oratype(conn),
+            OwnedValue::IntervalYM(it) => it.oratype(conn),
+        }
+    }
+
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        match self {
+            OwnedValue::Null => val.set_null(),
+            OwnedValue::Int64(n) => n.to_sql(val),
+            OwnedValue::UInt64(n) => n.to_sql(val),
+            OwnedValue::Number(s) => val.set(&(s, &OracleType::Number(0, 0))),
+            OwnedValue::Float(n) => n.to_sql(val),
+            OwnedValue::Double(n) => n.to_sql(val),
+            OwnedValue::Boolean(b) => b.to_sql(val),
+            OwnedValue::String(s) => s.to_sql(val),
+            OwnedValue::Bytes(bytes) => bytes.to_sql(val),
+            OwnedValue::Timestamp(ts) => ts.to_sql(val),
+            OwnedValue::IntervalDS(it) => it.to_sql(val),
+            OwnedValue::IntervalYM(it) => it.to_sql(val),
+        }
+    }
+}
+
+/// A trait to convert [`OwnedValue`] to a Rust type
+///
+/// This is used by [`OwnedRow::get`] and [`OwnedValue::get`].
+/// NULL values are converted only to `Option<T>`.
+pub trait FromOwnedValue: Sized {
+    /// Converts the value to the type
+    fn from_owned_value(val: &OwnedValue) -> Result<Self>;
+}
+
+macro_rules! impl_from_owned_value_for_integer {
+    ($($type:ty),*) => {
+        $(
+            impl FromOwnedValue for $type {
+                fn from_owned_value(val: &OwnedValue) -> Result<$type> {
+                    match val {
+                        OwnedValue::Int64(n) => <$type>::try_from(*n).map_err(|_| {
+                            Error::out_of_range(format!("{} overflows {}", n, stringify!($type)))
+                        }),
+                        OwnedValue::UInt64(n) => <$type>::try_from(*n).map_err(|_| {
+                            Error::out_of_range(format!("{} overflows {}", n, stringify!($type)))
+                        }),
+                        OwnedValue::Number(s) | OwnedValue::String(s) => {
+                            s.parse().map_err(Error::parse_error)
+                        }
+                        _ => val.invalid_conversion(stringify!($type)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_owned_value_for_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+macro_rules! impl_from_owned_value_for_float {
+    ($($type:ty),*) => {
+        $(
+            impl FromOwnedValue for $type {
+                fn from_owned_value(val: &OwnedValue) -> Result<$type> {
+                    match val {
+                        OwnedValue::Int64(n) => Ok(*n as $type),
+                        OwnedValue::UInt64(n) => Ok(*n as $type),
+                        OwnedValue::Float(n) => Ok(*n as $type),
+                        OwnedValue::Double(n) => Ok(*n as $type),
+                        OwnedValue::Number(s) | OwnedValue::String(s) => {
+                            s.parse().map_err(Error::parse_error)
+                        }
+                        _ => val.invalid_conversion(stringify!($type)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_owned_value_for_float!(f32, f64);
+
+impl FromOwnedValue for bool {
+    fn from_owned_value(val: &OwnedValue) -> Result<bool> {
+        match val {
+            OwnedValue::Boolean(b) => Ok(*b),
+            _ => val.invalid_conversion("bool"),
+        }
+    }
+}
+
+impl FromOwnedValue for String {
+    fn from_owned_value(val: &OwnedValue) -> Result<String> {
+        match val {
+            OwnedValue::Null => val.invalid_conversion("String"),
+            OwnedValue::String(s) | OwnedValue::Number(s) => Ok(s.clone()),
+            _ => Ok(val.to_string()),
+        }
+    }
+}
+
+impl FromOwnedValue for Vec<u8> {
+    fn from_owned_value(val: &OwnedValue) -> Result<Vec<u8>> {
+        match val {
+            OwnedValue::Bytes(bytes) => Ok(bytes.clone()),
+            _ => val.invalid_conversion("Vec<u8>"),
+        }
+    }
+}
+
+impl FromOwnedValue for Timestamp {
+    fn from_owned_value(val: &OwnedValue) -> Result<Timestamp> {
+        match val {
+            OwnedValue::Timestamp(ts) => Ok(*ts),
+            _ => val.invalid_conversion("Timestamp"),
+        }
+    }
+}
+
+impl FromOwnedValue for IntervalDS {
+    fn from_owned_value(val: &OwnedValue) -> Result<IntervalDS> {
+        match val {
+            OwnedValue::IntervalDS(it) => Ok(*it),
+            _ => val.invalid_conversion("IntervalDS"),
+        }
+    }
+}
+
+impl FromOwnedValue for IntervalYM {
+    fn from_owned_value(val: &OwnedValue) -> Result<IntervalYM> {
+        match val {
+            OwnedValue::IntervalYM(it) => Ok(*it),
+            _ => val.invalid_conversion("IntervalYM"),
+        }
+    }
+}
+
+impl FromOwnedValue for OwnedValue {
+    fn from_owned_value(val: &OwnedValue) -> Result<OwnedValue> {
+        Ok(val.clone())
+    }
+}
+
+impl<T: FromOwnedValue> FromOwnedValue for Option<T> {
+    fn from_owned_value(val: &OwnedValue) -> Result<Option<T>> {
+        match val {
+            OwnedValue::Null => Ok(None),
+            _ => T::from_owned_value(val).map(Some),
+        }
+    }
+}
+
+/// A row detached from fetch buffers
+///
+/// This is created by [`Row::to_owned_row`]. Unlike [`Row`], this is
+/// `Send` and `Sync` and doesn't refer to the connection.
+///
+/// # Examples
+///
+/// ```
+/// # use oracle::Error;
+/// # use oracle::test_util;
+/// use oracle::OwnedRow;
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// # let conn = test_util::connect()?;
+/// let rows = conn
+///     .query("select IntCol, StringCol from TestStrings where IntCol <= 2 order by IntCol", &[])?
+///     .map(|row| row?.to_owned_row())
+///     .collect::<Result<Vec<OwnedRow>, _>>()?;
+/// let rows = Arc::new(rows);
+///
+/// let handle = {
+///     let rows = rows.clone();
+///     thread::spawn(move || rows[1].get::<_, String>("STRINGCOL"))
+/// };
+/// assert_eq!(handle.join().unwrap()?, "String 2");
+/// assert_eq!(rows[0].get::<_, i32>(0)?, 1);
+/// # Ok::<(), Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct OwnedRow {
+    column_info: Arc<Vec<ColumnInfo>>,
+    column_values: Vec<OwnedValue>,
+}
+
+impl AssertSend for OwnedRow {}
+impl AssertSync for OwnedRow {}
+
+impl OwnedRow {
+    /// Creates a row from column names and values.
+    ///
+    /// Column types are guessed from the values. NULL values are typed as
+    /// `VARCHAR2`. This is intended to make rows returned by test doubles
+    /// such as `MockExecutor` in the `mock` module.
+    ///
+    /// An error is returned when the numbers of names and values differ.
+    pub fn new(names: &[&str], values: Vec<OwnedValue>) -> Result<OwnedRow> {
+        if names.len() != values.len() {
+            return Err(Error::invalid_argument(format!(
+                "{} column names for {} values",
+                names.len(),
+                values.len()
+            )));
+        }
+        let column_info = names
+            .iter()
+            .zip(values.iter())
+            .map(|(name, value)| {
+                ColumnInfo::from_parts(name.to_string(), value.oracle_type(), true)
+            })
+            .collect();
+        Ok(OwnedRow {
+            column_info: Arc::new(column_info),
+            column_values: values,
+        })
+    }
+
+    pub(crate) fn from_row(row: &Row) -> Result<OwnedRow> {
+        Ok(OwnedRow {
+            column_info: row.column_info.clone(),
+            column_values: row
+                .column_values
+                .iter()
+                .map(OwnedValue::from_sql_value)
+                .collect::<Result<_>>()?,
+        })
+    }
+
+    /// Gets the column value at the specified index.
+    pub fn get<I, T>(&self, colidx: I) -> Result<T>
+    where
+        I: ColumnIndex,
+        T: FromOwnedValue,
+    {
+        let pos = colidx.idx(&self.column_info)?;
+        self.column_values[pos].get()
+    }
+
+    /// Returns column values
+    pub fn values(&self) -> &[OwnedValue] {
+        &self.column_values
+    }
+
+    /// Returns information about columns
+    pub fn column_info(&self) -> &[ColumnInfo] {
+        &self.column_info
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convert_owned_value() {
+        let val = OwnedValue::Number("12.5".into());
+        assert_eq!(val.get::<f64>().unwrap(), 12.5);
+        assert_eq!(val.get::<String>().unwrap(), "12.5");
+        assert!(val.get::<i32>().is_err());
+        assert_eq!(OwnedValue::Int64(300).get::<i32>().unwrap(), 300);
+        assert!(OwnedValue::Int64(300).get::<u8>().is_err());
+        assert_eq!(OwnedValue::Bytes(vec![0xde, 0xad]).to_string(), "DEAD");
+        assert_eq!(OwnedValue::Null.get::<Option<i32>>().unwrap(), None);
+        assert!(OwnedValue::Null.get::<i32>().is_err());
+    }
+}
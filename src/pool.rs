@@ -19,8 +19,13 @@
 //!
 //! [Session Pooling in OCI]: https://www.oracle.com/pls/topic/lookup?ctx=dblatest&id=GUID-F9662FFB-EAEF-495C-96FC-49C6D1D9625C
 use crate::chkerr;
+use crate::conn;
 use crate::conn::Purity;
-use crate::connection::CommonCreateParamsBuilder;
+use crate::connection::{CommonCreateParamsBuilder, InnerConn};
+use crate::oci_attr::data_type::{AttrValue, DataType};
+use crate::oci_attr::handle::PoolHandle;
+use crate::oci_attr::mode::{ReadMode, WriteMode};
+use crate::oci_attr::OciAttr;
 use crate::AssertSend;
 use crate::AssertSync;
 use crate::Connection;
@@ -31,10 +36,13 @@ use crate::OdpiStr;
 use crate::Privilege;
 use crate::Result;
 use odpic_sys::*;
+use std::borrow::Cow;
 use std::convert::TryInto;
 use std::fmt;
 use std::ptr;
-use std::time::Duration;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock, RwLockWriteGuard};
+use std::time::{Duration, Instant};
 
 /// The mode to use when closing pools
 ///
@@ -161,6 +169,7 @@ impl I32Seconds {
 pub struct PoolOptions {
     username: String,
     password: String,
+    proxy_user: String,
     privilege: Option<Privilege>,
     external_auth: bool,
     tag: String,
@@ -174,6 +183,7 @@ impl PoolOptions {
         PoolOptions {
             username: "".into(),
             password: "".into(),
+            proxy_user: "".into(),
             privilege: None,
             external_auth: false,
             tag: "".into(),
@@ -183,6 +193,13 @@ impl PoolOptions {
         }
     }
 
+    /// Sets the username of the connection to get.
+    ///
+    /// This is for a [heterogeneous pool](PoolType::Heterogeneous), in which
+    /// sessions are pooled per credentials. Sessions authenticated by the
+    /// username and password are reused for later requests with the same
+    /// credentials. The credentials passed to [`PoolBuilder::new`] are used
+    /// when this isn't set.
     pub fn username<S>(mut self, username: S) -> Self
     where
         S: Into<String>,
@@ -191,6 +208,7 @@ impl PoolOptions {
         self
     }
 
+    /// Sets the password of the user set by [`PoolOptions::username`].
     pub fn password<S>(mut self, password: S) -> Self
     where
         S: Into<String>,
@@ -199,6 +217,34 @@ impl PoolOptions {
         self
     }
 
+    /// Sets the user whose session is created by proxy authentication.
+    ///
+    /// The user set by [`PoolOptions::username`] connects on behalf of
+    /// `proxy_user`. That is, the username passed to the pool is
+    /// `username[proxy_user]`. This is for a [heterogeneous pool](PoolType::Heterogeneous).
+    ///
+    /// ```no_run
+    /// # use oracle::Error;
+    /// # use oracle::pool::{PoolBuilder, PoolOptions, PoolType};
+    /// let pool = PoolBuilder::new("", "", "")
+    ///     .pool_type(PoolType::Heterogeneous)
+    ///     .build()?;
+    /// // The same as `PoolOptions::new().username("app_user[schema_user]")`
+    /// let opts = PoolOptions::new()
+    ///     .username("app_user")
+    ///     .password("app_password")
+    ///     .proxy_user("schema_user");
+    /// let conn = pool.get_with_options(&opts)?;
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn proxy_user<S>(mut self, proxy_user: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.proxy_user = proxy_user.into();
+        self
+    }
+
     pub fn privilege(mut self, privilege: Privilege) -> Self {
         self.privilege = Some(privilege);
         self
@@ -217,6 +263,49 @@ impl PoolOptions {
         self
     }
 
+    /// Sets a multi-property tag from `NAME=VALUE` pairs.
+    ///
+    /// The pairs are joined by semicolons such as `LANG=FRENCH;TIME_ZONE=UTC`.
+    /// An error whose kind is [`ErrorKind::InvalidArgument`](crate::ErrorKind::InvalidArgument)
+    /// is returned when a name or a value contains `=` or `;`.
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::pool::PoolOptions;
+    /// let opts = PoolOptions::new().tag_properties([("LANG", "FRENCH"), ("TIME_ZONE", "UTC")])?;
+    /// assert_eq!(opts, PoolOptions::new().tag("LANG=FRENCH;TIME_ZONE=UTC"));
+    ///
+    /// assert!(PoolOptions::new().tag_properties([("NLS", "LANG=FRENCH")]).is_err());
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn tag_properties<I, K, V>(mut self, properties: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let mut pairs = Vec::new();
+        for (name, value) in properties {
+            let (name, value) = (name.as_ref(), value.as_ref());
+            if name.contains(&['=', ';'][..]) || value.contains(&['=', ';'][..]) {
+                return Err(Error::invalid_argument(format!(
+                    "tag property {}={} must not contain '=' or ';' in its name and value",
+                    name, value
+                )));
+            }
+            pairs.push(format!("{}={}", name, value));
+        }
+        self.tag = pairs.join(";");
+        Ok(self)
+    }
+
+    /// Sets whether a connection with a different tag may be returned
+    /// when no connections with the specified tag are available.
+    ///
+    /// When it is enabled, a connection matching the tag exactly is preferred.
+    /// Otherwise, a connection with another tag or without tags is returned. Use [`Connection::tag`] to get the
+    /// tag of the returned connection. [`Connection::tag_found`] returns `false`
+    /// when the tag is different from the requested one.
     pub fn match_any_tag(mut self, enable: bool) -> Self {
         self.match_any_tag = enable;
         self
@@ -235,6 +324,22 @@ impl PoolOptions {
         self
     }
 
+    fn username_with_proxy_user(&self) -> Result<Cow<'_, str>> {
+        if self.proxy_user.is_empty() {
+            return Ok(Cow::Borrowed(&self.username));
+        }
+        if self.proxy_user.contains(['[', ']']) {
+            return Err(Error::invalid_argument(format!(
+                "invalid proxy user {:?}",
+                self.proxy_user
+            )));
+        }
+        Ok(Cow::Owned(format!(
+            "{}[{}]",
+            self.username, self.proxy_user
+        )))
+    }
+
     fn to_dpi_conn_create_params(&self, ctxt: &Context) -> dpiConnCreateParams {
         let mut conn_params = ctxt.conn_create_params();
 
@@ -452,6 +557,24 @@ impl PoolBuilder {
     /// the [OCI documentation](https://www.oracle.com/pls/topic/lookup?ctx=dblatest&id=GUID-B853A020-752F-494A-8D88-D0396EF57177)
     /// for more information. This functionality is only available when Oracle Client
     /// is at version 12.2 and higher.
+    ///
+    /// The procedure is called on the server side while a connection is
+    /// checked out. No additional round trip is required by the client.
+    ///
+    /// ```no_run
+    /// # use oracle::Error;
+    /// # use oracle::pool::{PoolBuilder, PoolOptions};
+    /// // create or replace package app_pkg as
+    /// //   procedure fixup(requested_tag varchar2, actual_tag varchar2);
+    /// // end;
+    /// let pool = PoolBuilder::new("scott", "tiger", "")
+    ///     .plsql_fixup_callback("scott.app_pkg.fixup")
+    ///     .build()?;
+    /// let opts = PoolOptions::new().tag_properties([("TIME_ZONE", "UTC")])?;
+    /// // app_pkg.fixup is called when the connection isn't tagged with TIME_ZONE=UTC.
+    /// let conn = pool.get_with_options(&opts)?;
+    /// # Ok::<(), Error>(())
+    /// ```
     pub fn plsql_fixup_callback<T>(&mut self, plsql: T) -> &mut PoolBuilder
     where
         T: Into<String>,
@@ -590,10 +713,51 @@ impl PoolBuilder {
         Ok(Pool {
             ctxt,
             handle: DpiPool::new(handle),
+            generation: Arc::new(AtomicUsize::new(0)),
+            pool_type: if pool_params.homogeneous != 0 {
+                PoolType::Homogeneous
+            } else {
+                PoolType::Heterogeneous
+            },
+            wait_callback: Arc::new(RwLock::new(None)),
         })
     }
 }
 
+/// An event passed to the callback registered by [`Pool::set_wait_callback`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoolWaitEvent {
+    elapsed: Duration,
+    busy_count: u32,
+    open_count: u32,
+    succeeded: bool,
+}
+
+impl PoolWaitEvent {
+    /// Returns the time spent to get a connection from the pool
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns the number of busy connections just after the wait
+    pub fn busy_count(&self) -> u32 {
+        self.busy_count
+    }
+
+    /// Returns the number of open connections just after the wait
+    pub fn open_count(&self) -> u32 {
+        self.open_count
+    }
+
+    /// Returns `true` when a connection was got and `false` when the wait
+    /// ended with an error such as a timeout
+    pub fn succeeded(&self) -> bool {
+        self.succeeded
+    }
+}
+
+type WaitCallback = (Duration, Arc<dyn Fn(&PoolWaitEvent) + Send + Sync>);
+
 /// Connection pool
 ///
 ///
@@ -648,6 +812,7 @@ impl PoolBuilder {
 ///     .pool_type(PoolType::Heterogeneous)
 ///     .max_connections(20)
 ///     .build()?;
+/// assert_eq!(pool.pool_type(), PoolType::Heterogeneous);
 ///
 /// // Get a connection from the pool.
 /// let conn1 = pool.get()?;
@@ -700,6 +865,7 @@ impl PoolBuilder {
 /// let conn = pool.get_with_options(&opts)?;
 /// assert_eq!(conn.tag_found(), true, "conn.tag_found() (2)");
 /// assert_eq!(conn.tag(), "LANG=FRENCH", "conn.tag() (2)");
+/// assert_eq!(conn.tag_properties(), [("LANG", "FRENCH")]);
 /// // Check whether this is the connection previously
 /// let sql = "select value from nls_session_parameters where parameter = 'NLS_LANGUAGE'";
 /// assert_eq!(conn.query_row_as::<String>(sql, &[])?, "FRENCH");
@@ -713,14 +879,19 @@ impl PoolBuilder {
 pub struct Pool {
     ctxt: Context,
     handle: DpiPool,
+    // incremented by `Pool::invalidate_all()`
+    generation: Arc<AtomicUsize>,
+    pool_type: PoolType,
+    // set by `Pool::set_wait_callback()` and shared by clones
+    wait_callback: Arc<RwLock<Option<WaitCallback>>>,
 }
 
 impl Pool {
-    fn handle(&self) -> *mut dpiPool {
+    pub(crate) fn handle(&self) -> *mut dpiPool {
         self.handle.raw()
     }
 
-    fn ctxt(&self) -> &Context {
+    pub(crate) fn ctxt(&self) -> &Context {
         &self.ctxt
     }
 
@@ -737,29 +908,224 @@ impl Pool {
         self.get_with_options(&PoolOptions::new())
     }
 
+    /// Acquires a connection and retries on errors which may disappear by waiting
+    ///
+    /// See [`RetryPolicy`](conn::RetryPolicy) and [`Connector::connect_with_retry`](crate::Connector::connect_with_retry).
+    pub fn get_with_retry(&self, policy: &conn::RetryPolicy) -> Result<Connection> {
+        policy.run(|| self.get())
+    }
+
     /// Acquires a connection from the specified connection pool.
     ///
     /// See also [`Pool::get`].
     pub fn get_with_options(&self, options: &PoolOptions) -> Result<Connection> {
         let ctxt = Context::new()?;
-        let username = OdpiStr::new(&options.username);
+        let username = options.username_with_proxy_user()?;
+        let username = OdpiStr::new(username.as_ref());
         let password = OdpiStr::new(&options.password);
         let mut conn_params = options.to_dpi_conn_create_params(&ctxt);
         let mut handle = ptr::null_mut();
-        chkerr!(
-            &ctxt,
-            dpiPool_acquireConnection(
-                self.handle(),
-                username.ptr,
-                username.len,
-                password.ptr,
-                password.len,
-                &mut conn_params,
-                &mut handle
-            )
-        );
-        ctxt.set_warning();
-        Ok(Connection::from_dpi_handle(ctxt, handle, &conn_params))
+        let wait_callback = self.wait_callback();
+        let start = wait_callback.as_ref().map(|_| Instant::now());
+        let result = (|| {
+            chkerr!(
+                &ctxt,
+                dpiPool_acquireConnection(
+                    self.handle(),
+                    username.ptr,
+                    username.len,
+                    password.ptr,
+                    password.len,
+                    &mut conn_params,
+                    &mut handle
+                )
+            );
+            // Get the warning before busy_count() and open_count() below
+            // call ODPI-C functions, which clear the error information.
+            ctxt.set_warning();
+            Ok(())
+        })();
+        // Wrap the handle before calling the callback so that the
+        // connection is released even when the callback panics.
+        let result = result.map(|()| {
+            let mut inner = InnerConn::new(ctxt, handle, &conn_params);
+            inner.pool_generation = Some((
+                self.generation.clone(),
+                self.generation.load(Ordering::Relaxed),
+            ));
+            Connection {
+                conn: Arc::new(inner),
+            }
+        });
+        if let (Some((threshold, callback)), Some(start)) = (wait_callback, start) {
+            let elapsed = start.elapsed();
+            if elapsed >= threshold {
+                callback(&PoolWaitEvent {
+                    elapsed,
+                    busy_count: self.busy_count().unwrap_or(0),
+                    open_count: self.open_count().unwrap_or(0),
+                    succeeded: result.is_ok(),
+                });
+            }
+        }
+        result
+    }
+
+    /// Registers a callback called when [`Pool::get`] and its variants wait
+    /// for `threshold` or longer
+    ///
+    /// The callback is called in the thread getting the connection after
+    /// the wait ends, whether a connection was got or not. It receives the
+    /// time spent and the numbers of busy and open connections at the time,
+    /// which helps to alert on pool saturation before waits reach
+    /// [`GetMode::TimedWait`] timeouts. Send events to a channel such as
+    /// [`std::sync::mpsc::SyncSender`] in the callback to handle them in
+    /// another thread.
+    ///
+    /// The callback is shared by clones of the pool. The previously
+    /// registered callback is replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::pool::PoolBuilder;
+    /// # use oracle::test_util;
+    /// # use std::time::Duration;
+    /// # let username = test_util::main_user();
+    /// # let password = test_util::main_password();
+    /// # let connect_string = test_util::connect_string();
+    /// use std::sync::mpsc;
+    ///
+    /// let pool = PoolBuilder::new(username, password, connect_string)
+    ///     .max_connections(3)
+    ///     .build()?;
+    /// let (tx, rx) = mpsc::sync_channel(10);
+    /// pool.set_wait_callback(Duration::ZERO, move |event| {
+    ///     let _ = tx.try_send(event.clone());
+    /// });
+    /// let conn = pool.get()?;
+    /// let event = rx.try_recv().unwrap();
+    /// assert!(event.succeeded());
+    /// assert_eq!(event.busy_count(), 1);
+    /// assert_eq!(event.open_count(), pool.open_count()?);
+    ///
+    /// pool.clear_wait_callback();
+    /// let conn2 = pool.get()?;
+    /// assert!(rx.try_recv().is_err());
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn set_wait_callback<F>(&self, threshold: Duration, callback: F)
+    where
+        F: Fn(&PoolWaitEvent) + Send + Sync + 'static,
+    {
+        *self.wait_callback_write() = Some((threshold, Arc::new(callback)));
+    }
+
+    /// Unregisters the callback registered by [`Pool::set_wait_callback`]
+    pub fn clear_wait_callback(&self) {
+        *self.wait_callback_write() = None;
+    }
+
+    fn wait_callback_write(&self) -> RwLockWriteGuard<'_, Option<WaitCallback>> {
+        match self.wait_callback.write() {
+            Ok(guard) => guard,
+            Err(err) => err.into_inner(),
+        }
+    }
+
+    // The lock is released before the callback is called so that
+    // the callback can replace itself without deadlock.
+    fn wait_callback(&self) -> Option<WaitCallback> {
+        match self.wait_callback.read() {
+            Ok(guard) => guard.clone(),
+            Err(err) => err.into_inner().clone(),
+        }
+    }
+
+    /// Drops idle sessions in the pool
+    ///
+    /// This acquires idle sessions one by one and closes them with
+    /// [`conn::CloseMode::Drop`]. Sessions in use aren't affected.
+    /// Subsequent [`Pool::get`] calls establish new sessions until
+    /// the pool grows again.
+    ///
+    /// This returns the number of dropped sessions. It stops when no idle
+    /// sessions are left. When it fails to get a session, the sessions got
+    /// so far are dropped and the error is returned. A new session
+    /// established because other threads took the remaining idle sessions
+    /// concurrently is returned to the pool without being dropped.
+    /// Though the number of idle sessions is checked before each get,
+    /// it may block as [`Pool::get`] does in that case.
+    ///
+    /// [`conn::CloseMode::Drop`]: crate::conn::CloseMode::Drop
+    pub fn expire_idle(&self) -> Result<u32> {
+        let mut idle_conns = Vec::new();
+        let result = (|| {
+            while self.open_count()? > self.busy_count()? {
+                let conn = self.get()?;
+                if conn.is_new_connection() {
+                    return conn.close();
+                }
+                // The session is dropped by `Drop` if closing it below isn't reached.
+                conn.set_drop_on_close(true);
+                idle_conns.push(conn);
+            }
+            Ok(())
+        })();
+        let count = idle_conns.len() as u32;
+        for conn in idle_conns {
+            conn.close_with_mode(conn::CloseMode::Drop)?;
+        }
+        result.map(|_| count)
+    }
+
+    /// Drops all sessions in the pool including those in use
+    ///
+    /// Idle sessions are dropped by [`Pool::expire_idle`]. Sessions in use
+    /// are dropped when they are released back to the pool, as if
+    /// [`Connection::set_drop_on_close(true)`] is called for them.
+    /// Use this to force all future [`Pool::get`] calls to establish new sessions
+    /// after a database password rotation or a failover.
+    ///
+    /// This returns the number of dropped idle sessions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::pool::PoolBuilder;
+    /// # use oracle::test_util;
+    /// # let username = test_util::main_user();
+    /// # let password = test_util::main_password();
+    /// # let connect_string = test_util::connect_string();
+    /// let pool = PoolBuilder::new(username, password, connect_string)
+    ///     .min_connections(0)
+    ///     .max_connections(3)
+    ///     .build()?;
+    /// let conn1 = pool.get()?;
+    /// let conn2 = pool.get()?;
+    /// conn2.close()?;
+    /// assert_eq!(pool.open_count()?, 2);
+    ///
+    /// // conn2's session is dropped now.
+    /// assert_eq!(pool.invalidate_all()?, 1);
+    /// assert_eq!(pool.open_count()?, 1);
+    ///
+    /// // conn1's session is dropped when it is released.
+    /// conn1.close()?;
+    /// assert_eq!(pool.open_count()?, 0);
+    ///
+    /// // A new session is established.
+    /// let conn3 = pool.get()?;
+    /// assert!(conn3.is_new_connection());
+    /// # Ok::<(), Error>(())
+    /// ```
+    ///
+    /// [`Connection::set_drop_on_close(true)`]: Connection::set_drop_on_close
+    pub fn invalidate_all(&self) -> Result<u32> {
+        self.generation.fetch_add(1, Ordering::Relaxed);
+        self.expire_idle()
     }
 
     /// Closes the pool and makes it unusable for further activity.
@@ -800,6 +1166,14 @@ impl Pool {
         Ok(count)
     }
 
+    /// Returns the pool type specified by [`PoolBuilder::pool_type`]
+    ///
+    /// Credentials set by [`PoolOptions::username`] and [`PoolOptions::password`]
+    /// are available only when this is [`PoolType::Heterogeneous`].
+    pub fn pool_type(&self) -> PoolType {
+        self.pool_type
+    }
+
     /// Returns the mode used for acquiring or getting connections from the pool.
     ///
     /// See also [`PoolBuilder::get_mode`] and [`Pool::set_get_mode`].
@@ -944,9 +1318,9 @@ impl Pool {
     /// Returns whether or not the SODA metadata cache is enabled or not.
     ///
     /// Enabling the SODA metadata cache can significantly improve the
-    /// performance of repeated calls to methods [`soda::Database::create_collection`]
+    /// performance of repeated calls to methods `soda::Database::create_collection`
     /// (when not specifying a value for the metadata parameter) and
-    /// [`soda::Database::open_collection`]. Note that the cache can
+    /// `soda::Database::open_collection`. Note that the cache can
     /// become out of date if changes to the metadata of cached collections
     /// are made externally.
     ///
@@ -954,7 +1328,6 @@ impl Pool {
     /// available in Oracle Client 19 from 19.11.
     ///
     /// See also [`Pool::set_soda_metadata_cache`].
-    #[doc(hidden)] // uncomment when SODA is supported.
     pub fn soda_metadata_cache(&self) -> Result<bool> {
         let mut val = 0;
         chkerr!(
@@ -970,7 +1343,6 @@ impl Pool {
     /// available in Oracle Client 19 from 19.11.
     ///
     /// See also [`Pool::soda_metadata_cache`].
-    #[doc(hidden)] // uncomment when SODA is supported.
     pub fn set_soda_metadata_cache(&mut self, enabled: bool) -> Result<()> {
         let enabled = i32::from(enabled);
         chkerr!(
@@ -1025,6 +1397,70 @@ impl Pool {
         chkerr!(self.ctxt(), dpiPool_setTimeout(self.handle(), val.0));
         Ok(())
     }
+
+    /// Gets an OCI handle attribute corresponding to the specified type parameter
+    /// See the [`oci_attr` module][crate::oci_attr] for details.
+    ///
+    /// Note that ODPI-C provides no generic way to access attributes of session pool
+    /// handles. Only attributes which ODPI-C also accesses such as `OCI_ATTR_SPOOL_STMTCACHESIZE`,
+    /// `OCI_ATTR_SPOOL_TIMEOUT`, `OCI_ATTR_SPOOL_GETMODE`, `OCI_ATTR_SPOOL_BUSY_COUNT`,
+    /// `OCI_ATTR_SPOOL_OPEN_COUNT`, `OCI_ATTR_SPOOL_MAX_LIFETIME_SESSION`,
+    /// `OCI_ATTR_SPOOL_WAIT_TIMEOUT` and `OCI_ATTR_SPOOL_MAX_PER_SHARD` are supported.
+    /// Others return an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::pool::PoolBuilder;
+    /// # use oracle::test_util;
+    /// use oracle::oci_attr::handle::SPool;
+    /// use oracle::oci_attr::mode::ReadWrite;
+    /// use oracle::oci_attr::OciAttr;
+    /// # let username = test_util::main_user();
+    /// # let password = test_util::main_password();
+    /// # let connect_string = test_util::connect_string();
+    ///
+    /// // OCI_ATTR_SPOOL_STMTCACHESIZE
+    /// struct SPoolStmtCacheSize;
+    /// unsafe impl OciAttr for SPoolStmtCacheSize {
+    ///     type HandleType = SPool;
+    ///     type Mode = ReadWrite;
+    ///     type DataType = u32;
+    ///     const ATTR_NUM: u32 = 208;
+    /// }
+    ///
+    /// let mut pool = PoolBuilder::new(username, password, connect_string)
+    ///     .stmt_cache_size(30)
+    ///     .build()?;
+    /// assert_eq!(pool.oci_attr::<SPoolStmtCacheSize>()?, 30);
+    /// pool.set_oci_attr::<SPoolStmtCacheSize>(&40)?;
+    /// assert_eq!(pool.stmt_cache_size()?, 40);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn oci_attr<T>(&self) -> Result<<<T::DataType as DataType>::Type as ToOwned>::Owned>
+    where
+        T: OciAttr,
+        T::HandleType: PoolHandle,
+        T::Mode: ReadMode,
+    {
+        let attr_value = AttrValue::from_pool(self, <T>::ATTR_NUM);
+        unsafe { <T::DataType>::get(attr_value) }
+    }
+
+    /// Sets an OCI handle attribute corresponding to the specified type parameter
+    /// See the [`oci_attr` module][crate::oci_attr] for details.
+    ///
+    /// See also [`Pool::oci_attr`] about supported attributes.
+    pub fn set_oci_attr<T>(&mut self, value: &<T::DataType as DataType>::Type) -> Result<()>
+    where
+        T: OciAttr,
+        T::HandleType: PoolHandle,
+        T::Mode: WriteMode,
+    {
+        let mut attr_value = AttrValue::from_pool(self, <T>::ATTR_NUM);
+        unsafe { <T::DataType>::set(&mut attr_value, value) }
+    }
 }
 
 impl fmt::Debug for Pool {
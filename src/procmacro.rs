@@ -18,3 +18,9 @@
 
 #[doc = include_str!("../oracle_procmacro/docs/row_value.md")]
 struct RowValue;
+
+#[doc = include_str!("../oracle_procmacro/docs/object_value.md")]
+struct ObjectValue;
+
+#[doc = include_str!("../oracle_procmacro/docs/sql_enum.md")]
+struct SqlEnum;
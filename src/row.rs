@@ -13,18 +13,22 @@
 // (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
 //-----------------------------------------------------------------------------
 
+use std::borrow::Cow;
 use std::fmt;
 use std::iter::FusedIterator;
 use std::marker::PhantomData;
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::sql_type::FromSql;
 use crate::statement::Stmt;
 use crate::AssertSend;
 use crate::ColumnIndex;
 use crate::ColumnInfo;
+use crate::ColumnProfile;
 #[cfg(doc)]
 use crate::Connection;
+use crate::OwnedRow;
 use crate::Result;
 use crate::SqlValue;
 #[cfg(doc)]
@@ -57,6 +61,120 @@ impl Row {
         self.column_values[pos].get()
     }
 
+    /// Gets the column value at the specified index or `default` when it is NULL.
+    ///
+    /// This is shorthand for `row.get::<_, Option<T>>(colidx)?.unwrap_or(default)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let row = conn.query_row("select 10, null from dual", &[])?;
+    /// assert_eq!(row.get_or(0, -1)?, 10);
+    /// assert_eq!(row.get_or(1, -1)?, -1);
+    /// assert_eq!(row.get_or_default::<_, String>(1)?, "");
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn get_or<I, T>(&self, colidx: I, default: T) -> Result<T>
+    where
+        I: ColumnIndex,
+        T: FromSql,
+    {
+        let pos = colidx.idx(&self.column_info)?;
+        let val = &self.column_values[pos];
+        if val.is_null()? {
+            Ok(default)
+        } else {
+            val.get()
+        }
+    }
+
+    /// Gets the column value at the specified index or [`T::default()`](Default::default)
+    /// when it is NULL.
+    ///
+    /// See [`Row::get_or`].
+    pub fn get_or_default<I, T>(&self, colidx: I) -> Result<T>
+    where
+        I: ColumnIndex,
+        T: FromSql + Default,
+    {
+        let pos = colidx.idx(&self.column_info)?;
+        let val = &self.column_values[pos];
+        if val.is_null()? {
+            Ok(T::default())
+        } else {
+            val.get()
+        }
+    }
+
+    /// Gets the `RAW` or `LONG RAW` column value at the specified index
+    /// as a byte slice without copying it.
+    ///
+    /// The slice refers to the fetch buffer and is valid while `self` is
+    /// borrowed. Use [`Row::get::<_, Vec<u8>>()`](Row::get) to get an owned
+    /// value or values of other types. NULL values are errors whose kind is
+    /// [`ErrorKind::NullValue`](crate::ErrorKind::NullValue).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let mut stmt = conn
+    ///     .statement("select hextoraw('0102FF') from dual")
+    ///     .build()?;
+    /// for row_result in stmt.query(&[])? {
+    ///     let row = row_result?;
+    ///     let bytes: &[u8] = row.get_bytes(0)?;
+    ///     assert_eq!(bytes, &[0x01, 0x02, 0xFF]);
+    /// }
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn get_bytes<I>(&self, colidx: I) -> Result<&[u8]>
+    where
+        I: ColumnIndex,
+    {
+        let pos = colidx.idx(&self.column_info)?;
+        self.column_values[pos].get_bytes()
+    }
+
+    /// Gets the column value at the specified index as a string without
+    /// copying it when possible.
+    ///
+    /// Character and number columns are borrowed from the fetch buffer
+    /// unless they contain invalid UTF-8, which is replaced with `U+FFFD`
+    /// in an owned string. Values of other types are converted to owned
+    /// strings. NULL values are errors whose kind is
+    /// [`ErrorKind::NullValue`](crate::ErrorKind::NullValue).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # use std::borrow::Cow;
+    /// # let conn = test_util::connect()?;
+    /// let mut stmt = conn
+    ///     .statement("select 'hello', sysdate from dual")
+    ///     .build()?;
+    /// for row_result in stmt.query(&[])? {
+    ///     let row = row_result?;
+    ///     assert!(matches!(row.get_str(0)?, Cow::Borrowed("hello")));
+    ///     assert!(matches!(row.get_str(1)?, Cow::Owned(_)));
+    /// }
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn get_str<I>(&self, colidx: I) -> Result<Cow<'_, str>>
+    where
+        I: ColumnIndex,
+    {
+        let pos = colidx.idx(&self.column_info)?;
+        self.column_values[pos].get_str()
+    }
+
     /// Returns column values as a vector of SqlValue
     pub fn sql_values(&self) -> &[SqlValue] {
         &self.column_values
@@ -91,6 +209,33 @@ impl Row {
     pub fn column_info(&self) -> &[ColumnInfo] {
         &self.column_info
     }
+
+    /// Returns an iterator visiting pairs of column information and its value
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let row = conn.query_row("select IntCol, StringCol from TestStrings where IntCol = 1", &[])?;
+    /// let pairs = row
+    ///     .columns()
+    ///     .map(|(info, val)| format!("{}={}", info.name(), val))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(pairs, vec!["INTCOL=1", "STRINGCOL=String 1"]);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn columns(&self) -> impl ExactSizeIterator<Item = (&ColumnInfo, &SqlValue<'_>)> {
+        self.column_info.iter().zip(self.column_values.iter())
+    }
+
+    /// Copies column values into an [`OwnedRow`], which can be sent to other threads.
+    ///
+    /// An error is returned when the row contains values which cannot be
+    /// detached from the connection such as objects and ref cursors.
+    /// See [`OwnedValue`](crate::OwnedValue) for supported types.
+    pub fn to_owned_row(&self) -> Result<OwnedRow> {
+        OwnedRow::from_row(self)
+    }
 }
 
 impl AssertSend for Row {}
@@ -111,6 +256,52 @@ enum StmtHolder<'a> {
     Owned(Stmt),
 }
 
+impl StmtHolder<'_> {
+    fn stmt_mut(&mut self) -> &mut Stmt {
+        match self {
+            StmtHolder::Borrowed(stmt) => stmt,
+            StmtHolder::Owned(stmt) => stmt,
+        }
+    }
+
+    fn stmt(&self) -> &Stmt {
+        match self {
+            StmtHolder::Borrowed(stmt) => stmt,
+            StmtHolder::Owned(stmt) => stmt,
+        }
+    }
+}
+
+/// Fetch statistics of [`ResultSet`]
+///
+/// This is a return value of [`ResultSet::fetch_stats`]. Use it to tune
+/// [`StatementBuilder::fetch_array_size`](crate::StatementBuilder::fetch_array_size)
+/// and [`StatementBuilder::prefetch_rows`](crate::StatementBuilder::prefetch_rows).
+///
+/// A fetch gets at most `fetch_array_size` rows from the Oracle client.
+/// It makes a round trip to the server unless the rows have been prefetched.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct FetchStats {
+    /// The number of fetches
+    pub fetches: u64,
+    /// The number of fetched rows
+    pub rows: u64,
+    /// The time spent in fetches
+    pub elapsed: Duration,
+}
+
+impl FetchStats {
+    /// Returns the average number of rows per fetch
+    pub fn rows_per_fetch(&self) -> f64 {
+        if self.fetches > 0 {
+            self.rows as f64 / self.fetches as f64
+        } else {
+            0.0
+        }
+    }
+}
+
 /// Result set
 ///
 /// # Remarks
@@ -137,6 +328,14 @@ where
     T: RowValue,
 {
     stmt: StmtHolder<'a>,
+    column_info: Arc<Vec<ColumnInfo>>,
+    // a row fetched by `peek()` and not returned by `next()` yet
+    pub(crate) peeked: Option<Option<Result<T>>>,
+    profiles: Option<Vec<ColumnProfile>>,
+    // the number of rows not returned by `next()` yet when it is known
+    remaining: Option<usize>,
+    // the number of items returned by `next()`
+    row_number: u64,
     phantom: PhantomData<T>,
 }
 
@@ -145,39 +344,271 @@ where
     T: RowValue,
 {
     pub(crate) fn new(stmt: &'a mut Stmt) -> ResultSet<'a, T> {
+        let column_info = stmt.row.as_ref().unwrap().column_info.clone();
         ResultSet {
             stmt: StmtHolder::Borrowed(stmt),
+            column_info,
+            peeked: None,
+            profiles: None,
+            remaining: None,
+            row_number: 0,
             phantom: PhantomData,
         }
     }
 
     pub(crate) fn from_stmt(stmt: Stmt) -> ResultSet<'a, T> {
+        let column_info = stmt.row.as_ref().unwrap().column_info.clone();
         ResultSet {
             stmt: StmtHolder::Owned(stmt),
+            column_info,
+            peeked: None,
+            profiles: None,
+            remaining: None,
+            row_number: 0,
             phantom: PhantomData,
         }
     }
 
-    fn stmt(&self) -> &Stmt {
-        match self.stmt {
-            StmtHolder::Borrowed(ref stmt) => stmt,
-            StmtHolder::Owned(ref stmt) => stmt,
-        }
+    fn fetch_next(&mut self) -> Option<Result<T>> {
+        let profiles = &mut self.profiles;
+        self.stmt.stmt_mut().next().map(|row_result| {
+            row_result.and_then(|row| {
+                if let Some(profiles) = profiles {
+                    for (profile, val) in profiles.iter_mut().zip(row.sql_values()) {
+                        profile.update(val);
+                    }
+                }
+                row.get_as::<T>()
+            })
+        })
     }
 
-    fn stmt_mut(&mut self) -> &mut Stmt {
-        match self.stmt {
-            StmtHolder::Borrowed(ref mut stmt) => stmt,
-            StmtHolder::Owned(ref mut stmt) => stmt,
+    /// Returns a reference to the next row without advancing the result set.
+    ///
+    /// The row is fetched from the server on the first call and kept until
+    /// [`next()`](Iterator::next) is called. This works like [`Peekable::peek()`]
+    /// and is useful to look one row ahead, for example when merging rows
+    /// from two result sets sorted by the same key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let mut rows = conn.query_as::<i32>("select IntCol from TestNumbers where IntCol <= 2 order by IntCol", &[])?;
+    ///
+    /// assert_eq!(rows.peek().unwrap().as_ref().unwrap(), &1);
+    /// assert_eq!(rows.peek().unwrap().as_ref().unwrap(), &1); // not advanced
+    /// assert_eq!(rows.next().unwrap()?, 1);
+    /// assert_eq!(rows.peek().unwrap().as_ref().unwrap(), &2);
+    /// assert_eq!(rows.next().unwrap()?, 2);
+    /// assert!(rows.peek().is_none());
+    /// assert!(rows.next().is_none());
+    /// # Ok::<(), Error>(())
+    /// ```
+    ///
+    /// [`Peekable::peek()`]: std::iter::Peekable::peek
+    pub fn peek(&mut self) -> Option<&Result<T>> {
+        if self.peeked.is_none() {
+            let next = self.fetch_next();
+            self.peeked = Some(next);
         }
+        self.peeked.as_ref().and_then(|row| row.as_ref())
+    }
+
+    /// Sets the number of rows not returned by the result set yet
+    ///
+    /// Oracle doesn't tell the number of rows until all rows are fetched.
+    /// When the number is known in advance, for example by
+    /// [`Connection::query_count`], this makes the upper bound of
+    /// [`Iterator::size_hint`] the number of remaining rows. It is useful
+    /// to show progress while rows are consumed.
+    ///
+    /// `rows` isn't checked. So the lower bound of the size hint stays zero
+    /// except when a row is buffered by [`ResultSet::peek`]. If `rows` differs
+    /// from the actual number of rows, the upper bound is wrong but rows
+    /// themselves are returned correctly.
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let sql = "select IntCol from TestNumbers";
+    /// let count = conn.query_count(sql, &[])?;
+    /// let mut rows = conn.query_as::<i32>(sql, &[])?.with_known_size(count as usize);
+    /// assert_eq!(rows.size_hint(), (0, Some(10)));
+    /// rows.next();
+    /// assert_eq!(rows.size_hint(), (0, Some(9)));
+    /// # Ok::<(), Error>(())
+    /// ```
+    ///
+    /// [`Connection::query_count`]: crate::Connection::query_count
+    pub fn with_known_size(mut self, rows: usize) -> Self {
+        self.remaining = Some(rows);
+        self
     }
 
     pub fn column_info(&self) -> &[ColumnInfo] {
-        &self.stmt().row.as_ref().unwrap().column_info
+        &self.column_info
+    }
+
+    /// Enables column profiling, which collects statistics of column values
+    /// in rows fetched after this call.
+    ///
+    /// The statistics are got by [`ResultSet::column_profiles`]. Profiling
+    /// costs conversion of each non-null value to a string or bytes. Values
+    /// failed to be converted are counted by [`ColumnProfile::error_count`]
+    /// and don't make the row fetch fail. Calling this method again resets
+    /// the statistics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let mut rows = conn.query(
+    ///     "select IntCol, case when IntCol <= 3 then null else 'x' end from TestNumbers",
+    ///     &[],
+    /// )?;
+    /// rows.enable_column_profiling();
+    /// for row_result in rows.by_ref() {
+    ///     row_result?;
+    /// }
+    /// let profiles = rows.column_profiles().unwrap();
+    /// assert_eq!(profiles[0].rows(), 10);
+    /// assert_eq!(profiles[0].null_count(), 0);
+    /// assert_eq!(profiles[0].error_count(), 0);
+    /// assert_eq!(profiles[0].min_length(), Some(1)); // "1"
+    /// assert_eq!(profiles[0].max_length(), Some(2)); // "10"
+    /// assert_eq!(profiles[0].distinct_estimate(), Some(10));
+    /// assert_eq!(profiles[1].null_count(), 3);
+    /// assert_eq!(profiles[1].distinct_estimate(), Some(1));
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn enable_column_profiling(&mut self) {
+        self.profiles = Some(
+            self.column_info
+                .iter()
+                .map(|info| ColumnProfile::new(info.oracle_type()))
+                .collect(),
+        );
+    }
+
+    /// Returns statistics of column values collected since
+    /// [`ResultSet::enable_column_profiling`] was called.
+    ///
+    /// This returns `None` when profiling isn't enabled.
+    pub fn column_profiles(&self) -> Option<&[ColumnProfile]> {
+        self.profiles.as_deref()
+    }
+
+    /// Fetches all remaining rows into `buf` after clearing it.
+    ///
+    /// This is same with `buf.extend(rows.collect::<Result<Vec<T>>>()?)` after
+    /// `buf.clear()` except that it doesn't allocate a new vector. The capacity of
+    /// `buf` is kept, so reusing a buffer avoids allocations in repeatedly executed
+    /// queries such as polling. The buffer is reserved for rows fetched together
+    /// with the first row. Use [`StatementBuilder::fetch_array_size`] to tune it.
+    ///
+    /// This returns the number of rows in `buf`. When an error occurs, `buf`
+    /// contains rows fetched before the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let mut stmt = conn
+    ///     .statement("select IntCol from TestNumbers where IntCol <= :1 order by IntCol")
+    ///     .build()?;
+    /// let mut buf = Vec::new();
+    ///
+    /// let n = stmt.query_as::<i32>(&[&3])?.try_collect_into(&mut buf)?;
+    /// assert_eq!(n, 3);
+    /// assert_eq!(buf, [1, 2, 3]);
+    ///
+    /// // The buffer is cleared and reused.
+    /// stmt.query_as::<i32>(&[&2])?.try_collect_into(&mut buf)?;
+    /// assert_eq!(buf, [1, 2]);
+    /// # Ok::<(), Error>(())
+    /// ```
+    ///
+    /// [`StatementBuilder::fetch_array_size`]: crate::StatementBuilder::fetch_array_size
+    pub fn try_collect_into(&mut self, buf: &mut Vec<T>) -> Result<usize> {
+        buf.clear();
+        if let Some(row) = self.next() {
+            buf.push(row?);
+            buf.reserve(self.stmt.stmt().buffered_rows());
+            for row in self.by_ref() {
+                buf.push(row?);
+            }
+        }
+        Ok(buf.len())
+    }
+
+    /// Returns fetch statistics since the query was executed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let mut stmt = conn
+    ///     .statement("select IntCol from TestNumbers")
+    ///     .fetch_array_size(4)
+    ///     .build()?;
+    /// let mut rows = stmt.query_as::<i32>(&[])?;
+    /// for row_result in rows.by_ref() {
+    ///     row_result?;
+    /// }
+    /// let stats = rows.fetch_stats();
+    /// assert_eq!(stats.rows, 10);
+    /// assert_eq!(stats.fetches, 3); // 4 + 4 + 2 rows
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn fetch_stats(&self) -> FetchStats {
+        self.stmt.stmt().fetch_stats
+    }
+
+    /// Returns the one-based row number of the row last returned by [`next()`](Iterator::next)
+    ///
+    /// This is counted in the client across fetches and is zero before the
+    /// first row is returned. A row kept by [`ResultSet::peek`] isn't counted
+    /// until it is returned by `next()`. Rows which failed to be converted
+    /// to `T` are also counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let mut stmt = conn
+    ///     .statement("select IntCol from TestNumbers order by IntCol")
+    ///     .fetch_array_size(4)
+    ///     .build()?;
+    /// let mut rows = stmt.query_as::<i32>(&[])?;
+    /// assert_eq!(rows.row_number(), 0);
+    /// rows.next().unwrap()?;
+    /// assert_eq!(rows.row_number(), 1);
+    /// rows.peek();
+    /// assert_eq!(rows.row_number(), 1);
+    /// for row_result in rows.by_ref() {
+    ///     row_result?;
+    /// }
+    /// assert_eq!(rows.row_number(), 10);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn row_number(&self) -> u64 {
+        self.row_number
     }
 }
 
-unsafe impl<T> Send for ResultSet<'static, T> where T: RowValue {}
+unsafe impl<T> Send for ResultSet<'static, T> where T: RowValue + Send {}
 
 impl<T> Iterator for ResultSet<'_, T>
 where
@@ -186,9 +617,27 @@ where
     type Item = Result<T>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.stmt_mut()
-            .next()
-            .map(|row_result| row_result.and_then(|row| row.get_as::<T>()))
+        let next = match self.peeked.take() {
+            Some(peeked) => peeked,
+            None => self.fetch_next(),
+        };
+        if next.is_some() {
+            self.row_number += 1;
+            if let Some(remaining) = self.remaining.as_mut() {
+                *remaining = remaining.saturating_sub(1);
+            }
+        }
+        next
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // The lower bound is the number of rows buffered by `peek()` because
+        // the number set by `with_known_size()` isn't checked.
+        match (&self.peeked, self.remaining) {
+            (Some(None), _) => (0, Some(0)),
+            (Some(Some(_)), remaining) => (1, remaining.map(|n| n.max(1))),
+            (None, remaining) => (0, remaining),
+        }
     }
 }
 
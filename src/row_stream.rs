@@ -0,0 +1,175 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2025 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+//! A bridge from blocking [`ResultSet`] to async [`Stream`] enabled by the `async-bridge` feature
+use crate::OwnedRow;
+use crate::Result;
+use crate::ResultSet;
+use crate::Row;
+use futures_core::Stream;
+use std::collections::VecDeque;
+use std::fmt;
+use std::pin::Pin;
+use std::sync::{Arc, Condvar, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+
+struct State {
+    rows: VecDeque<Result<OwnedRow>>,
+    // true when the fetching thread finished
+    done: bool,
+    // true when the stream was dropped
+    canceled: bool,
+    waker: Option<Waker>,
+}
+
+struct Shared {
+    state: Mutex<State>,
+    // notified when a row is taken or the stream is dropped
+    not_full: Condvar,
+}
+
+/// An async stream of rows fetched by a background thread
+///
+/// This is created by [`ResultSet::into_stream`].
+pub struct RowStream {
+    shared: Arc<Shared>,
+}
+
+impl RowStream {
+    fn new(mut rs: ResultSet<'static, Row>, buffer_rows: usize) -> RowStream {
+        let buffer_rows = buffer_rows.max(1);
+        let shared = Arc::new(Shared {
+            state: Mutex::new(State {
+                rows: VecDeque::with_capacity(buffer_rows),
+                done: false,
+                canceled: false,
+                waker: None,
+            }),
+            not_full: Condvar::new(),
+        });
+        let thread_shared = shared.clone();
+        thread::spawn(move || {
+            let shared = thread_shared;
+            for row_result in rs.by_ref() {
+                let row = row_result.and_then(|row| row.to_owned_row());
+                let is_err = row.is_err();
+                let mut state = match shared.state.lock() {
+                    Ok(state) => state,
+                    Err(_) => return,
+                };
+                while state.rows.len() >= buffer_rows && !state.canceled {
+                    state = match shared.not_full.wait(state) {
+                        Ok(state) => state,
+                        Err(_) => return,
+                    };
+                }
+                if state.canceled {
+                    return;
+                }
+                state.rows.push_back(row);
+                if let Some(waker) = state.waker.take() {
+                    waker.wake();
+                }
+                if is_err {
+                    break;
+                }
+            }
+            if let Ok(mut state) = shared.state.lock() {
+                state.done = true;
+                if let Some(waker) = state.waker.take() {
+                    waker.wake();
+                }
+            };
+        });
+        RowStream { shared }
+    }
+}
+
+impl Stream for RowStream {
+    type Item = Result<OwnedRow>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut state = match self.shared.state.lock() {
+            Ok(state) => state,
+            Err(err) => return Poll::Ready(Some(Err(err.into()))),
+        };
+        if let Some(row) = state.rows.pop_front() {
+            self.shared.not_full.notify_one();
+            Poll::Ready(Some(row))
+        } else if state.done {
+            Poll::Ready(None)
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl Drop for RowStream {
+    fn drop(&mut self) {
+        if let Ok(mut state) = self.shared.state.lock() {
+            state.canceled = true;
+        }
+        self.shared.not_full.notify_one();
+    }
+}
+
+impl fmt::Debug for RowStream {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RowStream")
+    }
+}
+
+impl ResultSet<'static, Row> {
+    /// Converts the result set into an async [`Stream`] of [`OwnedRow`]s
+    ///
+    /// This spawns a thread, which fetches rows and buffers at most
+    /// `buffer_rows` rows until they are taken by the stream. The thread
+    /// stops when all rows are fetched, an error occurs or the stream is dropped.
+    /// This is available only for result sets created by [`Connection::query`]
+    /// and [`Connection::query_named`], which don't borrow a statement.
+    ///
+    /// Note that the connection is used by the thread while rows are fetched.
+    /// Other calls on the connection wait for the current round trip in the
+    /// thread to finish.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// use futures::StreamExt;
+    /// # let conn = test_util::connect()?;
+    ///
+    /// let rows = conn.query("select IntCol from TestNumbers order by IntCol", &[])?;
+    /// let mut stream = rows.into_stream(100);
+    /// let mut sum = 0;
+    /// futures::executor::block_on(async {
+    ///     while let Some(row_result) = stream.next().await {
+    ///         sum += row_result?.get::<_, i32>(0)?;
+    ///     }
+    ///     Ok::<(), Error>(())
+    /// })?;
+    /// assert_eq!(sum, 55);
+    /// # Ok::<(), Error>(())
+    /// ```
+    ///
+    /// [`Connection::query`]: crate::Connection::query
+    /// [`Connection::query_named`]: crate::Connection::query_named
+    pub fn into_stream(self, buffer_rows: usize) -> RowStream {
+        RowStream::new(self, buffer_rows)
+    }
+}
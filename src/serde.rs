@@ -0,0 +1,248 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2025 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+//! Implementations of [`serde::Serialize`] enabled by the `serde` feature
+use crate::sql_type::{Collection, IntervalDS, IntervalYM, Object, OracleType, Timestamp};
+use crate::Error;
+use crate::ResultSet;
+use crate::Row;
+use crate::RowValue;
+use crate::SqlValue;
+use serde::ser::{Error as _, SerializeMap, SerializeSeq};
+use serde::{Serialize, Serializer};
+use std::cell::RefCell;
+use std::error;
+
+impl Serialize for SqlValue<'_> {
+    /// Serializes the value according to the Oracle type.
+    ///
+    /// | Oracle Type | Serialized as |
+    /// | --- | --- |
+    /// | NULL value of any type | none |
+    /// | `NUMBER`, `FLOAT`, `BINARY_FLOAT`, `BINARY_DOUBLE` | i64, u64 or f64 |
+    /// | `BOOLEAN` | bool |
+    /// | `RAW`, `LONG RAW`, `BLOB`, `BFILE` | bytes |
+    /// | `DATE`, `TIMESTAMP`, `INTERVAL` | string formatted by `Display` |
+    /// | object type | map of attribute names to values |
+    /// | collection type | sequence |
+    /// | `REF CURSOR` | error |
+    /// | other types | string |
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if self.is_null().map_err(S::Error::custom)? {
+            return serializer.serialize_none();
+        }
+        match self.oracle_type().map_err(S::Error::custom)? {
+            OracleType::Int64 => serializer.serialize_i64(self.get().map_err(S::Error::custom)?),
+            OracleType::UInt64 => serializer.serialize_u64(self.get().map_err(S::Error::custom)?),
+            OracleType::Number(_, _) | OracleType::Float(_) => {
+                let s: String = self.get().map_err(S::Error::custom)?;
+                if let Ok(n) = s.parse::<i64>() {
+                    serializer.serialize_i64(n)
+                } else if let Ok(n) = s.parse::<u64>() {
+                    serializer.serialize_u64(n)
+                } else if let Ok(n) = s.parse::<f64>() {
+                    serializer.serialize_f64(n)
+                } else {
+                    serializer.serialize_str(&s)
+                }
+            }
+            OracleType::BinaryFloat => {
+                serializer.serialize_f32(self.get().map_err(S::Error::custom)?)
+            }
+            OracleType::BinaryDouble => {
+                serializer.serialize_f64(self.get().map_err(S::Error::custom)?)
+            }
+            OracleType::Boolean => serializer.serialize_bool(self.get().map_err(S::Error::custom)?),
+            OracleType::Raw(_) | OracleType::LongRaw | OracleType::BLOB | OracleType::BFILE => {
+                serializer.serialize_bytes(&self.get::<Vec<u8>>().map_err(S::Error::custom)?)
+            }
+            OracleType::Date
+            | OracleType::Timestamp(_)
+            | OracleType::TimestampTZ(_)
+            | OracleType::TimestampLTZ(_) => self
+                .get::<Timestamp>()
+                .map_err(S::Error::custom)?
+                .serialize(serializer),
+            OracleType::IntervalDS(_, _) => self
+                .get::<IntervalDS>()
+                .map_err(S::Error::custom)?
+                .serialize(serializer),
+            OracleType::IntervalYM(_) => self
+                .get::<IntervalYM>()
+                .map_err(S::Error::custom)?
+                .serialize(serializer),
+            OracleType::Object(objtype) if objtype.is_collection() => self
+                .get::<Collection>()
+                .map_err(S::Error::custom)?
+                .serialize(serializer),
+            OracleType::Object(_) => self
+                .get::<Object>()
+                .map_err(S::Error::custom)?
+                .serialize(serializer),
+            OracleType::RefCursor => Err(S::Error::custom("REF CURSOR cannot be serialized")),
+            _ => serializer.serialize_str(&self.get::<String>().map_err(S::Error::custom)?),
+        }
+    }
+}
+
+impl Serialize for Row {
+    /// Serializes the row as a map of column names to values.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.column_values.len()))?;
+        for (info, value) in self.column_info.iter().zip(&self.column_values) {
+            map.serialize_entry(info.name(), value)?;
+        }
+        map.end()
+    }
+}
+
+impl<'a, T> ResultSet<'a, T>
+where
+    T: RowValue,
+{
+    /// Converts the result set into a value implementing [`Serialize`]
+    /// (available when `serde` feature is enabled.)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let rows = conn.query("select IntCol from TestNumbers where IntCol <= 2 order by IntCol", &[])?;
+    /// let json = serde_json::to_string(&rows.into_serializable()).unwrap();
+    /// assert_eq!(json, r#"[{"INTCOL":1},{"INTCOL":2}]"#);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn into_serializable(self) -> SerializableRows<'a, T> {
+        SerializableRows {
+            rows: RefCell::new(self),
+        }
+    }
+}
+
+/// A wrapper of [`ResultSet`] implementing [`Serialize`] (available when `serde` feature is enabled.)
+///
+/// This is created by [`ResultSet::into_serializable`].
+#[derive(Debug)]
+pub struct SerializableRows<'a, T>
+where
+    T: RowValue,
+{
+    // RefCell is used to fetch rows in `Serialize::serialize(&self, ..)`
+    rows: RefCell<ResultSet<'a, T>>,
+}
+
+impl<'a, T> SerializableRows<'a, T>
+where
+    T: RowValue,
+{
+    /// Returns the wrapped result set
+    pub fn into_inner(self) -> ResultSet<'a, T> {
+        self.rows.into_inner()
+    }
+}
+
+impl<T> Serialize for SerializableRows<'_, T>
+where
+    T: RowValue + Serialize,
+{
+    /// Fetches remaining rows and serializes them as a sequence.
+    ///
+    /// Rows serialized once aren't serialized again by later calls.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut rows = self.rows.borrow_mut();
+        let mut seq = serializer.serialize_seq(None)?;
+        for row in rows.by_ref() {
+            seq.serialize_element(&row.map_err(S::Error::custom)?)?;
+        }
+        seq.end()
+    }
+}
+
+impl Serialize for Object {
+    /// Serializes the object as a map of attribute names to values.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.object_type().num_attributes()))?;
+        for attr in self.attributes() {
+            let (name, value) = attr.map_err(S::Error::custom)?;
+            map.serialize_entry(name, &value)?;
+        }
+        map.end()
+    }
+}
+
+impl Serialize for Collection {
+    /// Serializes the collection as a sequence of elements.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(None)?;
+        for index in self.indices() {
+            let index = index.map_err(S::Error::custom)?;
+            let value = self.get_sql_value(index).map_err(S::Error::custom)?;
+            seq.serialize_element(&value)?;
+        }
+        seq.end()
+    }
+}
+
+macro_rules! impl_serialize_by_display {
+    ($($type:ty),*) => {
+        $(
+            impl Serialize for $type {
+                /// Serializes the value as a string formatted by `Display`.
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    serializer.collect_str(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_serialize_by_display!(Timestamp, IntervalDS, IntervalYM);
+
+// Gets a column value as JSON text and deserializes it by `from_str`.
+// This is used by `#[row_value(json)]` in `#[derive(RowValue)]`, which
+// passes `serde_json::from_str` of the user's crate so that this crate
+// doesn't depend on serde_json.
+// A NULL value is deserialized from `null` so that it is `None` when
+// the field type is `Option<T>`.
+pub fn row_value_json<T, E, F>(row: &Row, colname: &str, from_str: F) -> crate::Result<T>
+where
+    F: Fn(&str) -> Result<T, E>,
+    E: Into<Box<dyn error::Error + Send + Sync>>,
+{
+    match row.get::<_, Option<String>>(colname)? {
+        Some(text) => from_str(&text).map_err(Error::parse_error),
+        None => from_str("null").map_err(|_| Error::null_value()),
+    }
+}
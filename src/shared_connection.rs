@@ -0,0 +1,160 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2025 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use crate::sql_type::ToSql;
+use crate::AssertSend;
+use crate::AssertSync;
+use crate::Connection;
+use crate::Result;
+use crate::RowValue;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// A connection shared by threads with explicit locking
+///
+/// [`Connection`] is `Send` and `Sync`, but calls from multiple threads on
+/// one connection are just serialized by the Oracle client and all threads
+/// share one session state such as the current transaction. For example a
+/// commit by one thread also commits rows inserted by another thread.
+///
+/// `SharedConnection` makes the boundary explicit. Each method acquires an
+/// internal mutex during the call, which finishes all round trips needed
+/// by the call. Use [`SharedConnection::lock`] to run several calls
+/// exclusively such as statements in one transaction or fetching rows
+/// from a [`ResultSet`](crate::ResultSet).
+///
+/// Cloned values refer to the same connection.
+///
+/// # Examples
+///
+/// ```
+/// # use oracle::Error;
+/// # use oracle::test_util;
+/// use oracle::SharedConnection;
+/// use std::thread;
+///
+/// # let conn = test_util::connect()?;
+/// let shared = SharedConnection::new(conn);
+/// let handles = (1..=3)
+///     .map(|i| {
+///         let shared = shared.clone();
+///         thread::spawn(move || {
+///             shared.query_row_as::<String>("select StringCol from TestStrings where IntCol = :1", &[&i])
+///         })
+///     })
+///     .collect::<Vec<_>>();
+/// for (i, handle) in (1..=3).zip(handles) {
+///     assert_eq!(handle.join().unwrap()?, format!("String {}", i));
+/// }
+///
+/// // Execute statements in one transaction.
+/// {
+///     let conn = shared.lock()?;
+///     conn.execute("insert into TestTempTable values(1, 'First')", &[])?;
+///     conn.execute("insert into TestTempTable values(2, 'Second')", &[])?;
+///     conn.commit()?;
+/// }
+/// # Ok::<(), Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct SharedConnection {
+    conn: Arc<Mutex<Connection>>,
+}
+
+impl AssertSync for SharedConnection {}
+impl AssertSend for SharedConnection {}
+
+impl SharedConnection {
+    /// Creates a new shared connection from `conn`.
+    pub fn new(conn: Connection) -> SharedConnection {
+        SharedConnection {
+            conn: Arc::new(Mutex::new(conn)),
+        }
+    }
+
+    /// Locks the connection and returns a guard to use it exclusively
+    ///
+    /// Other threads wait on calls to `SharedConnection` until the guard is dropped.
+    pub fn lock(&self) -> Result<MutexGuard<'_, Connection>> {
+        Ok(self.conn.lock()?)
+    }
+
+    /// Calls `f` with the locked connection
+    pub fn with<F, T>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&Connection) -> Result<T>,
+    {
+        f(&*self.lock()?)
+    }
+
+    /// Executes a statement and returns the number of affected rows.
+    ///
+    /// See [`Connection::execute`].
+    pub fn execute(&self, sql: &str, params: &[&dyn ToSql]) -> Result<u64> {
+        self.with(|conn| conn.execute_returning_count(sql, params))
+    }
+
+    /// Executes a statement with named parameters and returns the number of affected rows.
+    ///
+    /// See [`Connection::execute_named`].
+    pub fn execute_named(&self, sql: &str, params: &[(&str, &dyn ToSql)]) -> Result<u64> {
+        self.with(|conn| conn.execute_named(sql, params)?.row_count())
+    }
+
+    /// Fetches all rows of a query. See [`Connection::query_collect`].
+    pub fn query_collect<T>(&self, sql: &str, params: &[&dyn ToSql]) -> Result<Vec<T>>
+    where
+        T: RowValue,
+    {
+        self.with(|conn| conn.query_collect(sql, params))
+    }
+
+    /// Fetches the first row of a query. See [`Connection::query_row_as`].
+    pub fn query_row_as<T>(&self, sql: &str, params: &[&dyn ToSql]) -> Result<T>
+    where
+        T: RowValue,
+    {
+        self.with(|conn| conn.query_row_as(sql, params))
+    }
+
+    /// Fetches the first row of a query with named parameters.
+    /// See [`Connection::query_row_as_named`].
+    pub fn query_row_as_named<T>(&self, sql: &str, params: &[(&str, &dyn ToSql)]) -> Result<T>
+    where
+        T: RowValue,
+    {
+        self.with(|conn| conn.query_row_as_named(sql, params))
+    }
+
+    /// Commits the current active transaction
+    pub fn commit(&self) -> Result<()> {
+        self.with(|conn| conn.commit())
+    }
+
+    /// Rolls back the current active transaction
+    pub fn rollback(&self) -> Result<()> {
+        self.with(|conn| conn.rollback())
+    }
+
+    /// Pings the connection. See [`Connection::ping`].
+    pub fn ping(&self) -> Result<()> {
+        self.with(|conn| conn.ping())
+    }
+}
+
+impl From<Connection> for SharedConnection {
+    fn from(conn: Connection) -> SharedConnection {
+        SharedConnection::new(conn)
+    }
+}
@@ -0,0 +1,764 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2025 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+//! Simple Oracle Document Access (available when `soda` feature is enabled.)
+//!
+//! SODA is a set of NoSQL-style APIs to store JSON documents in collections
+//! and to get them by keys or query-by-example (QBE) filters without SQL.
+//! It requires Oracle client 18.3 or later and Oracle Database 18.1 or later.
+//! The user needs the `SODA_APP` role.
+//!
+//! # Examples
+//!
+//! ```
+//! # use oracle::Error;
+//! # use oracle::test_util::{self, check_version, VER18};
+//! use oracle::soda;
+//! # let conn = test_util::connect()?;
+//! # if !check_version(&conn, &VER18, &VER18)? {
+//! #     return Ok(()); // skip this test
+//! # }
+//!
+//! let db = soda::Database::new(&conn)?;
+//! let coll = db.create_collection("RustOracleSodaBooks", None)?;
+//! assert!(db.collection_names()?.contains(&"RustOracleSodaBooks".to_string()));
+//!
+//! // Insert a document and get the key generated by the server.
+//! let doc = db.create_json_document(None, r#"{"title": "I, Robot", "year": 1950}"#)?;
+//! let key = coll.insert_one(&doc)?.key()?;
+//! let doc = db.create_json_document(None, r#"{"title": "Foundation", "year": 1951}"#)?;
+//! coll.insert_one(&doc)?;
+//!
+//! // Get the document by the key.
+//! let doc = coll.find().key(&key).get_one()?.unwrap();
+//! assert_eq!(doc.key()?, key);
+//! assert!(doc.content_as_string()?.contains("I, Robot"));
+//!
+//! // Query documents by example.
+//! let filter = r#"{"year": {"$lt": 1951}}"#;
+//! assert_eq!(coll.find().filter(filter).count()?, 1);
+//! for doc in coll.find().filter(filter).get_cursor()? {
+//!     assert!(doc?.content_as_string()?.contains("I, Robot"));
+//! }
+//!
+//! // Replace and remove the document.
+//! let new_doc = db.create_json_document(None, r#"{"title": "I, Robot", "year": 1952}"#)?;
+//! assert!(coll.find().key(&key).replace_one(&new_doc)?);
+//! assert_eq!(coll.find().filter(filter).count()?, 0);
+//! assert_eq!(coll.find().key(&key).remove()?, 1);
+//! assert_eq!(coll.find().count()?, 1);
+//! conn.commit()?;
+//!
+//! // Drop the collection.
+//! assert!(coll.drop_collection()?);
+//! assert!(db.open_collection("RustOracleSodaBooks")?.is_none());
+//! # Ok::<(), Error>(())
+//! ```
+use crate::chkerr;
+use crate::connection::Conn;
+use crate::Connection;
+use crate::Context;
+use crate::DpiSodaColl;
+use crate::DpiSodaDb;
+use crate::DpiSodaDoc;
+use crate::DpiSodaDocCursor;
+use crate::Error;
+use crate::OdpiStr;
+use crate::Result;
+use odpic_sys::*;
+use std::fmt;
+use std::os::raw::c_char;
+use std::ptr;
+use std::slice;
+use std::str;
+
+// Flags to commit the operation when autocommit is enabled on the connection
+fn flags(conn: &Conn) -> u32 {
+    if conn.autocommit() {
+        DPI_SODA_FLAGS_ATOMIC_COMMIT
+    } else {
+        DPI_SODA_FLAGS_DEFAULT
+    }
+}
+
+/// SODA database, which is the top-level object to create and open collections
+#[derive(Clone)]
+pub struct Database {
+    conn: Conn,
+    handle: DpiSodaDb,
+}
+
+impl Database {
+    /// Creates a SODA database object using the connection
+    pub fn new(conn: &Connection) -> Result<Database> {
+        let mut handle = ptr::null_mut();
+        chkerr!(conn.ctxt(), dpiConn_getSodaDb(conn.handle(), &mut handle));
+        Ok(Database {
+            conn: conn.conn.clone(),
+            handle: DpiSodaDb::new(handle),
+        })
+    }
+
+    fn ctxt(&self) -> &Context {
+        self.conn.ctxt()
+    }
+
+    /// Creates a collection or opens it when it exists already
+    ///
+    /// `metadata` is a JSON string to specify how the collection is stored.
+    /// The default metadata is used when it is `None`. An error is returned
+    /// when the collection exists with different metadata.
+    pub fn create_collection(&self, name: &str, metadata: Option<&str>) -> Result<Collection> {
+        let name = OdpiStr::new(name);
+        let metadata = OdpiStr::new(metadata.unwrap_or(""));
+        let mut handle = ptr::null_mut();
+        chkerr!(
+            self.ctxt(),
+            dpiSodaDb_createCollection(
+                self.handle.raw(),
+                name.ptr,
+                name.len,
+                metadata.ptr,
+                metadata.len,
+                flags(&self.conn),
+                &mut handle
+            )
+        );
+        Ok(Collection::new(self.conn.clone(), handle))
+    }
+
+    /// Opens an existing collection. `None` is returned when it doesn't exist.
+    pub fn open_collection(&self, name: &str) -> Result<Option<Collection>> {
+        let name = OdpiStr::new(name);
+        let mut handle = ptr::null_mut();
+        chkerr!(
+            self.ctxt(),
+            dpiSodaDb_openCollection(
+                self.handle.raw(),
+                name.ptr,
+                name.len,
+                flags(&self.conn),
+                &mut handle
+            )
+        );
+        Ok((!handle.is_null()).then(|| Collection::new(self.conn.clone(), handle)))
+    }
+
+    /// Returns names of all collections in the database sorted by name
+    pub fn collection_names(&self) -> Result<Vec<String>> {
+        let mut list = unsafe { std::mem::zeroed::<dpiStringList>() };
+        chkerr!(
+            self.ctxt(),
+            dpiSodaDb_getCollectionNames(
+                self.handle.raw(),
+                ptr::null(),
+                0,
+                0,
+                DPI_SODA_FLAGS_DEFAULT,
+                &mut list
+            )
+        );
+        let names = unsafe {
+            let num = list.__bindgen_anon_1.numStrings as usize;
+            (0..num)
+                .map(|i| {
+                    OdpiStr {
+                        ptr: *list.__bindgen_anon_2.strings.add(i),
+                        len: *list.__bindgen_anon_3.stringLengths.add(i),
+                    }
+                    .to_string()
+                })
+                .collect()
+        };
+        chkerr!(
+            self.ctxt(),
+            dpiSodaDb_freeCollectionNames(self.handle.raw(), &mut list)
+        );
+        Ok(names)
+    }
+
+    /// Creates a document with arbitrary content to be inserted into a collection
+    ///
+    /// `key` is required when the collection uses client-assigned keys.
+    /// `media_type` is `application/json` when it is `None`.
+    pub fn create_document(
+        &self,
+        key: Option<&str>,
+        content: &[u8],
+        media_type: Option<&str>,
+    ) -> Result<Document> {
+        let key = OdpiStr::new(key.unwrap_or(""));
+        let content = OdpiStr::new(content);
+        let media_type = OdpiStr::new(media_type.unwrap_or(""));
+        let mut handle = ptr::null_mut();
+        chkerr!(
+            self.ctxt(),
+            dpiSodaDb_createDocument(
+                self.handle.raw(),
+                key.ptr,
+                key.len,
+                content.ptr,
+                content.len,
+                media_type.ptr,
+                media_type.len,
+                DPI_SODA_FLAGS_DEFAULT,
+                &mut handle
+            )
+        );
+        Ok(Document::new(self.conn.clone(), handle))
+    }
+
+    /// Creates a document with JSON content to be inserted into a collection
+    ///
+    /// This is same with `create_document(key, json.as_bytes(), None)`.
+    pub fn create_json_document(&self, key: Option<&str>, json: &str) -> Result<Document> {
+        self.create_document(key, json.as_bytes(), None)
+    }
+}
+
+impl fmt::Debug for Database {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Database {{ handle: {:?} }}", self.handle.raw())
+    }
+}
+
+/// SODA collection, which stores documents
+#[derive(Clone)]
+pub struct Collection {
+    conn: Conn,
+    handle: DpiSodaColl,
+}
+
+impl Collection {
+    fn new(conn: Conn, handle: *mut dpiSodaColl) -> Collection {
+        Collection {
+            conn,
+            handle: DpiSodaColl::new(handle),
+        }
+    }
+
+    fn ctxt(&self) -> &Context {
+        self.conn.ctxt()
+    }
+
+    /// Returns the name of the collection
+    pub fn name(&self) -> Result<String> {
+        let mut s = OdpiStr::new("");
+        chkerr!(
+            self.ctxt(),
+            dpiSodaColl_getName(self.handle.raw(), &mut s.ptr, &mut s.len)
+        );
+        Ok(s.to_string())
+    }
+
+    /// Returns the metadata of the collection as a JSON string
+    pub fn metadata(&self) -> Result<String> {
+        let mut s = OdpiStr::new("");
+        chkerr!(
+            self.ctxt(),
+            dpiSodaColl_getMetadata(self.handle.raw(), &mut s.ptr, &mut s.len)
+        );
+        Ok(s.to_string())
+    }
+
+    /// Inserts a document into the collection
+    ///
+    /// The returned document has the key, the version and timestamps set by
+    /// the server but doesn't have content.
+    pub fn insert_one(&self, doc: &Document) -> Result<Document> {
+        let mut handle = ptr::null_mut();
+        chkerr!(
+            self.ctxt(),
+            dpiSodaColl_insertOne(
+                self.handle.raw(),
+                doc.handle.raw(),
+                flags(&self.conn),
+                &mut handle
+            )
+        );
+        Ok(Document::new(self.conn.clone(), handle))
+    }
+
+    /// Inserts a document or replaces the document with the same key
+    ///
+    /// This is available only for collections using client-assigned keys.
+    /// The returned document is same with that of [`Collection::insert_one`].
+    pub fn save(&self, doc: &Document) -> Result<Document> {
+        let mut handle = ptr::null_mut();
+        chkerr!(
+            self.ctxt(),
+            dpiSodaColl_save(
+                self.handle.raw(),
+                doc.handle.raw(),
+                flags(&self.conn),
+                &mut handle
+            )
+        );
+        Ok(Document::new(self.conn.clone(), handle))
+    }
+
+    /// Returns an operation builder to find, count, replace or remove documents
+    pub fn find(&self) -> Operation<'_> {
+        Operation::new(self)
+    }
+
+    /// Removes all documents in the collection
+    pub fn truncate(&self) -> Result<()> {
+        chkerr!(self.ctxt(), dpiSodaColl_truncate(self.handle.raw()));
+        Ok(())
+    }
+
+    /// Drops the collection. It returns `false` when the collection didn't exist.
+    pub fn drop_collection(&self) -> Result<bool> {
+        let mut is_dropped = 0;
+        chkerr!(
+            self.ctxt(),
+            dpiSodaColl_drop(self.handle.raw(), flags(&self.conn), &mut is_dropped)
+        );
+        Ok(is_dropped != 0)
+    }
+
+    /// Creates an index specified by a JSON string
+    pub fn create_index(&self, spec: &str) -> Result<()> {
+        let spec = OdpiStr::new(spec);
+        chkerr!(
+            self.ctxt(),
+            dpiSodaColl_createIndex(self.handle.raw(), spec.ptr, spec.len, flags(&self.conn))
+        );
+        Ok(())
+    }
+
+    /// Drops the index. It returns `false` when the index didn't exist.
+    ///
+    /// When `force` is true, the index is dropped even when the index
+    /// is in use. This applies only to spatial and JSON search indexes.
+    pub fn drop_index(&self, name: &str, force: bool) -> Result<bool> {
+        let name = OdpiStr::new(name);
+        let mut flags = flags(&self.conn);
+        if force {
+            flags |= DPI_SODA_FLAGS_INDEX_DROP_FORCE;
+        }
+        let mut is_dropped = 0;
+        chkerr!(
+            self.ctxt(),
+            dpiSodaColl_dropIndex(
+                self.handle.raw(),
+                name.ptr,
+                name.len,
+                flags,
+                &mut is_dropped
+            )
+        );
+        Ok(is_dropped != 0)
+    }
+}
+
+impl fmt::Debug for Collection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Collection {{ handle: {:?} }}", self.handle.raw())
+    }
+}
+
+/// A builder of operations on documents in a [`Collection`]
+///
+/// This is created by [`Collection::find`]. Methods setting criteria return
+/// `&mut Operation` to chain them. The other methods execute the operation
+/// on documents matching all criteria.
+pub struct Operation<'a> {
+    coll: &'a Collection,
+    key: Option<String>,
+    keys: Vec<String>,
+    version: Option<String>,
+    filter: Option<String>,
+    skip: u32,
+    limit: u32,
+    fetch_array_size: u32,
+    hint: Option<String>,
+    lock: bool,
+}
+
+impl<'a> Operation<'a> {
+    fn new(coll: &'a Collection) -> Operation<'a> {
+        Operation {
+            coll,
+            key: None,
+            keys: Vec::new(),
+            version: None,
+            filter: None,
+            skip: 0,
+            limit: 0,
+            fetch_array_size: 0,
+            hint: None,
+            lock: false,
+        }
+    }
+
+    /// Restricts the operation to the document with the key
+    pub fn key(&mut self, key: &str) -> &mut Operation<'a> {
+        self.key = Some(key.into());
+        self
+    }
+
+    /// Restricts the operation to documents with the keys
+    pub fn keys<S>(&mut self, keys: &[S]) -> &mut Operation<'a>
+    where
+        S: AsRef<str>,
+    {
+        self.keys = keys.iter().map(|key| key.as_ref().to_string()).collect();
+        self
+    }
+
+    /// Restricts the operation to the document with the version
+    ///
+    /// This is used with [`Operation::key`] for optimistic locking.
+    pub fn version(&mut self, version: &str) -> &mut Operation<'a> {
+        self.version = Some(version.into());
+        self
+    }
+
+    /// Restricts the operation to documents matching the query-by-example (QBE) filter
+    pub fn filter(&mut self, filter: &str) -> &mut Operation<'a> {
+        self.filter = Some(filter.into());
+        self
+    }
+
+    /// Skips the specified number of documents in the results
+    pub fn skip(&mut self, skip: u32) -> &mut Operation<'a> {
+        self.skip = skip;
+        self
+    }
+
+    /// Limits the number of documents in the results
+    pub fn limit(&mut self, limit: u32) -> &mut Operation<'a> {
+        self.limit = limit;
+        self
+    }
+
+    /// Sets the number of documents fetched at a time by [`Operation::get_cursor`]
+    pub fn fetch_array_size(&mut self, size: u32) -> &mut Operation<'a> {
+        self.fetch_array_size = size;
+        self
+    }
+
+    /// Sets the hint passed to the SQL statements generated by SODA
+    pub fn hint(&mut self, hint: &str) -> &mut Operation<'a> {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    /// Locks documents got by the operation like `SELECT ... FOR UPDATE`
+    pub fn lock(&mut self) -> &mut Operation<'a> {
+        self.lock = true;
+        self
+    }
+
+    // Calls `f` with dpiSodaOperOptions referring criteria in `self`
+    fn with_options<R, F>(&self, f: F) -> Result<R>
+    where
+        F: FnOnce(&dpiSodaOperOptions) -> Result<R>,
+    {
+        let mut opts = self.coll.ctxt().soda_oper_options();
+        let mut key_ptrs: Vec<*const c_char> = Vec::with_capacity(self.keys.len());
+        let mut key_lens: Vec<u32> = Vec::with_capacity(self.keys.len());
+        for key in &self.keys {
+            let s = OdpiStr::new(key);
+            key_ptrs.push(s.ptr);
+            key_lens.push(s.len);
+        }
+        if !self.keys.is_empty() {
+            opts.numKeys = u32::try_from(self.keys.len())?;
+            opts.keys = key_ptrs.as_mut_ptr();
+            opts.keyLengths = key_lens.as_mut_ptr();
+        }
+        if let Some(key) = &self.key {
+            let s = OdpiStr::new(key);
+            opts.key = s.ptr;
+            opts.keyLength = s.len;
+        }
+        if let Some(version) = &self.version {
+            let s = OdpiStr::new(version);
+            opts.version = s.ptr;
+            opts.versionLength = s.len;
+        }
+        if let Some(filter) = &self.filter {
+            let s = OdpiStr::new(filter);
+            opts.filter = s.ptr;
+            opts.filterLength = s.len;
+        }
+        if let Some(hint) = &self.hint {
+            let s = OdpiStr::new(hint);
+            opts.hint = s.ptr;
+            opts.hintLength = s.len;
+        }
+        opts.skip = self.skip;
+        opts.limit = self.limit;
+        opts.fetchArraySize = self.fetch_array_size;
+        opts.lock = i32::from(self.lock);
+        f(&opts)
+    }
+
+    /// Gets the first document. `None` is returned when no documents match.
+    pub fn get_one(&self) -> Result<Option<Document>> {
+        let coll = self.coll;
+        self.with_options(|opts| {
+            let mut handle = ptr::null_mut();
+            chkerr!(
+                coll.ctxt(),
+                dpiSodaColl_findOne(coll.handle.raw(), opts, flags(&coll.conn), &mut handle)
+            );
+            Ok((!handle.is_null()).then(|| Document::new(coll.conn.clone(), handle)))
+        })
+    }
+
+    /// Gets an iterator over matching documents
+    pub fn get_cursor(&self) -> Result<DocumentCursor> {
+        let coll = self.coll;
+        self.with_options(|opts| {
+            let mut handle = ptr::null_mut();
+            chkerr!(
+                coll.ctxt(),
+                dpiSodaColl_find(coll.handle.raw(), opts, flags(&coll.conn), &mut handle)
+            );
+            Ok(DocumentCursor {
+                conn: coll.conn.clone(),
+                handle: DpiSodaDocCursor::new(handle),
+            })
+        })
+    }
+
+    /// Gets all matching documents
+    pub fn get_documents(&self) -> Result<Vec<Document>> {
+        self.get_cursor()?.collect()
+    }
+
+    /// Returns the number of matching documents
+    ///
+    /// This cannot be used with [`Operation::skip`] and [`Operation::limit`].
+    pub fn count(&self) -> Result<u64> {
+        let coll = self.coll;
+        self.with_options(|opts| {
+            let mut count = 0;
+            chkerr!(
+                coll.ctxt(),
+                dpiSodaColl_getDocCount(coll.handle.raw(), opts, flags(&coll.conn), &mut count)
+            );
+            Ok(count)
+        })
+    }
+
+    /// Removes matching documents and returns the number of removed ones
+    pub fn remove(&self) -> Result<u64> {
+        let coll = self.coll;
+        self.with_options(|opts| {
+            let mut count = 0;
+            chkerr!(
+                coll.ctxt(),
+                dpiSodaColl_remove(coll.handle.raw(), opts, flags(&coll.conn), &mut count)
+            );
+            Ok(count)
+        })
+    }
+
+    /// Replaces the document specified by [`Operation::key`] with the content of `doc`
+    ///
+    /// It returns `false` when no documents are replaced.
+    pub fn replace_one(&self, doc: &Document) -> Result<bool> {
+        let coll = self.coll;
+        self.with_options(|opts| {
+            let mut replaced = 0;
+            chkerr!(
+                coll.ctxt(),
+                dpiSodaColl_replaceOne(
+                    coll.handle.raw(),
+                    opts,
+                    doc.handle.raw(),
+                    flags(&coll.conn),
+                    &mut replaced,
+                    ptr::null_mut()
+                )
+            );
+            Ok(replaced != 0)
+        })
+    }
+}
+
+impl fmt::Debug for Operation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Operation")
+            .field("coll", &self.coll)
+            .field("key", &self.key)
+            .field("keys", &self.keys)
+            .field("version", &self.version)
+            .field("filter", &self.filter)
+            .field("skip", &self.skip)
+            .field("limit", &self.limit)
+            .field("fetch_array_size", &self.fetch_array_size)
+            .field("hint", &self.hint)
+            .field("lock", &self.lock)
+            .finish()
+    }
+}
+
+/// An iterator over documents returned by [`Operation::get_cursor`]
+pub struct DocumentCursor {
+    conn: Conn,
+    handle: DpiSodaDocCursor,
+}
+
+impl DocumentCursor {
+    fn fetch_next(&mut self) -> Result<Option<Document>> {
+        let mut handle = ptr::null_mut();
+        chkerr!(
+            self.conn.ctxt(),
+            dpiSodaDocCursor_getNext(self.handle.raw(), DPI_SODA_FLAGS_DEFAULT, &mut handle)
+        );
+        Ok((!handle.is_null()).then(|| Document::new(self.conn.clone(), handle)))
+    }
+}
+
+impl Iterator for DocumentCursor {
+    type Item = Result<Document>;
+
+    fn next(&mut self) -> Option<Result<Document>> {
+        self.fetch_next().transpose()
+    }
+}
+
+impl fmt::Debug for DocumentCursor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DocumentCursor {{ handle: {:?} }}", self.handle.raw())
+    }
+}
+
+/// SODA document
+///
+/// Documents to be stored are created by [`Database::create_document`] or
+/// [`Database::create_json_document`]. Documents got from collections have
+/// attributes set by the server such as the key and the version.
+#[derive(Clone)]
+pub struct Document {
+    conn: Conn,
+    handle: DpiSodaDoc,
+}
+
+macro_rules! doc_str_attr {
+    ($(#[$attr:meta])* $name:ident, $func:ident) => {
+        $(#[$attr])*
+        pub fn $name(&self) -> Result<String> {
+            let mut s = OdpiStr::new("");
+            chkerr!(
+                self.conn.ctxt(),
+                $func(self.handle.raw(), &mut s.ptr, &mut s.len)
+            );
+            Ok(s.to_string())
+        }
+    };
+}
+
+impl Document {
+    fn new(conn: Conn, handle: *mut dpiSodaDoc) -> Document {
+        Document {
+            conn,
+            handle: DpiSodaDoc::new(handle),
+        }
+    }
+
+    doc_str_attr!(
+        /// Returns the key. It is empty when the key isn't set.
+        key,
+        dpiSodaDoc_getKey
+    );
+
+    doc_str_attr!(
+        /// Returns the version set by the server
+        version,
+        dpiSodaDoc_getVersion
+    );
+
+    doc_str_attr!(
+        /// Returns the timestamp when the document was created as a string
+        created_on,
+        dpiSodaDoc_getCreatedOn
+    );
+
+    doc_str_attr!(
+        /// Returns the timestamp when the document was last modified as a string
+        last_modified,
+        dpiSodaDoc_getLastModified
+    );
+
+    doc_str_attr!(
+        /// Returns the media type such as `application/json`
+        media_type,
+        dpiSodaDoc_getMediaType
+    );
+
+    // Returns the content and its encoding
+    fn raw_content(&self) -> Result<(&[u8], Option<&str>)> {
+        let mut ptr = ptr::null();
+        let mut len = 0;
+        let mut encoding = ptr::null();
+        chkerr!(
+            self.conn.ctxt(),
+            dpiSodaDoc_getContent(self.handle.raw(), &mut ptr, &mut len, &mut encoding)
+        );
+        let content = if ptr.is_null() {
+            &[][..]
+        } else {
+            unsafe { slice::from_raw_parts(ptr as *const u8, len as usize) }
+        };
+        let encoding = if encoding.is_null() {
+            None
+        } else {
+            Some(unsafe { std::ffi::CStr::from_ptr(encoding) }.to_str()?)
+        };
+        Ok((content, encoding))
+    }
+
+    /// Returns the content as bytes
+    ///
+    /// JSON content is encoded in the encoding detected by the server,
+    /// which is usually UTF-8. Use [`Document::content_as_string`] to get it
+    /// as a string.
+    pub fn content(&self) -> Result<Vec<u8>> {
+        Ok(self.raw_content()?.0.to_vec())
+    }
+
+    /// Returns the content as a string
+    ///
+    /// JSON content encoded in UTF-16 is converted to UTF-8.
+    pub fn content_as_string(&self) -> Result<String> {
+        let (content, encoding) = self.raw_content()?;
+        let from_u16 = |to_u16: fn([u8; 2]) -> u16| {
+            let units = content
+                .chunks_exact(2)
+                .map(|b| to_u16([b[0], b[1]]))
+                .collect::<Vec<_>>();
+            String::from_utf16(&units)
+                .map_err(|err| Error::invalid_operation("invalid UTF-16 content").add_source(err))
+        };
+        match encoding {
+            Some("UTF-16LE") => from_u16(u16::from_le_bytes),
+            Some("UTF-16BE") | Some("UTF-16") => from_u16(u16::from_be_bytes),
+            _ => Ok(str::from_utf8(content)?.to_string()),
+        }
+    }
+}
+
+impl fmt::Debug for Document {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Document {{ handle: {:?} }}", self.handle.raw())
+    }
+}
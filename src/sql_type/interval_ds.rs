@@ -20,17 +20,21 @@ use crate::ParseOracleTypeError;
 use crate::Result;
 use odpic_sys::dpiIntervalDS;
 use std::cmp::{self, Ordering};
+use std::convert::TryFrom;
 use std::fmt;
+use std::ops::Neg;
 use std::result;
 use std::str;
+use std::time::Duration;
 
 /// Oracle-specific [Interval Day to Second][INTVL_DS] data type.
 ///
 /// [INTVL_DS]: https://www.oracle.com/pls/topic/lookup?ctx=dblatest&id=GUID-FD8C41B7-8CDC-4D02-8E6B-5250416BC17D
 ///
-/// This struct doesn't have arithmetic methods and they won't be added to avoid
-/// reinventing the wheel. If you need methods such as adding an interval to a
-/// timestamp, enable `chrono` feature and use [chrono::Duration][] instead.
+/// This struct has basic arithmetic methods such as [`IntervalDS::checked_add`]
+/// and conversion from and to [`std::time::Duration`]. Intervals are compared
+/// and ordered by their lengths. If you need more, enable `chrono` feature and
+/// use [chrono::Duration][] instead.
 ///
 /// [chrono::Duration]: https://docs.rs/chrono/0.4/chrono/struct.Duration.html
 ///
@@ -67,6 +71,34 @@ use std::str;
 /// # Ok::<(), Error>(())
 /// ```
 ///
+/// Arithmetic and conversion from and to [`Duration`].
+///
+/// ```
+/// # use oracle::*; use oracle::sql_type::*;
+/// use std::time::Duration;
+///
+/// let intvl1 = IntervalDS::from_duration(Duration::from_secs(90061))?;
+/// assert_eq!(intvl1, IntervalDS::new(1, 1, 1, 1, 0)?);
+///
+/// // Create a negative interval from Duration.
+/// let intvl2 = -IntervalDS::from_duration(Duration::from_millis(1500))?;
+/// assert_eq!(intvl2.to_string(), "-000000000 00:00:01.500000000");
+/// assert!(intvl2.is_negative());
+/// assert_eq!(intvl2.unsigned_abs(), Duration::from_millis(1500));
+/// assert_eq!(intvl2.total_nanoseconds(), -1_500_000_000);
+///
+/// // Add and compare intervals.
+/// let intvl3 = intvl1.checked_add(&intvl2).unwrap();
+/// assert_eq!(intvl3, IntervalDS::new(1, 1, 0, 59, 500000000)?);
+/// assert!(intvl2 < intvl3 && intvl3 < intvl1);
+///
+/// // A negative interval cannot be converted to Duration.
+/// assert!(Duration::try_from(intvl2).is_err());
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// [`Duration`]: std::time::Duration
+///
 /// Fetch and bind interval values.
 ///
 /// ```no_run
@@ -255,6 +287,125 @@ impl IntervalDS {
     pub fn fsprec(&self) -> u8 {
         self.fsprec
     }
+
+    /// Creates a new IntervalDS from the total number of nanoseconds.
+    ///
+    /// An error is returned when the number of days is out of range.
+    pub fn from_total_nanoseconds(nanos: i128) -> Result<IntervalDS> {
+        let days = nanos / NANOS_PER_DAY;
+        if days.abs() > 999999999 {
+            return Err(Error::out_of_range(format!(
+                "days must be between -999999999 and 999999999 but {}",
+                days
+            )));
+        }
+        let rem = nanos % NANOS_PER_DAY;
+        Ok(IntervalDS {
+            days: days as i32,
+            hours: (rem / (3600 * NANOS_PER_SEC)) as i32,
+            minutes: (rem / (60 * NANOS_PER_SEC) % 60) as i32,
+            seconds: (rem / NANOS_PER_SEC % 60) as i32,
+            nanoseconds: (rem % NANOS_PER_SEC) as i32,
+            lfprec: 9,
+            fsprec: 9,
+        })
+    }
+
+    /// Creates a new positive IntervalDS from [`Duration`].
+    ///
+    /// Use `-IntervalDS::from_duration(dur)?` to create a negative one.
+    ///
+    /// [`Duration`]: std::time::Duration
+    pub fn from_duration(dur: Duration) -> Result<IntervalDS> {
+        IntervalDS::from_total_nanoseconds(dur.as_nanos() as i128)
+    }
+
+    /// Returns the total number of nanoseconds, which is negative for negative intervals.
+    pub fn total_nanoseconds(&self) -> i128 {
+        self.days as i128 * NANOS_PER_DAY
+            + self.hours as i128 * 3600 * NANOS_PER_SEC
+            + self.minutes as i128 * 60 * NANOS_PER_SEC
+            + self.seconds as i128 * NANOS_PER_SEC
+            + self.nanoseconds as i128
+    }
+
+    /// Returns true when the interval is negative.
+    pub fn is_negative(&self) -> bool {
+        self.total_nanoseconds() < 0
+    }
+
+    /// Returns the absolute length of the interval as [`Duration`].
+    ///
+    /// [`Duration`]: std::time::Duration
+    pub fn unsigned_abs(&self) -> Duration {
+        let nanos = self.total_nanoseconds().unsigned_abs();
+        Duration::new(
+            (nanos / NANOS_PER_SEC as u128) as u64,
+            (nanos % NANOS_PER_SEC as u128) as u32,
+        )
+    }
+
+    /// Adds two intervals. `None` is returned when the result is out of range.
+    ///
+    /// The precisions of the result are the larger ones of the two.
+    pub fn checked_add(&self, other: &IntervalDS) -> Option<IntervalDS> {
+        IntervalDS::from_total_nanoseconds(self.total_nanoseconds() + other.total_nanoseconds())
+            .ok()
+            .map(|it| IntervalDS {
+                lfprec: cmp::max(self.lfprec, other.lfprec),
+                fsprec: cmp::max(self.fsprec, other.fsprec),
+                ..it
+            })
+    }
+
+    /// Subtracts `other` from `self`. `None` is returned when the result is out of range.
+    ///
+    /// The precisions of the result are the larger ones of the two.
+    pub fn checked_sub(&self, other: &IntervalDS) -> Option<IntervalDS> {
+        self.checked_add(&-*other)
+    }
+}
+
+const NANOS_PER_SEC: i128 = 1_000_000_000;
+const NANOS_PER_DAY: i128 = 86400 * NANOS_PER_SEC;
+
+impl Neg for IntervalDS {
+    type Output = IntervalDS;
+
+    fn neg(self) -> IntervalDS {
+        IntervalDS {
+            days: -self.days,
+            hours: -self.hours,
+            minutes: -self.minutes,
+            seconds: -self.seconds,
+            nanoseconds: -self.nanoseconds,
+            ..self
+        }
+    }
+}
+
+impl TryFrom<Duration> for IntervalDS {
+    type Error = Error;
+
+    fn try_from(dur: Duration) -> Result<IntervalDS> {
+        IntervalDS::from_duration(dur)
+    }
+}
+
+impl TryFrom<IntervalDS> for Duration {
+    type Error = Error;
+
+    /// Converts a zero or positive interval to `Duration`.
+    fn try_from(it: IntervalDS) -> Result<Duration> {
+        if it.is_negative() {
+            Err(Error::out_of_range(format!(
+                "negative interval {} cannot be converted to Duration",
+                it
+            )))
+        } else {
+            Ok(it.unsigned_abs())
+        }
+    }
 }
 
 impl cmp::PartialEq for IntervalDS {
@@ -267,6 +418,20 @@ impl cmp::PartialEq for IntervalDS {
     }
 }
 
+impl cmp::Eq for IntervalDS {}
+
+impl cmp::PartialOrd for IntervalDS {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl cmp::Ord for IntervalDS {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.total_nanoseconds().cmp(&other.total_nanoseconds())
+    }
+}
+
 impl fmt::Display for IntervalDS {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if self.days < 0
@@ -386,6 +551,45 @@ impl str::FromStr for IntervalDS {
 mod tests {
     use super::*;
 
+    #[test]
+    fn arithmetic() -> Result<()> {
+        let it = IntervalDS::new(1, 2, 3, 4, 5)?;
+        assert_eq!(
+            it.total_nanoseconds(),
+            (((24 + 2) * 60 + 3) * 60 + 4) * 1_000_000_000i128 + 5
+        );
+        assert_eq!(
+            IntervalDS::from_total_nanoseconds(it.total_nanoseconds())?,
+            it
+        );
+        assert_eq!(
+            IntervalDS::from_total_nanoseconds(-it.total_nanoseconds())?,
+            IntervalDS::new(-1, -2, -3, -4, -5)?
+        );
+        assert_eq!(-it, IntervalDS::new(-1, -2, -3, -4, -5)?);
+        assert!(IntervalDS::from_total_nanoseconds(1_000_000_000 * NANOS_PER_DAY).is_err());
+
+        let one_sec = IntervalDS::new(0, 0, 0, 1, 0)?;
+        let half_sec = IntervalDS::new(0, 0, 0, 0, 500_000_000)?;
+        assert_eq!(one_sec.checked_sub(&half_sec), Some(half_sec));
+        assert_eq!(
+            half_sec.checked_sub(&one_sec),
+            Some(IntervalDS::new(0, 0, 0, 0, -500_000_000)?)
+        );
+        let max = IntervalDS::new(999999999, 23, 59, 59, 999999999)?;
+        assert_eq!(max.checked_add(&half_sec), None);
+        assert_eq!(max.checked_sub(&half_sec).map(|it| it < max), Some(true));
+        assert!(-one_sec < -half_sec);
+
+        assert_eq!(Duration::try_from(half_sec)?, Duration::from_millis(500));
+        assert!(Duration::try_from(-half_sec).is_err());
+        assert_eq!(
+            IntervalDS::try_from(Duration::from_millis(1500))?,
+            one_sec.checked_add(&half_sec).unwrap()
+        );
+        Ok(())
+    }
+
     #[test]
     fn to_string() -> Result<()> {
         let mut it = IntervalDS::new(1, 2, 3, 4, 123456789)?;
@@ -18,8 +18,10 @@ use crate::sql_type::FromSql;
 use crate::sql_type::OracleType;
 use crate::sql_type::ToSql;
 use crate::sql_type::ToSqlNull;
+use crate::util::Sha256;
 use crate::Connection;
 use crate::Context;
+use crate::Error;
 use crate::OdpiStr;
 use crate::Result;
 use crate::SqlValue;
@@ -75,6 +77,70 @@ impl LobLocator {
         Ok(())
     }
 
+    fn try_clone(&self) -> Result<LobLocator> {
+        let mut handle = ptr::null_mut();
+        chkerr!(self.ctxt(), dpiLob_copy(self.handle, &mut handle));
+        Ok(LobLocator {
+            ctxt: self.ctxt.clone(),
+            handle,
+            pos: self.pos,
+        })
+    }
+
+    /// Compares contents chunk by chunk without changing the current position.
+    ///
+    /// `nls_ratio` is the maximum number of bytes per unit of `amount`.
+    fn content_eq(&self, other: &LobLocator, nls_ratio: usize) -> Result<bool> {
+        let size = self.size()?;
+        if size != other.size()? {
+            return Ok(false);
+        }
+        let amount = cmp::max(self.chunk_size()?, 1) as u64;
+        let mut buf1 = vec![0u8; amount as usize * nls_ratio];
+        let mut buf2 = vec![0u8; amount as usize * nls_ratio];
+        let mut offset = 1;
+        while offset <= size {
+            let len1 = self.read_bytes_at(offset, amount, &mut buf1)?;
+            let len2 = other.read_bytes_at(offset, amount, &mut buf2)?;
+            if buf1[..len1] != buf2[..len2] {
+                return Ok(false);
+            }
+            offset += amount;
+        }
+        Ok(true)
+    }
+
+    /// Computes the SHA-256 digest by reading the content chunk by chunk
+    /// without changing the current position.
+    fn sha256(&self, nls_ratio: usize) -> Result<[u8; 32]> {
+        let size = self.size()?;
+        let amount = cmp::max(self.chunk_size()?, 1) as u64;
+        let mut buf = vec![0u8; amount as usize * nls_ratio];
+        let mut hasher = Sha256::new();
+        let mut offset = 1;
+        while offset <= size {
+            let len = self.read_bytes_at(offset, amount, &mut buf)?;
+            hasher.update(&buf[..len]);
+            offset += amount;
+        }
+        Ok(hasher.finish())
+    }
+
+    fn read_bytes_at(&self, offset: u64, amount: u64, buf: &mut [u8]) -> Result<usize> {
+        let mut len = buf.len() as u64;
+        chkerr!(
+            self.ctxt(),
+            dpiLob_readBytes(
+                self.handle,
+                offset,
+                amount,
+                buf.as_mut_ptr() as *mut c_char,
+                &mut len
+            )
+        );
+        Ok(len as usize)
+    }
+
     fn read_bytes(&mut self, amount: usize, buf: &mut [u8]) -> Result<usize> {
         unsafe { self.read_bytes_unsafe(amount, buf.as_mut_ptr(), buf.len()) }
     }
@@ -185,6 +251,46 @@ impl LobLocator {
         Ok(len)
     }
 
+    // Reads data from the current position to the end when its size in bytes
+    // doesn't exceed `max`. The size is checked before reading by the LOB size
+    // in `unit`, which is one byte or more per unit.
+    fn read_capped(&mut self, max: usize, nls_ratio: usize, unit: &str) -> Result<Vec<u8>> {
+        let exceeded = |size: u64, unit: &str| {
+            Error::out_of_range(format!(
+                "LOB data size {} {} exceeds the limit {} bytes",
+                size, unit, max
+            ))
+        };
+        let rest_size = self.size()?.saturating_sub(self.pos);
+        if rest_size > max as u64 {
+            return Err(exceeded(rest_size, unit));
+        }
+        if rest_size == 0 {
+            return Ok(Vec::new());
+        }
+        let rest_size = rest_size as usize;
+        let mut buf = Vec::with_capacity(rest_size.saturating_mul(nls_ratio));
+        let len = unsafe { self.read_bytes_unsafe(rest_size, buf.as_mut_ptr(), buf.capacity())? };
+        unsafe { buf.set_len(len) };
+        if len > max {
+            return Err(exceeded(len as u64, "bytes"));
+        }
+        Ok(buf)
+    }
+
+    fn read_binary_capped(&mut self, max: usize) -> Result<Vec<u8>> {
+        let buf = self.read_capped(max, 1, "bytes")?;
+        self.pos += buf.len() as u64;
+        Ok(buf)
+    }
+
+    fn read_chars_capped(&mut self, max: usize) -> Result<String> {
+        let buf = self.read_capped(max, 4, "characters")?;
+        let s = String::from_utf8(buf).map_err(|err| err.utf8_error())?;
+        self.pos += s.chars().fold(0, |acc, c| acc + c.len_utf16()) as u64;
+        Ok(s)
+    }
+
     fn write_bytes(&mut self, buf: &[u8]) -> Result<usize> {
         let len = buf.len() as u64;
         chkerr!(
@@ -342,6 +448,18 @@ impl Drop for LobLocator {
     }
 }
 
+fn sha256_in_db(conn: &Connection, lob: &dyn ToSql) -> Result<[u8; 32]> {
+    let mut stmt = conn
+        .statement("begin :1 := dbms_crypto.hash(:2, dbms_crypto.hash_sh256); end;")
+        .lob_locator()
+        .build()?;
+    stmt.execute(&[&OracleType::Raw(32), lob])?;
+    let digest: Vec<u8> = stmt.bind_value(1)?;
+    digest
+        .try_into()
+        .map_err(|_| Error::internal_error("unexpected length of SHA-256 digest"))
+}
+
 /// A trait for LOB types
 pub trait Lob {
     /// Returns the size of the data stored in the LOB.
@@ -528,6 +646,53 @@ impl Blob {
     pub fn close(&mut self) -> Result<()> {
         self.lob.close()
     }
+
+    /// Computes the SHA-256 digest of the content in the server by `DBMS_CRYPTO.HASH`.
+    ///
+    /// Unlike [`Blob::sha256`], the LOB content isn't transferred to the client.
+    /// The user needs the `EXECUTE` privilege on `DBMS_CRYPTO`.
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # use oracle::sql_type::Blob;
+    /// # use std::io::Write;
+    /// # let conn = test_util::connect()?;
+    /// let mut blob = Blob::new(&conn)?;
+    /// blob.write_all(b"abc")?;
+    /// let digest = blob.sha256_in_db(&conn)?;
+    /// assert_eq!(digest, blob.sha256()?);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn sha256_in_db(&self, conn: &Connection) -> Result<[u8; 32]> {
+        sha256_in_db(conn, self)
+    }
+
+    /// Reads bytes from the current position to the end unless the size exceeds `max` bytes.
+    ///
+    /// This fails with an error whose kind is [`ErrorKind::OutOfRange`](crate::ErrorKind::OutOfRange)
+    /// before reading data when the rest of the LOB is larger than `max`.
+    /// Use this instead of [`Read::read_to_end`] to avoid loading unexpectedly
+    /// large LOBs into memory.
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::ErrorKind;
+    /// # use oracle::test_util;
+    /// # use oracle::sql_type::Blob;
+    /// # use std::io::{Seek, Write};
+    /// # let conn = test_util::connect()?;
+    /// let mut blob = Blob::new(&conn)?;
+    /// blob.write_all(b"0123456789")?;
+    /// blob.seek(std::io::SeekFrom::Start(0))?;
+    /// let err = blob.read_to_vec_capped(9).unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::OutOfRange);
+    /// assert_eq!(blob.read_to_vec_capped(10)?, b"0123456789");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn read_to_vec_capped(&mut self, max: usize) -> Result<Vec<u8>> {
+        self.lob.read_binary_capped(max)
+    }
 }
 
 /// A reference to Oracle data type `CLOB`
@@ -574,6 +739,48 @@ impl Clob {
     pub fn close(&mut self) -> Result<()> {
         self.lob.close()
     }
+
+    /// Computes the SHA-256 digest of the content in the server by `DBMS_CRYPTO.HASH`.
+    ///
+    /// Unlike [`Clob::sha256`], the LOB content isn't transferred to the client.
+    /// The user needs the `EXECUTE` privilege on `DBMS_CRYPTO`. The digest is
+    /// computed from the content encoded in the database character set. So it
+    /// differs from [`Clob::sha256`] unless the character set is `AL32UTF8`.
+    pub fn sha256_in_db(&self, conn: &Connection) -> Result<[u8; 32]> {
+        sha256_in_db(conn, self)
+    }
+
+    /// Reads characters from the current position to the end unless the size
+    /// exceeds `max` bytes in UTF-8.
+    ///
+    /// This fails with an error whose kind is [`ErrorKind::OutOfRange`](crate::ErrorKind::OutOfRange)
+    /// before reading data when the number of characters in the rest of the LOB
+    /// exceeds `max`. Otherwise, it may fail after reading data because
+    /// a character may need more than one byte.
+    /// Use this instead of [`Read::read_to_string`] to avoid loading unexpectedly
+    /// large LOBs into memory.
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::ErrorKind;
+    /// # use oracle::test_util;
+    /// # use oracle::io::SeekInChars;
+    /// # use oracle::sql_type::Clob;
+    /// # use std::io::{SeekFrom, Write};
+    /// # let conn = test_util::connect()?;
+    /// let mut clob = Clob::new(&conn)?;
+    /// clob.write_all("caf\u{e9}".as_bytes())?;
+    /// clob.seek_in_chars(SeekFrom::Start(0))?;
+    /// // 4 characters but 5 bytes
+    /// let err = clob.read_to_string_capped(4).unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::OutOfRange);
+    /// clob.seek_in_chars(SeekFrom::Start(0))?;
+    /// assert_eq!(clob.read_to_string_capped(5)?, "caf\u{e9}");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn read_to_string_capped(&mut self, max: usize) -> Result<String> {
+        self.lob.read_chars_capped(max)
+    }
 }
 
 /// A reference to Oracle data type `NCLOB`
@@ -620,6 +827,14 @@ impl Nclob {
     pub fn close(&mut self) -> Result<()> {
         self.lob.close()
     }
+
+    /// Reads characters from the current position to the end unless the size
+    /// exceeds `max` bytes in UTF-8.
+    ///
+    /// See [`Clob::read_to_string_capped`].
+    pub fn read_to_string_capped(&mut self, max: usize) -> Result<String> {
+        self.lob.read_chars_capped(max)
+    }
 }
 
 macro_rules! impl_traits {
@@ -737,14 +952,63 @@ macro_rules! impl_traits {
         impl_traits!($($trait),* for $name : $type);
     };
 
+    (TryClone $(,$trait:ident)* for $name:ty : $type:ident) => {
+        impl $name {
+            /// Creates an independent copy of the LOB locator.
+            ///
+            /// [`Clone::clone`] shares the underlying locator. When it was fetched
+            /// from a result set, the locator may be overwritten by subsequent fetches.
+            /// The copy made by this method doesn't depend on the statement buffer,
+            /// so it can be kept after the next row is fetched and read later.
+            /// The current position is also copied.
+            pub fn try_clone(&self) -> Result<Self> {
+                Ok(Self {
+                    lob: self.lob.try_clone()?,
+                })
+            }
+        }
+        impl_traits!($($trait),* for $name : $type);
+    };
+
+    (ContentEq $(,$trait:ident)* for $name:ty : $type:ident) => {
+        impl $name {
+            /// Compares the content with `other` by reading both in chunks.
+            ///
+            /// This returns false immediately when the sizes differ. Otherwise,
+            /// the contents are read chunk by chunk and compared without loading
+            /// whole LOBs into memory. The current positions aren't changed.
+            pub fn content_eq(&self, other: &Self) -> Result<bool> {
+                self.lob.content_eq(&other.lob, impl_traits!(@nls_ratio $type))
+            }
+        }
+        impl_traits!($($trait),* for $name : $type);
+    };
+
+    (Sha256 $(,$trait:ident)* for $name:ty : $type:ident) => {
+        impl $name {
+            /// Computes the SHA-256 digest of the content in the client.
+            ///
+            /// The content is read chunk by chunk and isn't loaded into memory
+            /// entirely. The current position isn't changed. The digest of
+            /// character LOBs is computed from the content encoded in UTF-8.
+            pub fn sha256(&self) -> Result<[u8; 32]> {
+                self.lob.sha256(impl_traits!(@nls_ratio $type))
+            }
+        }
+        impl_traits!($($trait),* for $name : $type);
+    };
+
+    (@nls_ratio binary) => { 1 };
+    (@nls_ratio chars) => { 4 };
+
     (for $name:ty : $type:ident) => {
     };
 }
 
 impl_traits!(FromSql, ToSqlNull, ToSql, Read, Seek, Lob for Bfile : binary);
-impl_traits!(FromSql, ToSqlNull, ToSql, Read, Write, Seek, Lob for Blob : binary);
-impl_traits!(FromSql, ToSqlNull, ToSql, Read, Write, SeekInChars, Lob for Clob : chars);
-impl_traits!(FromSql, ToSqlNull, ToSql, Read, Write, SeekInChars, Lob for Nclob : chars);
+impl_traits!(FromSql, ToSqlNull, ToSql, Read, Write, Seek, Lob, TryClone, ContentEq, Sha256 for Blob : binary);
+impl_traits!(FromSql, ToSqlNull, ToSql, Read, Write, SeekInChars, Lob, TryClone, ContentEq, Sha256 for Clob : chars);
+impl_traits!(FromSql, ToSqlNull, ToSql, Read, Write, SeekInChars, Lob, TryClone, ContentEq, Sha256 for Nclob : chars);
 
 #[cfg(test)]
 mod tests {
@@ -861,6 +1125,68 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn try_clone_blobs_in_rows() -> std::result::Result<(), std::boxed::Box<dyn std::error::Error>>
+    {
+        let conn = test_util::connect()?;
+        for i in 1..=3 {
+            let mut lob = Blob::new(&conn)?;
+            lob.write_all(format!("BLOB DATA {}", i).as_bytes())?;
+            conn.execute("insert into TestBLOBs values (:1, :2)", &[&i, &lob])?;
+        }
+        let sql = "select BLOBCol from TestBLOBs where IntCol <= 3 order by IntCol";
+        let mut stmt = conn
+            .statement(sql)
+            .lob_locator()
+            .fetch_array_size(1)
+            .build()?;
+        let lobs = stmt
+            .query_as::<Blob>(&[])?
+            .map(|lob| lob?.try_clone())
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(lobs.len(), 3);
+        for (i, mut lob) in lobs.into_iter().enumerate() {
+            let mut buf = Vec::new();
+            lob.read_to_end(&mut buf)?;
+            assert_eq!(buf, format!("BLOB DATA {}", i + 1).as_bytes());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn content_eq() -> std::result::Result<(), std::boxed::Box<dyn std::error::Error>> {
+        let conn = test_util::connect()?;
+        let data = "crab 🦀 ".repeat(5000);
+        let mut lob1 = Clob::new(&conn)?;
+        let mut lob2 = Clob::new(&conn)?;
+        lob1.write_all(data.as_bytes())?;
+        lob2.write_all(data.as_bytes())?;
+        assert!(lob1.content_eq(&lob2)?);
+        lob2.seek_in_chars(io::SeekFrom::Start(1))?;
+        lob2.write_all(b"R")?;
+        assert!(!lob1.content_eq(&lob2)?);
+        lob2.truncate(10)?;
+        assert!(!lob1.content_eq(&lob2)?);
+        Ok(())
+    }
+
+    #[test]
+    fn sha256() -> std::result::Result<(), std::boxed::Box<dyn std::error::Error>> {
+        let conn = test_util::connect()?;
+        let data = "crab 🦀 ".repeat(5000);
+        let mut hasher = Sha256::new();
+        hasher.update(data.as_bytes());
+        let expected = hasher.finish();
+        let mut clob = Clob::new(&conn)?;
+        clob.write_all(data.as_bytes())?;
+        assert_eq!(clob.sha256()?, expected);
+        let mut blob = Blob::new(&conn)?;
+        blob.write_all(data.as_bytes())?;
+        assert_eq!(blob.sha256()?, expected);
+        assert_eq!(blob.sha256_in_db(&conn)?, expected);
+        Ok(())
+    }
+
     #[test]
     fn read_write_clob() -> std::result::Result<(), std::boxed::Box<dyn std::error::Error>> {
         let conn = test_util::connect()?;
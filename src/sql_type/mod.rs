@@ -18,9 +18,15 @@
 #[cfg(doc)]
 use crate::sql_type::vector::VecRef;
 use crate::Connection;
+use crate::EmptyStringMode;
+use crate::Error;
 use crate::ErrorKind;
 use crate::Result;
 use crate::SqlValue;
+use std::num::{
+    NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU16, NonZeroU32,
+    NonZeroU64, NonZeroU8, NonZeroUsize,
+};
 
 #[cfg(feature = "chrono")]
 mod chrono;
@@ -29,6 +35,7 @@ mod interval_ds;
 mod interval_ym;
 mod lob;
 mod object;
+mod object_ref;
 mod oracle_type;
 mod ref_cursor;
 mod timestamp;
@@ -41,16 +48,23 @@ pub use self::lob::Blob;
 pub use self::lob::Clob;
 pub use self::lob::Lob;
 pub use self::lob::Nclob;
+pub use self::object::Attributes;
 pub use self::object::Collection;
 pub use self::object::Object;
 pub use self::object::ObjectType;
 pub use self::object::ObjectTypeAttr;
 pub(crate) use self::object::ObjectTypeInternal;
+pub use self::object::ObjectValue;
+pub use self::object_ref::Ref;
 pub use self::oracle_type::InnerValue;
 pub(crate) use self::oracle_type::NativeType;
 pub use self::oracle_type::OracleType;
+pub use self::ref_cursor::OutRefCursor;
 pub use self::ref_cursor::RefCursor;
+pub(crate) use self::timestamp::days_from_civil;
 pub use self::timestamp::Timestamp;
+pub use oracle_procmacro::ObjectValue;
+pub use oracle_procmacro::SqlEnum;
 
 /// Conversion from Oracle values to rust values.
 ///
@@ -60,14 +74,17 @@ pub use self::timestamp::Timestamp;
 /// | Oracle Type | Rust Type |
 /// | --- | --- |
 /// | character data types | String |
+/// |     " | `char` (The Oracle value must consist of one character.) |
 /// |     " | `i8`, `i16`, `i32`, `i64`, `isize`, `u8`, `u16`, `u32`, `u64`, `usize`, `f64`, `f32` by using ``String::parse`` |
 /// |     " | `Vec\<u8>` (The Oracle value must be in hexadecimal.) |
 /// |     " | [`Timestamp`] by `String.parse()` |
 /// |     " | [`IntervalDS`] by `String.parse()` |
 /// |     " | [`IntervalYM`] by `String.parse()` |
-/// | numeric data types | `i8`, `i16`, `i32`, `i64`, `isize`, `u8`, `u16`, `u32`, `u64`, `usize`, `f64`, `f32` |
+/// | numeric data types | `i8`, `i16`, `i32`, `i64`, `i128`, `isize`, `u8`, `u16`, `u32`, `u64`, `u128`, `usize`, `f64`, `f32` |
+/// |     " | `NonZeroI8`, `NonZeroI16`, ..., `NonZeroUsize` (conversion error for zero) |
 /// |     " | `String` |
 /// | `raw` | `Vec\<u8>` |
+/// |     " | `[u8; N]` (conversion error when the length isn't `N`) |
 /// |     " | `String` (The Oracle value is converted to characters in hexadecimal.) |
 /// | timestamp data types | [`Timestamp`] |
 /// |     " | `String` |
@@ -117,7 +134,7 @@ pub trait FromSql {
 /// | Rust Type | Oracle Type |
 /// | --- | --- |
 /// | `str`, `String` | `nvarchar2(0)` |
-/// | `i8`, `i16`, `i32`, `i64`, `u8`, `u16`, `u32`, `u64`, `f32`, `f64` | `number` |
+/// | `i8`, `i16`, `i32`, `i64`, `i128`, `u8`, `u16`, `u32`, `u64`, `u128`, `f32`, `f64` | `number` |
 /// | `Vec\<u8>` | `raw(0)` |
 /// | `bool` | `boolean` (PL/SQL only) |
 /// | [`Timestamp`] | `timestamp(9) with time zone` |
@@ -152,7 +169,7 @@ pub trait ToSqlNull {
 /// | Rust Type | Oracle Type | Oracle Value |
 /// | --- | --- | --- |
 /// | `str`, `String` | `nvarchar2(length of the rust value)` | The specified value |
-/// | `i8`, `i16`, `i32`, `i64`, `isize`, `u8`, `u16`, `u32`, `u64`, `usize`, `f32`, `f64` | `number` | The specified value |
+/// | `i8`, `i16`, `i32`, `i64`, `i128`, `isize`, `u8`, `u16`, `u32`, `u64`, `u128`, `usize`, `f32`, `f64` | `number` | The specified value (`i128` and `u128` must fit in 38 digits) |
 /// | `Vec\<u8>` | `raw(length of the rust value)` | The specified value |
 /// | `bool` | `boolean` (PL/SQL only) | The specified value |
 /// | [`Timestamp`] | `timestamp(9) with time zone` | The specified value |
@@ -168,6 +185,10 @@ pub trait ToSqlNull {
 /// When you need to bind output parameters such as varchar2, use `OracleType`
 /// or `(&ToSql, &OracleType)` to specify the maximum length of data types.
 ///
+/// An empty `str` or `String` is bound as it is by default and Oracle treats it
+/// as `NULL`. Use [`Connection::set_empty_string_mode`] to bind it as a single
+/// blank or to reject it.
+///
 /// When `chrono` feature is enabled, the following conversions are added.
 ///
 /// | Rust Type | Oracle Type |
@@ -238,6 +259,8 @@ impl_from_and_to_sql!(u16, to_u16, set_u16, OracleType::Number(0, 0));
 impl_from_and_to_sql!(u32, to_u32, set_u32, OracleType::Number(0, 0));
 impl_from_and_to_sql!(u64, to_u64, set_u64, OracleType::Number(0, 0));
 impl_from_and_to_sql!(usize, to_usize, set_usize, OracleType::Number(0, 0));
+impl_from_and_to_sql!(i128, to_i128, set_i128, OracleType::Number(0, 0));
+impl_from_and_to_sql!(u128, to_u128, set_u128, OracleType::Number(0, 0));
 impl_from_and_to_sql!(f64, to_f64, set_f64, OracleType::Number(0, 0));
 impl_from_and_to_sql!(f32, to_f32, set_f32, OracleType::Number(0, 0));
 impl_from_and_to_sql!(bool, to_bool, set_bool, OracleType::Boolean);
@@ -246,6 +269,50 @@ impl_from_sql!(Vec<u8>, to_bytes);
 impl_from_sql!(Vec<f32>, to_f32_vec);
 impl_from_sql!(Vec<f64>, to_f64_vec);
 impl_from_sql!(Vec<i8>, to_i8_vec);
+
+impl FromSql for char {
+    fn from_sql(val: &SqlValue) -> Result<char> {
+        let s = val.to_string()?;
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(Error::out_of_range(format!(
+                "char requires exactly one character but got {:?}",
+                s
+            ))),
+        }
+    }
+}
+
+macro_rules! impl_from_sql_for_nonzero {
+    ($type:ident, $func:ident) => {
+        impl FromSql for $type {
+            fn from_sql(val: &SqlValue) -> Result<$type> {
+                $type::new(val.$func()?)
+                    .ok_or_else(|| Error::out_of_range(concat!(stringify!($type), " got zero")))
+            }
+        }
+    };
+}
+
+impl_from_sql_for_nonzero!(NonZeroI8, to_i8);
+impl_from_sql_for_nonzero!(NonZeroI16, to_i16);
+impl_from_sql_for_nonzero!(NonZeroI32, to_i32);
+impl_from_sql_for_nonzero!(NonZeroI64, to_i64);
+impl_from_sql_for_nonzero!(NonZeroIsize, to_isize);
+impl_from_sql_for_nonzero!(NonZeroU8, to_u8);
+impl_from_sql_for_nonzero!(NonZeroU16, to_u16);
+impl_from_sql_for_nonzero!(NonZeroU32, to_u32);
+impl_from_sql_for_nonzero!(NonZeroU64, to_u64);
+impl_from_sql_for_nonzero!(NonZeroUsize, to_usize);
+
+impl<const N: usize> FromSql for [u8; N] {
+    fn from_sql(val: &SqlValue) -> Result<[u8; N]> {
+        val.to_bytes()?
+            .try_into()
+            .map_err(|v: Vec<u8>| Error::out_of_range(format!("[u8; {}] got {} bytes", N, v.len())))
+    }
+}
 impl_from_and_to_sql!(
     Timestamp,
     to_timestamp,
@@ -268,6 +335,21 @@ impl_from_and_to_sql!(
     OracleType::IntervalYM(9)
 );
 
+fn str_oratype(s: &str, mode: EmptyStringMode) -> Result<OracleType> {
+    if s.is_empty() {
+        match mode {
+            EmptyStringMode::Null => (),
+            EmptyStringMode::Blank => return Ok(OracleType::NChar(1)),
+            EmptyStringMode::Error => {
+                return Err(Error::invalid_argument(
+                    "empty string is not allowed by EmptyStringMode::Error",
+                ))
+            }
+        }
+    }
+    Ok(OracleType::NVarchar2(s.len() as u32))
+}
+
 impl ToSqlNull for String {
     fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
         Ok(OracleType::NVarchar2(0))
@@ -275,11 +357,11 @@ impl ToSqlNull for String {
 }
 
 impl ToSql for String {
-    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
-        Ok(OracleType::NVarchar2(self.len() as u32))
+    fn oratype(&self, conn: &Connection) -> Result<OracleType> {
+        str_oratype(self, conn.empty_string_mode())
     }
     fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
-        val.set_string(self)
+        val.set_str_with_empty_string_mode(self)
     }
 }
 
@@ -305,11 +387,11 @@ impl ToSqlNull for &str {
 }
 
 impl ToSql for &str {
-    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
-        Ok(OracleType::NVarchar2(self.len() as u32))
+    fn oratype(&self, conn: &Connection) -> Result<OracleType> {
+        str_oratype(self, conn.empty_string_mode())
     }
     fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
-        val.set_string(self)
+        val.set_str_with_empty_string_mode(self)
     }
 }
 
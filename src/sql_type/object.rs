@@ -19,6 +19,7 @@ use crate::sql_type::collection::{Indices, Iter, Values};
 use crate::sql_type::FromSql;
 use crate::sql_type::OracleType;
 use crate::sql_type::ToSql;
+use crate::sql_value::BufferRowIndex;
 use crate::to_rust_str;
 use crate::util::write_literal;
 use crate::AssertSend;
@@ -28,12 +29,14 @@ use crate::DpiObject;
 use crate::DpiObjectAttr;
 use crate::DpiObjectType;
 use crate::Error;
+use crate::QueryParams;
 use crate::Result;
 use crate::SqlValue;
 use odpic_sys::dpi_impl::DPI_NUMBER_AS_TEXT_CHARS;
 use odpic_sys::*;
 use std::cmp;
 use std::fmt;
+use std::iter::FusedIterator;
 use std::mem::{self, MaybeUninit};
 use std::os::raw::c_char;
 use std::sync::Arc;
@@ -55,6 +58,12 @@ unsafe fn release_dpi_data(data: &dpiData, native_type_num: u32) {
     }
 }
 
+fn copy_object(conn: &Conn, handle: &DpiObject) -> Result<DpiObject> {
+    let mut copied = DpiObject::null();
+    chkerr!(conn.ctxt(), dpiObject_copy(handle.raw, &mut copied.raw));
+    Ok(copied)
+}
+
 /// Oracle-specific collection data type
 ///
 /// This type corresponds to varray and nested table data types.
@@ -76,6 +85,9 @@ unsafe fn release_dpi_data(data: &dpiData, native_type_num: u32) {
 /// ```
 ///
 /// Note: Methods in the type may be changed in future.
+///
+/// `Collection` is [`Send`] but not [`Sync`] as [`Object`] is.
+/// See [the thread safety section of `Object`](Object#thread-safety).
 pub struct Collection {
     conn: Conn,
     pub(crate) handle: DpiObject,
@@ -104,6 +116,19 @@ impl Collection {
         &self.objtype
     }
 
+    /// Creates an independent copy of the collection.
+    ///
+    /// [`Clone::clone`] shares the underlying collection with the original one.
+    /// The copy made by this method doesn't share it. Use this to move a
+    /// collection to another thread while the original one is still used.
+    pub fn try_clone(&self) -> Result<Collection> {
+        Ok(Collection::new(
+            self.conn.clone(),
+            copy_object(&self.conn, &self.handle)?,
+            self.objtype.clone(),
+        ))
+    }
+
     /// Returns the number of elements.
     ///
     /// This counts also deleted elements. See "Comments" about [OCICollSize()][].
@@ -366,6 +391,30 @@ impl Collection {
         res
     }
 
+    /// Returns the value of the element at the specified index as [`SqlValue`].
+    pub fn get_sql_value(&self, index: i32) -> Result<SqlValue<'static>> {
+        let oratype = self.objtype.element_oracle_type().unwrap();
+        let mut val = SqlValue::for_bind(self.conn.clone(), QueryParams::new(), 1);
+        val.init_handle(oratype)?;
+        let mut data = unsafe { mem::zeroed() };
+        let mut buf = [0 as c_char; DPI_NUMBER_AS_TEXT_CHARS as usize];
+        if val.native_type_num() == DPI_NATIVE_TYPE_BYTES {
+            unsafe { dpiData_setBytes(&mut data, buf.as_mut_ptr(), buf.len() as u32) };
+        }
+        chkerr!(
+            self.ctxt(),
+            dpiObject_getElementValueByIndex(
+                self.handle(),
+                index,
+                val.native_type_num(),
+                &mut data
+            )
+        );
+        let res = val.set_from_dpi_data(&data);
+        unsafe { release_dpi_data(&data, val.native_type_num()) };
+        res.map(|_| val)
+    }
+
     /// Sets the value to the element at the specified index.
     pub fn set(&mut self, index: i32, value: &dyn ToSql) -> Result<()> {
         let oratype = self.objtype.element_oracle_type().unwrap();
@@ -401,6 +450,103 @@ impl Collection {
         Ok(())
     }
 
+    /// Replaces all elements in the collection with `values`.
+    ///
+    /// All values are converted to the element type before the collection
+    /// is modified. When one of them cannot be converted, this returns an
+    /// error and the collection is left unchanged. Note that ODPI-C has no
+    /// function to set elements at once. They are appended one by one after
+    /// the conversion. If Oracle rejects one of them, for example because
+    /// it exceeds the maximum size of `VARRAY`, the collection keeps only
+    /// elements appended before the error.
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// conn.execute("create or replace type number_varray is varray(20) of number", &[])?;
+    /// let objtype = conn.object_type("NUMBER_VARRAY")?;
+    ///
+    /// let mut coll = objtype.new_collection()?;
+    /// coll.push(&100)?;
+    /// coll.set_values_from_slice(&[1, 2, 3])?;
+    /// assert_eq!(coll.get_values::<i32>()?, vec![1, 2, 3]);
+    ///
+    /// // "x" cannot be converted to a number. The collection isn't changed.
+    /// assert!(coll.set_values_from_slice(&["4", "x"]).is_err());
+    /// assert_eq!(coll.get_values::<i32>()?, vec![1, 2, 3]);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn set_values_from_slice<T>(&mut self, values: &[T]) -> Result<()>
+    where
+        T: ToSql,
+    {
+        let oratype = self.objtype.element_oracle_type().unwrap();
+        let num_values = values.len() as u32;
+        let mut sql_value =
+            SqlValue::for_bind(self.conn.clone(), QueryParams::new(), num_values.max(1));
+        sql_value.init_handle(oratype)?;
+        for (i, value) in values.iter().enumerate() {
+            sql_value.buffer_row_index = BufferRowIndex::Owned(i as u32);
+            sql_value.set(value)?;
+        }
+        let size = self.size()?;
+        if size > 0 {
+            self.trim(size as usize)?;
+        }
+        let native_type_num = sql_value.native_type_num();
+        for i in 0..num_values {
+            sql_value.buffer_row_index = BufferRowIndex::Owned(i);
+            chkerr!(
+                self.ctxt(),
+                dpiObject_appendElement(self.handle(), native_type_num, sql_value.data()?)
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns the values of all elements in the collection.
+    ///
+    /// Deleted elements are skipped. This is faster than [`Collection::values`]
+    /// because the element type is looked up and the buffer to get values
+    /// from ODPI-C is prepared only once.
+    pub fn get_values<T>(&self) -> Result<Vec<T>>
+    where
+        T: FromSql,
+    {
+        let mut index = 0;
+        let mut exists = 0;
+        chkerr!(
+            self.ctxt(),
+            dpiObject_getFirstIndex(self.handle(), &mut index, &mut exists)
+        );
+        let mut values = Vec::with_capacity(self.size()? as usize);
+        let oratype = self.objtype.element_oracle_type().unwrap();
+        let mut data = unsafe { mem::zeroed() };
+        let mut buf = [0 as c_char; DPI_NUMBER_AS_TEXT_CHARS as usize];
+        let sql_value = SqlValue::from_oratype(self.conn.clone(), oratype, &mut data)?;
+        let native_type_num = sql_value.native_type_num();
+        let is_number = matches!(oratype, OracleType::Number(_, _) | OracleType::Float(_));
+        while exists != 0 {
+            let data = sql_value.data()?;
+            if is_number {
+                unsafe { dpiData_setBytes(data, buf.as_mut_ptr(), buf.len() as u32) };
+            }
+            chkerr!(
+                self.ctxt(),
+                dpiObject_getElementValueByIndex(self.handle(), index, native_type_num, data)
+            );
+            let res = sql_value.get();
+            unsafe { release_dpi_data(sql_value.data()?, native_type_num) };
+            values.push(res?);
+            chkerr!(
+                self.ctxt(),
+                dpiObject_getNextIndex(self.handle(), index, &mut index, &mut exists)
+            );
+        }
+        Ok(values)
+    }
+
     /// Remove the element at the specified index.
     /// Note that the position ordinals of the remaining elements are not changed.
     /// The operation creates **holes** in the collection.
@@ -512,6 +658,27 @@ impl AssertSend for Collection {}
 /// ```
 ///
 /// Note: Methods in the type may be changed in future.
+///
+/// # Thread safety
+///
+/// `Object` is [`Send`] but not [`Sync`]. An object fetched in a thread may be
+/// moved to and processed in another thread. Note that [`Clone::clone`] doesn't
+/// copy the object. It shares the underlying object with the original one.
+/// Don't use clones in different threads at the same time. Use
+/// [`Object::try_clone`] to get a copy to be moved to another thread instead.
+///
+/// ```
+/// # use oracle::Error;
+/// # use oracle::sql_type::Object;
+/// # use oracle::test_util;
+/// # let conn = test_util::connect()?;
+/// let objtype = conn.object_type("UDT_OBJECT")?;
+/// let mut obj = objtype.new_object()?;
+/// obj.set("NUMBERVALUE", &10)?;
+/// let handle = std::thread::spawn(move || obj.get::<i32>("NUMBERVALUE"));
+/// assert_eq!(handle.join().unwrap()?, 10);
+/// # Ok::<(), Error>(())
+/// ```
 pub struct Object {
     conn: Conn,
     pub(crate) handle: DpiObject,
@@ -540,6 +707,19 @@ impl Object {
         &self.objtype
     }
 
+    /// Creates an independent copy of the object.
+    ///
+    /// [`Clone::clone`] shares the underlying object with the original one.
+    /// The copy made by this method doesn't share it. Use this to move an
+    /// object to another thread while the original one is still used.
+    pub fn try_clone(&self) -> Result<Object> {
+        Ok(Object::new(
+            self.conn.clone(),
+            copy_object(&self.conn, &self.handle)?,
+            self.objtype.clone(),
+        ))
+    }
+
     fn type_attr(&self, name: &str) -> Result<&ObjectTypeAttr> {
         for attr in self.objtype.attributes() {
             if attr.name() == name {
@@ -589,6 +769,77 @@ impl Object {
         self.get_by_attr(self.type_attr(name)?)
     }
 
+    fn get_sql_value_by_attr(&self, attr: &ObjectTypeAttr) -> Result<SqlValue<'static>> {
+        let mut val = SqlValue::for_bind(self.conn.clone(), QueryParams::new(), 1);
+        val.init_handle(&attr.oratype)?;
+        let mut data = unsafe { mem::zeroed() };
+        let mut buf = [0 as c_char; DPI_NUMBER_AS_TEXT_CHARS as usize];
+        if val.native_type_num() == DPI_NATIVE_TYPE_BYTES {
+            unsafe { dpiData_setBytes(&mut data, buf.as_mut_ptr(), buf.len() as u32) };
+        }
+        chkerr!(
+            self.ctxt(),
+            dpiObject_getAttributeValue(
+                self.handle(),
+                attr.handle.raw(),
+                val.native_type_num(),
+                &mut data
+            )
+        );
+        let res = val.set_from_dpi_data(&data);
+        unsafe { release_dpi_data(&data, val.native_type_num()) };
+        res.map(|_| val)
+    }
+
+    /// Gets an value at the specified attribute as [`SqlValue`].
+    ///
+    /// This is useful when the attribute type isn't known at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use oracle::*;
+    /// let conn = Connection::connect("scott", "tiger", "")?;
+    /// let point_type = conn.object_type("MDSYS.SDO_POINT_TYPE")?;
+    /// let mut point = point_type.new_object()?;
+    /// point.set("X", &-79)?;
+    ///
+    /// let x = point.get_sql_value("X")?;
+    /// assert_eq!(x.to_string(), "-79");
+    /// assert!(point.get_sql_value("Y")?.is_null()?);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn get_sql_value(&self, name: &str) -> Result<SqlValue<'static>> {
+        self.get_sql_value_by_attr(self.type_attr(name)?)
+    }
+
+    /// Returns an iterator over pairs of attribute names and values.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use oracle::*;
+    /// let conn = Connection::connect("scott", "tiger", "")?;
+    /// let point_type = conn.object_type("MDSYS.SDO_POINT_TYPE")?;
+    /// let mut point = point_type.new_object()?;
+    /// point.set("X", &-79)?;
+    /// point.set("Y", &37)?;
+    ///
+    /// let mut attrs = Vec::new();
+    /// for attr in point.attributes() {
+    ///     let (name, value) = attr?;
+    ///     attrs.push(format!("{}={}", name, value));
+    /// }
+    /// assert_eq!(attrs, ["X=-79", "Y=37", "Z=NULL"]);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn attributes(&self) -> Attributes<'_> {
+        Attributes {
+            obj: self,
+            index: 0,
+        }
+    }
+
     /// Sets the value to the specified attribute.
     pub fn set(&mut self, name: &str, value: &dyn ToSql) -> Result<()> {
         let attrtype = self.type_attr(name)?;
@@ -621,6 +872,52 @@ impl FromSql for Object {
     }
 }
 
+/// A trait to convert between an Oracle object and a Rust struct
+///
+/// This is implemented by `#[derive(ObjectValue)]`, which maps
+/// attribute names to field names. See [the derive macro](derive.ObjectValue.html).
+///
+/// # Examples
+///
+/// ```
+/// # use oracle::Error;
+/// # use oracle::test_util;
+/// use oracle::sql_type::ObjectValue;
+///
+/// #[derive(ObjectValue, Debug, PartialEq)]
+/// struct Book {
+///     title: String,
+///     authors: String,
+///     price: f64,
+/// }
+///
+/// # let conn = test_util::connect()?;
+/// let objtype = conn.object_type("UDT_BOOK")?;
+/// let book = Book {
+///     title: "Pebble in the Sky".into(),
+///     authors: "Isaac Asimov".into(),
+///     price: 17.0,
+/// };
+/// let obj = book.to_object(&objtype)?;
+/// assert_eq!(obj.get::<String>("TITLE")?, "Pebble in the Sky");
+/// assert_eq!(Book::from_object(&obj)?, book);
+/// # Ok::<(), Error>(())
+/// ```
+pub trait ObjectValue: Sized {
+    /// Creates a value from attributes of `obj`.
+    fn from_object(obj: &Object) -> Result<Self>;
+
+    /// Sets the value to attributes of `obj`.
+    fn set_to_object(&self, obj: &mut Object) -> Result<()>;
+
+    /// Creates a new object of `objtype` and sets the value to its attributes.
+    fn to_object(&self, objtype: &ObjectType) -> Result<Object> {
+        let mut obj = objtype.new_object()?;
+        self.set_to_object(&mut obj)?;
+        Ok(obj)
+    }
+}
+
 impl ToSql for Object {
     fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
         Ok(OracleType::Object(self.object_type().clone()))
@@ -665,6 +962,38 @@ impl fmt::Debug for Object {
 
 impl AssertSend for Object {}
 
+/// An iterator over the attributes of an Object.
+///
+/// This struct is created by [`Object::attributes()`]. See its documentation for more.
+#[derive(Clone)]
+pub struct Attributes<'a> {
+    obj: &'a Object,
+    index: usize,
+}
+
+impl<'a> Iterator for Attributes<'a> {
+    type Item = Result<(&'a str, SqlValue<'static>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let attr = self.obj.objtype.attributes().get(self.index)?;
+        self.index += 1;
+        Some(
+            self.obj
+                .get_sql_value_by_attr(attr)
+                .map(|val| (attr.name(), val)),
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.obj.objtype.attributes().len() - self.index;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Attributes<'_> {}
+
+impl FusedIterator for Attributes<'_> {}
+
 /// Type information about Object or Collection data type
 ///
 /// This is for not only Object type information but also
@@ -0,0 +1,175 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2025 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use crate::batch::check_identifier;
+use crate::sql_type::FromSql;
+use crate::sql_type::Object;
+use crate::sql_type::OracleType;
+use crate::sql_type::ToSql;
+use crate::sql_type::ToSqlNull;
+use crate::Connection;
+use crate::Error;
+use crate::Result;
+use crate::SqlValue;
+use std::fmt;
+use std::str::FromStr;
+
+/// Reference to a row object in an object table
+///
+/// ODPI-C, which this crate is built on, has no data type for `REF`. A query
+/// selecting a `REF` column fails with `DPI-1008: data type 110 is not supported`
+/// and a `REF` value cannot be bound as a parameter. When the referenced
+/// object itself is needed, dereference it in the SQL statement by `DEREF`
+/// and fetch it as an [`Object`]. That is done by the server and can use
+/// indexes.
+///
+/// ```no_run
+/// # use oracle::Error;
+/// # use oracle::sql_type::Object;
+/// # use oracle::test_util;
+/// # let conn = test_util::connect()?;
+/// let address: Object = conn.query_row_as(
+///     "select deref(address) from customers where id = :1",
+///     &[&101],
+/// )?;
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// Use this type when a reference has to be kept on the client side.
+/// Fetch the hexadecimal representation of a reference by the SQL function
+/// `REFTOHEX`. It is converted to this type and then dereferenced into an
+/// [`Object`] by [`Ref::deref`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use oracle::Error;
+/// # use oracle::sql_type::Ref;
+/// # use oracle::test_util;
+/// # let conn = test_util::connect()?;
+/// // create type address_type as object (street varchar2(40), city varchar2(20));
+/// // create table addresses of address_type;
+/// // create table customers (id number, address ref address_type scope is addresses);
+/// let address_ref: Ref = conn.query_row_as(
+///     "select reftohex(address) from customers where id = :1",
+///     &[&101],
+/// )?;
+/// let address = address_ref.deref(&conn, "addresses")?;
+/// let city: String = address.get("CITY")?;
+/// # Ok::<(), Error>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Ref {
+    hex: String,
+}
+
+impl Ref {
+    /// Creates a reference from its hexadecimal representation returned
+    /// by the SQL function `REFTOHEX`.
+    pub fn from_hex(hex: &str) -> Result<Ref> {
+        if hex.is_empty() || hex.len() % 2 != 0 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(Error::parse_error(format!(
+                "invalid REF hexadecimal representation {:?}",
+                hex
+            )));
+        }
+        Ok(Ref {
+            hex: hex.to_ascii_uppercase(),
+        })
+    }
+
+    /// Returns the hexadecimal representation in upper case
+    pub fn as_hex(&self) -> &str {
+        &self.hex
+    }
+
+    /// Gets the row object referenced by `self`
+    ///
+    /// `table` is the object table storing the referenced row, which
+    /// is the scope table for a scoped `REF` column. It may be qualified
+    /// by a schema name. This fails with an error whose kind is
+    /// [`ErrorKind::NoDataFound`] when the row doesn't exist in the table,
+    /// that is, the reference is dangling or points to another table.
+    ///
+    /// The row is looked up by comparing `REFTOHEX(REF(t))` with `self`
+    /// because Oracle has no SQL function converting the hexadecimal
+    /// representation back to a `REF` and ODPI-C cannot bind a `REF` to
+    /// use `DEREF(:1)`. Oracle cannot use an index for the comparison and
+    /// scans the whole table. Use `DEREF` in the query fetching the
+    /// reference instead for large tables. See the [type documentation](Ref).
+    ///
+    /// [`ErrorKind::NoDataFound`]: crate::ErrorKind::NoDataFound
+    pub fn deref(&self, conn: &Connection, table: &str) -> Result<Object> {
+        check_identifier(table, true)?;
+        let sql = format!(
+            "select value(t) from {} t where reftohex(ref(t)) = :1",
+            table
+        );
+        conn.query_row_as(&sql, &[&self.hex])
+    }
+}
+
+impl FromStr for Ref {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ref::from_hex(s)
+    }
+}
+
+impl fmt::Display for Ref {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.hex)
+    }
+}
+
+impl FromSql for Ref {
+    fn from_sql(val: &SqlValue) -> Result<Ref> {
+        Ref::from_hex(&val.get::<String>()?)
+    }
+}
+
+impl ToSqlNull for Ref {
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Varchar2(0))
+    }
+}
+
+impl ToSql for Ref {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Varchar2(self.hex.len() as u32))
+    }
+
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        val.set(&self.hex)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ErrorKind;
+
+    #[test]
+    fn from_hex() {
+        let r = Ref::from_hex("0000280209abcdef").unwrap();
+        assert_eq!(r.as_hex(), "0000280209ABCDEF");
+        assert_eq!(r.to_string(), "0000280209ABCDEF");
+        assert_eq!("0000280209ABCDEF".parse::<Ref>().unwrap(), r);
+        for s in ["", "0", "00G0", "00 0"] {
+            assert_eq!(Ref::from_hex(s).unwrap_err().kind(), ErrorKind::ParseError);
+        }
+    }
+}
@@ -34,7 +34,8 @@ use crate::Statement;
 use crate::StatementBuilder;
 use odpic_sys::*;
 
-/// Result set output by or returned by a PL/SQL block or a stored procedure
+/// Result set output by or returned by a PL/SQL block or a stored procedure,
+/// or fetched as a nested cursor column
 ///
 /// This struct has four query methods, which are similar to [`Statement`]'s query methods
 /// excluding `params` arguments. The latter methods internally execute statements with
@@ -108,6 +109,31 @@ use odpic_sys::*;
 /// # Ok::<(), Error>(())
 /// ```
 ///
+/// Nested cursor in a select list
+/// ```
+/// # use oracle::Error;
+/// # use oracle::sql_type::RefCursor;
+/// # use oracle::test_util;
+/// # let conn = test_util::connect()?;
+/// let sql = r#"
+/// select IntCol, cursor(select StringCol from TestStrings s where s.IntCol <= n.IntCol order by s.IntCol)
+///   from TestNumbers n
+///  where IntCol <= 3
+///  order by IntCol
+/// "#;
+/// for row_result in conn.query_as::<(i32, RefCursor)>(sql, &[])? {
+///     let (int_col, mut cursor) = row_result?;
+///     let strings = cursor
+///         .query_as::<String>()?
+///         .collect::<Result<Vec<_>, _>>()?;
+///     assert_eq!(strings.len(), int_col as usize);
+///     assert_eq!(strings.last(), Some(&format!("String {}", int_col)));
+/// }
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// A nested cursor is valid after the parent result set fetches next rows.
+///
 pub struct RefCursor {
     stmt: Stmt,
 }
@@ -256,6 +282,44 @@ impl RefCursor {
     }
 }
 
+/// A marker to bind an OUT parameter receiving a ref cursor
+///
+/// Bind this as a placeholder opened by `open :cursor for ...` and then
+/// get a [`RefCursor`] by [`Statement::bind_value`]. This works as
+/// `None::<RefCursor>` but tells what the parameter is for.
+///
+/// # Examples
+///
+/// ```
+/// # use oracle::Error;
+/// # use oracle::sql_type::{OutRefCursor, RefCursor};
+/// # use oracle::test_util;
+/// # let conn = test_util::connect()?;
+/// let mut stmt = conn.execute(
+///     "begin open :1 for select IntCol, StringCol from TestStrings where IntCol <= :2 order by IntCol; end;",
+///     &[&OutRefCursor, &3],
+/// )?;
+/// let mut cursor: RefCursor = stmt.bind_value(1)?;
+/// let rows = cursor
+///     .query_as::<(i32, String)>()?
+///     .collect::<Result<Vec<_>, _>>()?;
+/// assert_eq!(rows.len(), 3);
+/// assert_eq!(rows[2], (3, "String 3".to_string()));
+/// # Ok::<(), Error>(())
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OutRefCursor;
+
+impl ToSql for OutRefCursor {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::RefCursor)
+    }
+
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        val.set_null()
+    }
+}
+
 impl ToSqlNull for RefCursor {
     fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
         Ok(OracleType::RefCursor)
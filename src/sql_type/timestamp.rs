@@ -13,6 +13,8 @@
 // (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
 //-----------------------------------------------------------------------------
 
+use crate::sql_type::IntervalDS;
+use crate::sql_type::IntervalYM;
 use crate::sql_type::OracleType;
 use crate::util::Scanner;
 use crate::Error;
@@ -20,17 +22,19 @@ use crate::ParseOracleTypeError;
 use crate::Result;
 use odpic_sys::dpiTimestamp;
 use std::cmp::{self, Ordering};
+use std::convert::TryFrom;
 use std::fmt;
 use std::result;
 use std::str;
+use std::time::Duration;
 
 /// Oracle-specific [Datetime][] data type
 ///
 /// [Datetime]: https://www.oracle.com/pls/topic/lookup?ctx=dblatest&id=GUID-3A1B7AC6-2EDB-4DDC-9C9D-223D4C72AC74
 ///
-/// This struct doesn't have arithmetic methods and they won't be added to avoid
-/// reinventing the wheel. If you need methods such as adding an interval to a
-/// timestamp, enable `chrono` feature and use [chrono::Date][], [chrono::DateTime][],
+/// This struct has only basic arithmetic methods such as [`Timestamp::checked_add_interval_ds`],
+/// [`Timestamp::interval_since`] and [`Timestamp::to_tz_offset`].
+/// If you need more, enable `chrono` feature and use [chrono::Date][], [chrono::DateTime][],
 /// [chrono::naive::NaiveDate][] or [chrono::naive::NaiveDateTime][] instead.
 ///
 /// [chrono::Date]: https://docs.rs/chrono/0.4/chrono/struct.Date.html
@@ -332,6 +336,222 @@ impl Timestamp {
     pub fn tz_offset(&self) -> i32 {
         self.tz_hour_offset * 3600 + self.tz_minute_offset * 60
     }
+
+    /// Adds an interval to the timestamp.
+    ///
+    /// The interval is added to the date and time fields as they are.
+    /// The time zone offset and the precision are unchanged.
+    /// `None` is returned when the year of the result is out of range.
+    ///
+    /// Dates are calculated in the proleptic Gregorian calendar unlike Oracle,
+    /// which uses the Julian calendar for dates before October 15, 1582.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error; use oracle::sql_type::*;
+    /// let ts = Timestamp::new(2024, 2, 28, 23, 0, 0, 0)?;
+    /// let intvl = IntervalDS::new(1, 2, 0, 0, 0)?;
+    /// assert_eq!(
+    ///     ts.checked_add_interval_ds(&intvl),
+    ///     Some(Timestamp::new(2024, 3, 1, 1, 0, 0, 0)?)
+    /// );
+    /// assert_eq!(
+    ///     ts.checked_add_interval_ds(&-intvl),
+    ///     Some(Timestamp::new(2024, 2, 27, 21, 0, 0, 0)?)
+    /// );
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn checked_add_interval_ds(&self, intvl: &IntervalDS) -> Option<Timestamp> {
+        self.with_local_nanoseconds(self.local_nanoseconds() + intvl.total_nanoseconds())
+    }
+
+    /// Subtracts an interval from the timestamp.
+    ///
+    /// See [`Timestamp::checked_add_interval_ds`].
+    pub fn checked_sub_interval_ds(&self, intvl: &IntervalDS) -> Option<Timestamp> {
+        self.with_local_nanoseconds(self.local_nanoseconds() - intvl.total_nanoseconds())
+    }
+
+    /// Adds an interval year to month to the timestamp.
+    ///
+    /// The day and time fields are unchanged. `None` is returned when the
+    /// result is out of range or the day doesn't exist in the resulting
+    /// month, as Oracle raises `ORA-01839: date not valid for month specified`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error; use oracle::sql_type::*;
+    /// let ts = Timestamp::new(2024, 1, 31, 12, 0, 0, 0)?;
+    /// assert_eq!(
+    ///     ts.checked_add_interval_ym(&IntervalYM::new(1, 2)?),
+    ///     Some(Timestamp::new(2025, 3, 31, 12, 0, 0, 0)?)
+    /// );
+    /// // 2024-02-31 doesn't exist.
+    /// assert_eq!(ts.checked_add_interval_ym(&IntervalYM::new(0, 1)?), None);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn checked_add_interval_ym(&self, intvl: &IntervalYM) -> Option<Timestamp> {
+        self.add_months(i64::from(intvl.years()) * 12 + i64::from(intvl.months()))
+    }
+
+    /// Subtracts an interval year to month from the timestamp.
+    ///
+    /// See [`Timestamp::checked_add_interval_ym`].
+    pub fn checked_sub_interval_ym(&self, intvl: &IntervalYM) -> Option<Timestamp> {
+        self.add_months(-(i64::from(intvl.years()) * 12 + i64::from(intvl.months())))
+    }
+
+    /// Adds [`Duration`] to the timestamp.
+    ///
+    /// See [`Timestamp::checked_add_interval_ds`].
+    ///
+    /// [`Duration`]: std::time::Duration
+    pub fn checked_add_duration(&self, dur: Duration) -> Option<Timestamp> {
+        self.with_local_nanoseconds(self.local_nanoseconds() + dur.as_nanos() as i128)
+    }
+
+    /// Subtracts [`Duration`] from the timestamp.
+    ///
+    /// See [`Timestamp::checked_add_interval_ds`].
+    ///
+    /// [`Duration`]: std::time::Duration
+    pub fn checked_sub_duration(&self, dur: Duration) -> Option<Timestamp> {
+        self.with_local_nanoseconds(self.local_nanoseconds() - dur.as_nanos() as i128)
+    }
+
+    /// Returns the interval from `other` to `self`, which is negative when `self` is earlier.
+    ///
+    /// Time zone offsets are taken into account. This is same with `self - other` in SQL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error; use oracle::sql_type::*;
+    /// let ts1 = Timestamp::new(2024, 3, 1, 9, 0, 0, 0)?.and_tz_hm_offset(9, 0)?;
+    /// let ts2 = Timestamp::new(2024, 2, 28, 12, 30, 0, 0)?.and_tz_hm_offset(0, 0)?;
+    /// assert_eq!(ts1.interval_since(&ts2), IntervalDS::new(1, 11, 30, 0, 0)?);
+    /// assert_eq!(ts2.interval_since(&ts1), IntervalDS::new(-1, -11, -30, 0, 0)?);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn interval_since(&self, other: &Timestamp) -> IntervalDS {
+        let nanos = self.utc_nanoseconds() - other.utc_nanoseconds();
+        // This never fails because the difference of years is at most 14712.
+        IntervalDS::from_total_nanoseconds(nanos).unwrap()
+    }
+
+    /// Converts the timestamp to the same point in time at another time zone offset.
+    ///
+    /// `offset` is time zone offset seconds from UTC. Use [`Timestamp::and_tz_offset`]
+    /// to change the time zone offset without changing the date and time fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error; use oracle::sql_type::*;
+    /// let ts = Timestamp::new(2024, 1, 1, 1, 0, 0, 0)?.and_tz_hm_offset(9, 0)?;
+    /// assert_eq!(
+    ///     ts.to_tz_offset(-5 * 3600)?.to_string(),
+    ///     "2023-12-31 11:00:00.000000000 -05:00"
+    /// );
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn to_tz_offset(&self, offset: i32) -> Result<Timestamp> {
+        let ts = self.and_tz_offset(offset)?;
+        let nanos = self.utc_nanoseconds() + i128::from(offset) * 1_000_000_000;
+        ts.with_local_nanoseconds(nanos).ok_or_else(|| {
+            Error::out_of_range(format!(
+                "{} at time zone offset {} is out of range",
+                self, offset
+            ))
+        })
+    }
+
+    fn utc_nanoseconds(&self) -> i128 {
+        self.local_nanoseconds() - i128::from(self.tz_offset()) * 1_000_000_000
+    }
+
+    fn add_months(&self, months: i64) -> Option<Timestamp> {
+        let months = i64::from(self.year) * 12 + i64::from(self.month) - 1 + months;
+        let year = months.div_euclid(12);
+        let month = months.rem_euclid(12) as u32 + 1;
+        if !(-4713..=9999).contains(&year) {
+            return None;
+        }
+        let (next_year, next_month) = if month == 12 {
+            (year + 1, 1)
+        } else {
+            (year, month + 1)
+        };
+        let days_in_month = days_from_civil(next_year as i32, next_month, 1)
+            - days_from_civil(year as i32, month, 1);
+        if i64::from(self.day) > days_in_month {
+            return None;
+        }
+        Some(Timestamp {
+            year: year as i32,
+            month,
+            ..*self
+        })
+    }
+
+    // Returns nanoseconds since 1970-01-01 00:00:00 ignoring the time zone.
+    fn local_nanoseconds(&self) -> i128 {
+        let days = days_from_civil(self.year, self.month, self.day);
+        let secs = days * 86400
+            + i64::from(self.hour) * 3600
+            + i64::from(self.minute) * 60
+            + i64::from(self.second);
+        i128::from(secs) * 1_000_000_000 + i128::from(self.nanosecond)
+    }
+
+    // The reverse of `local_nanoseconds()`. Fields other than date and time are copied from `self`.
+    fn with_local_nanoseconds(&self, nanos: i128) -> Option<Timestamp> {
+        let secs = nanos.div_euclid(1_000_000_000);
+        let days = secs.div_euclid(86400);
+        let secs_of_day = secs.rem_euclid(86400) as u32;
+        let (year, month, day) = civil_from_days(i64::try_from(days).ok()?);
+        if !(-4713..=9999).contains(&year) {
+            return None;
+        }
+        Some(Timestamp {
+            year: year as i32,
+            month,
+            day,
+            hour: secs_of_day / 3600,
+            minute: secs_of_day / 60 % 60,
+            second: secs_of_day % 60,
+            nanosecond: nanos.rem_euclid(1_000_000_000) as u32,
+            ..*self
+        })
+    }
+}
+
+// Returns days since 1970-01-01 in the proleptic Gregorian calendar.
+// This is the days_from_civil algorithm by Howard Hinnant.
+pub(crate) fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    let y = i64::from(year) - i64::from(month <= 2);
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400;
+    let m = i64::from(month);
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+// The reverse of `days_from_civil()`
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = if z >= 0 { z } else { z - 146096 } / 146097;
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
 }
 
 impl cmp::PartialEq for Timestamp {
@@ -525,6 +745,83 @@ impl str::FromStr for Timestamp {
 mod tests {
     use super::*;
 
+    #[test]
+    fn civil_days() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 3, 1), 11017);
+        assert_eq!(days_from_civil(-4712, 1, 1), -2440588 + 38);
+        for days in [-2440550, -719468, -1, 0, 1, 11016, 11017, 2932896] {
+            let (y, m, d) = civil_from_days(days);
+            assert_eq!(days_from_civil(y as i32, m, d), days);
+        }
+    }
+
+    #[test]
+    fn add_interval_ds() -> Result<()> {
+        let ts = Timestamp::new(1999, 12, 31, 23, 59, 59, 500_000_000)?.and_tz_hm_offset(9, 0)?;
+        let it = IntervalDS::new(0, 0, 0, 0, 500_000_000)?;
+        let ts2 = ts.checked_add_interval_ds(&it).unwrap();
+        assert_eq!(
+            ts2,
+            Timestamp::new(2000, 1, 1, 0, 0, 0, 0)?.and_tz_hm_offset(9, 0)?
+        );
+        assert_eq!(ts2.checked_add_interval_ds(&-it), Some(ts));
+        let it = IntervalDS::new(-1, 0, 0, 0, 0)?;
+        assert_eq!(
+            Timestamp::new(2024, 3, 1, 0, 0, 0, 0)?.checked_add_interval_ds(&it),
+            Some(Timestamp::new(2024, 2, 29, 0, 0, 0, 0)?)
+        );
+        let it = IntervalDS::new(1, 0, 0, 0, 0)?;
+        assert_eq!(
+            Timestamp::new(9999, 12, 31, 0, 0, 0, 0)?.checked_add_interval_ds(&it),
+            None
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn add_interval_ym_and_duration() -> Result<()> {
+        let ts = Timestamp::new(2024, 2, 29, 1, 2, 3, 4)?;
+        assert_eq!(
+            ts.checked_add_interval_ym(&IntervalYM::new(4, 0)?),
+            Some(Timestamp::new(2028, 2, 29, 1, 2, 3, 4)?)
+        );
+        assert_eq!(ts.checked_add_interval_ym(&IntervalYM::new(1, 0)?), None);
+        assert_eq!(
+            ts.checked_sub_interval_ym(&IntervalYM::new(1, 2)?),
+            Some(Timestamp::new(2022, 12, 29, 1, 2, 3, 4)?)
+        );
+        assert_eq!(
+            ts.checked_add_duration(Duration::from_secs(86400)),
+            Some(Timestamp::new(2024, 3, 1, 1, 2, 3, 4)?)
+        );
+        assert_eq!(
+            ts.checked_sub_duration(Duration::new(3723, 5)),
+            Some(Timestamp::new(2024, 2, 28, 23, 59, 59, 999_999_999)?)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn interval_since_and_tz() -> Result<()> {
+        let ts1 = Timestamp::new(2024, 1, 1, 0, 0, 0, 0)?.and_tz_hm_offset(-3, -30)?;
+        let ts2 = ts1.to_tz_offset(5 * 3600 + 45 * 60)?;
+        assert_eq!(
+            ts2,
+            Timestamp::new(2024, 1, 1, 9, 15, 0, 0)?.and_tz_hm_offset(5, 45)?
+        );
+        assert_eq!(ts2.interval_since(&ts1), IntervalDS::new(0, 0, 0, 0, 0)?);
+        let ts3 = Timestamp::new(2024, 1, 1, 0, 0, 0, 1)?;
+        assert_eq!(
+            ts3.interval_since(&ts1),
+            IntervalDS::new(0, -3, -29, -59, -999_999_999)?
+        );
+        assert!(Timestamp::new(9999, 12, 31, 23, 0, 0, 0)?
+            .to_tz_offset(3600 * 2)
+            .is_err());
+        Ok(())
+    }
+
     #[test]
     fn to_string() -> Result<()> {
         let mut ts = Timestamp::new(2012, 3, 4, 5, 6, 7, 890123456)?.and_tz_hm_offset(8, 45)?;
@@ -15,6 +15,7 @@
 
 use crate::chkerr;
 use crate::connection::Conn;
+use crate::connection::EmptyStringMode;
 use crate::sql_type::vector::VecFmt;
 use crate::sql_type::vector::VecRef;
 use crate::sql_type::vector::Vector;
@@ -54,7 +55,7 @@ use odpic_sys::*;
 use std::borrow::Cow;
 use std::convert::TryInto;
 use std::fmt;
-use std::mem::MaybeUninit;
+use std::mem::{self, MaybeUninit};
 use std::os::raw::c_char;
 use std::ptr;
 use std::rc::Rc;
@@ -64,9 +65,12 @@ use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Arc;
 
 macro_rules! flt_to_int {
-    ($expr:expr, $src_type:ident, $dest_type:ident) => {{
+    ($self:ident, $expr:expr, $src_type:ident, $dest_type:ident) => {{
         let src_val = $expr;
-        if $dest_type::MIN as $src_type <= src_val && src_val <= $dest_type::MAX as $src_type {
+        if $self.is_strict() && src_val.fract() != 0.0 {
+            Err(lossy_conversion(src_val, stringify!($dest_type)))
+        } else if $dest_type::MIN as $src_type <= src_val && src_val <= $dest_type::MAX as $src_type
+        {
             Ok(src_val as $dest_type)
         } else {
             Err(Error::out_of_range(format!(
@@ -88,9 +92,9 @@ macro_rules! define_fn_to_int {
                 NativeType::UInt64 =>
                     Ok(self.get_u64_unchecked()?.try_into()?),
                 NativeType::Float =>
-                    flt_to_int!(self.get_f32_unchecked()?, f32, $type),
+                    flt_to_int!(self, self.get_f32_unchecked()?, f32, $type),
                 NativeType::Double =>
-                    flt_to_int!(self.get_f64_unchecked()?, f64, $type),
+                    flt_to_int!(self, self.get_f64_unchecked()?, f64, $type),
                 NativeType::Char => Ok(self.get_cow_str_unchecked()?.parse()?),
                 NativeType::Number => Ok(self.get_str_unchecked()?.parse()?),
                 NativeType::Clob => Ok(self.get_clob_as_string_unchecked()?.parse()?),
@@ -106,12 +110,20 @@ macro_rules! define_fn_set_int {
         $(#[$attr])*
         pub(crate) fn $func_name(&mut self, val: &$type) -> Result<()> {
             match self.native_type {
+                NativeType::Int64 if self.is_strict() =>
+                    self.set_i64_unchecked(val.exact_i64().ok_or_else(|| lossy_conversion(val, "i64"))?),
                 NativeType::Int64 =>
                     self.set_i64_unchecked(*val as i64),
+                NativeType::UInt64 if self.is_strict() =>
+                    self.set_u64_unchecked(val.exact_u64().ok_or_else(|| lossy_conversion(val, "u64"))?),
                 NativeType::UInt64 =>
                     self.set_u64_unchecked(*val as u64),
+                NativeType::Float if self.is_strict() =>
+                    self.set_f32_unchecked(val.exact_f32().ok_or_else(|| lossy_conversion(val, "f32"))?),
                 NativeType::Float =>
                     self.set_f32_unchecked(*val as f32),
+                NativeType::Double if self.is_strict() =>
+                    self.set_f64_unchecked(val.exact_f64().ok_or_else(|| lossy_conversion(val, "f64"))?),
                 NativeType::Double =>
                     self.set_f64_unchecked(*val as f64),
                 NativeType::Char |
@@ -126,6 +138,170 @@ macro_rules! define_fn_set_int {
     }
 }
 
+macro_rules! define_fn_to_int128 {
+    ($(#[$attr:meta])* : $func_name:ident, $type:ident) => {
+        $(#[$attr])*
+        pub(crate) fn $func_name(&self) -> Result<$type> {
+            match self.native_type {
+                NativeType::Int64 =>
+                    self.get_i64_unchecked()?.try_into().map_err(Error::parse_error),
+                NativeType::UInt64 =>
+                    self.get_u64_unchecked()?.try_into().map_err(Error::parse_error),
+                NativeType::Float =>
+                    flt_to_int!(self, self.get_f32_unchecked()?, f32, $type),
+                NativeType::Double =>
+                    flt_to_int!(self, self.get_f64_unchecked()?, f64, $type),
+                NativeType::Char => Ok(self.get_cow_str_unchecked()?.parse()?),
+                NativeType::Number => Ok(self.get_str_unchecked()?.parse()?),
+                NativeType::Clob => Ok(self.get_clob_as_string_unchecked()?.parse()?),
+                _ =>
+                    self.invalid_conversion_to_rust_type(stringify!($type))
+            }
+        }
+    }
+}
+
+// Oracle NUMBER keeps up to 38 significant digits. Larger integers
+// are rounded silently by Oracle, so they are rejected here.
+const MAX_NUMBER_DIGITS: usize = 38;
+
+macro_rules! define_fn_set_int128 {
+    ($(#[$attr:meta])* : $func_name:ident, $type:ident) => {
+        $(#[$attr])*
+        pub(crate) fn $func_name(&mut self, val: &$type) -> Result<()> {
+            match self.native_type {
+                NativeType::Int64 =>
+                    self.set_i64_unchecked((*val).try_into()?),
+                NativeType::UInt64 =>
+                    self.set_u64_unchecked((*val).try_into()?),
+                NativeType::Float if self.is_strict() =>
+                    self.set_f32_unchecked(val.exact_f32().ok_or_else(|| lossy_conversion(val, "f32"))?),
+                NativeType::Float =>
+                    self.set_f32_unchecked(*val as f32),
+                NativeType::Double if self.is_strict() =>
+                    self.set_f64_unchecked(val.exact_f64().ok_or_else(|| lossy_conversion(val, "f64"))?),
+                NativeType::Double =>
+                    self.set_f64_unchecked(*val as f64),
+                NativeType::Char => {
+                    let s = val.to_string();
+                    self.set_string_unchecked(&s)
+                },
+                NativeType::Number => {
+                    let s = val.to_string();
+                    if s.trim_start_matches('-').len() > MAX_NUMBER_DIGITS {
+                        return Err(Error::out_of_range(format!(
+                            "{} exceeds the {}-digit precision of Oracle NUMBER",
+                            s, MAX_NUMBER_DIGITS
+                        )));
+                    }
+                    self.set_string_unchecked(&s)
+                },
+                _ =>
+                    self.invalid_conversion_from_rust_type(stringify!($type))
+            }
+        }
+    }
+}
+
+// Conversions between numeric types which fail when the value changes.
+// They are used when strict conversions are enabled by
+// `Connection::set_strict_conversions()`.
+trait ExactNum: Copy + fmt::Display {
+    fn exact_i64(self) -> Option<i64>;
+    fn exact_u64(self) -> Option<u64>;
+    fn exact_f32(self) -> Option<f32>;
+    fn exact_f64(self) -> Option<f64>;
+}
+
+macro_rules! impl_exact_num_for_int {
+    ($($type:ty),*) => {
+        $(
+            impl ExactNum for $type {
+                fn exact_i64(self) -> Option<i64> {
+                    self.try_into().ok()
+                }
+                fn exact_u64(self) -> Option<u64> {
+                    self.try_into().ok()
+                }
+                // Values are compared as i128 because `f as $type` saturates
+                // such as i64::MAX for 2^63. Values over 1e38, which exceed
+                // the precision of Oracle NUMBER, are rejected to avoid
+                // saturation of `f as i128`.
+                fn exact_f32(self) -> Option<f32> {
+                    let f = self as f32;
+                    (f.abs() < 1e38 && f as i128 == self as i128).then(|| f)
+                }
+                fn exact_f64(self) -> Option<f64> {
+                    let f = self as f64;
+                    (f.abs() < 1e38 && f as i128 == self as i128).then(|| f)
+                }
+            }
+        )*
+    };
+}
+
+impl_exact_num_for_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_exact_num_for_float {
+    ($($type:ty),*) => {
+        $(
+            impl ExactNum for $type {
+                fn exact_i64(self) -> Option<i64> {
+                    // 9223372036854775808.0 is 2^63.
+                    let range = -9223372036854775808.0..9223372036854775808.0;
+                    (self.fract() == 0.0 && range.contains(&self)).then(|| self as i64)
+                }
+                fn exact_u64(self) -> Option<u64> {
+                    // 18446744073709551616.0 is 2^64.
+                    let range = 0.0..18446744073709551616.0;
+                    (self.fract() == 0.0 && range.contains(&self)).then(|| self as u64)
+                }
+                fn exact_f32(self) -> Option<f32> {
+                    let f = self as f32;
+                    (f as f64 == self as f64 || self.is_nan()).then(|| f)
+                }
+                fn exact_f64(self) -> Option<f64> {
+                    Some(self as f64)
+                }
+            }
+        )*
+    };
+}
+
+impl_exact_num_for_float!(f32, f64);
+
+fn exact_f32<T: ExactNum>(val: T) -> Result<f32> {
+    val.exact_f32().ok_or_else(|| lossy_conversion(val, "f32"))
+}
+
+fn exact_f64<T: ExactNum>(val: T) -> Result<f64> {
+    val.exact_f64().ok_or_else(|| lossy_conversion(val, "f64"))
+}
+
+fn lossy_conversion<T>(val: T, to_type: &str) -> Error
+where
+    T: fmt::Display,
+{
+    Error::out_of_range(format!(
+        "{} cannot be converted to {} without loss",
+        val, to_type
+    ))
+}
+
+// Returns true when `num`, a NUMBER value formatted by ODPI-C, is
+// converted to `flt` without loss. `flt_str` is the string representation
+// of `flt` in the original float type.
+fn number_matches_float(num: &str, flt: f64, flt_str: &str) -> bool {
+    if !num.contains('.') {
+        // Integers are compared exactly because f64's Display may round them
+        // such as 1152921504606847000 for 2^60.
+        if let Ok(n) = num.parse::<i128>() {
+            return flt.fract() == 0.0 && flt.abs() < 1e38 && flt as i128 == n;
+        }
+    }
+    num == flt_str || (num == "0" && flt_str == "-0")
+}
+
 pub enum BufferRowIndex {
     Shared(Arc<AtomicU32>),
     Owned(u32),
@@ -362,6 +538,22 @@ impl SqlValue<'_> {
         }
     }
 
+    // Returns the size of the value. It is the length for character and
+    // binary data and the size of dpiDataBuffer for others.
+    pub(crate) fn data_size(&self) -> usize {
+        let data = match self.data() {
+            Ok(data) => data,
+            Err(_) => return 0,
+        };
+        if data.isNull != 0 {
+            0
+        } else if self.native_type_num() == DPI_NATIVE_TYPE_BYTES {
+            unsafe { data.value.asBytes.length as usize }
+        } else {
+            mem::size_of::<dpiDataBuffer>()
+        }
+    }
+
     pub(crate) fn native_type_num(&self) -> dpiNativeTypeNum {
         self.native_type.to_native_type_num()
     }
@@ -385,6 +577,40 @@ impl SqlValue<'_> {
         <T>::from_sql(self)
     }
 
+    /// Gets a `RAW` or `LONG RAW` value as a byte slice without copying it
+    ///
+    /// The slice borrows the buffer in `self`, which is not modified while it
+    /// is borrowed. Use [`SqlValue::get::<Vec<u8>>()`](SqlValue::get) to get
+    /// values of other types as bytes. This returns `Err(Error::NullValue)`
+    /// for NULL values.
+    pub fn get_bytes(&self) -> Result<&[u8]> {
+        match self.native_type {
+            NativeType::Raw => {
+                self.check_not_null()?;
+                unsafe {
+                    let bytes = dpiData_getBytes(self.data()?);
+                    Ok(to_rust_slice((*bytes).ptr, (*bytes).length))
+                }
+            }
+            _ => self.invalid_conversion_to_rust_type("&[u8]"),
+        }
+    }
+
+    /// Gets the Oracle value as a string, borrowing the buffer when possible
+    ///
+    /// `CHAR`, `VARCHAR2`, `NCHAR`, `NVARCHAR2`, `LONG` and `NUMBER` values
+    /// are borrowed when the buffer holds valid UTF-8. Invalid byte sequences
+    /// are replaced with `U+FFFD` and an owned string is returned in that case.
+    /// Values of other types are converted as [`SqlValue::get::<String>()`](SqlValue::get)
+    /// does. This returns `Err(Error::NullValue)` for NULL values.
+    pub fn get_str(&self) -> Result<Cow<'_, str>> {
+        match self.native_type {
+            NativeType::Char => self.get_cow_str_unchecked(),
+            NativeType::Number => Ok(Cow::Borrowed(self.get_str_unchecked()?)),
+            _ => Ok(Cow::Owned(self.get()?)),
+        }
+    }
+
     /// Sets a rust value to the Oracle value. It internally does the followings:
     ///
     /// 1. Checks whether the conversion from the rust type to the target Oracle type
@@ -400,6 +626,11 @@ impl SqlValue<'_> {
         val.to_sql(self)
     }
 
+    // Returns true when strict conversions are enabled on the connection.
+    fn is_strict(&self) -> bool {
+        self.conn.strict_conversions.load(Ordering::Relaxed)
+    }
+
     fn invalid_conversion_to_rust_type<T>(&self, to_type: &str) -> Result<T> {
         Err(match self.oratype {
             Some(ref oratype) => Error::invalid_type_conversion(oratype.to_string(), to_type),
@@ -930,6 +1161,62 @@ impl SqlValue<'_> {
         Ok(())
     }
 
+    // Copies a value got by ODPI-C object functions such as
+    // dpiObject_getAttributeValue() to the dpiVar handle.
+    // `data` must be got with the native type of `self`.
+    pub(crate) fn set_from_dpi_data(&mut self, data: &dpiData) -> Result<()> {
+        let var = self.handle()?;
+        let pos = self.buffer_row_index();
+        if data.isNull != 0 {
+            return self.set_null();
+        }
+        unsafe {
+            match self.native_type_num() {
+                DPI_NATIVE_TYPE_BYTES => {
+                    let bytes = &data.value.asBytes;
+                    chkerr!(
+                        self.ctxt(),
+                        dpiVar_setFromBytes(var, pos, bytes.ptr, bytes.length)
+                    );
+                }
+                DPI_NATIVE_TYPE_LOB => {
+                    chkerr!(self.ctxt(), dpiVar_setFromLob(var, pos, data.value.asLOB));
+                }
+                DPI_NATIVE_TYPE_OBJECT => {
+                    chkerr!(
+                        self.ctxt(),
+                        dpiVar_setFromObject(var, pos, data.value.asObject)
+                    );
+                }
+                DPI_NATIVE_TYPE_ROWID => {
+                    chkerr!(
+                        self.ctxt(),
+                        dpiVar_setFromRowid(var, pos, data.value.asRowid)
+                    );
+                }
+                DPI_NATIVE_TYPE_INT64
+                | DPI_NATIVE_TYPE_UINT64
+                | DPI_NATIVE_TYPE_FLOAT
+                | DPI_NATIVE_TYPE_DOUBLE
+                | DPI_NATIVE_TYPE_TIMESTAMP
+                | DPI_NATIVE_TYPE_INTERVAL_DS
+                | DPI_NATIVE_TYPE_INTERVAL_YM
+                | DPI_NATIVE_TYPE_BOOLEAN => {
+                    let dest = self.data()?;
+                    dest.value = data.value;
+                    dest.isNull = 0;
+                }
+                _ => {
+                    return Err(Error::internal_error(format!(
+                        "unsupported native type number {}",
+                        self.native_type_num()
+                    )))
+                }
+            }
+        }
+        Ok(())
+    }
+
     pub(crate) fn clone_except_fetch_array_buffer(&self) -> Result<SqlValue<'static>> {
         if let DpiData::Var(ref var) = self.data {
             Ok(SqlValue {
@@ -985,8 +1272,8 @@ impl SqlValue<'_> {
         match self.native_type {
             NativeType::Int64 => self.get_i64_unchecked(),
             NativeType::UInt64 => Ok(self.get_u64_unchecked()?.try_into()?),
-            NativeType::Float => flt_to_int!(self.get_f32_unchecked()?, f32, i64),
-            NativeType::Double => flt_to_int!(self.get_f64_unchecked()?, f64, i64),
+            NativeType::Float => flt_to_int!(self, self.get_f32_unchecked()?, f32, i64),
+            NativeType::Double => flt_to_int!(self, self.get_f64_unchecked()?, f64, i64),
             NativeType::Char => Ok(self.get_cow_str_unchecked()?.parse()?),
             NativeType::Number => Ok(self.get_str_unchecked()?.parse()?),
             NativeType::Clob => Ok(self.get_clob_as_string_unchecked()?.parse()?),
@@ -1017,8 +1304,8 @@ impl SqlValue<'_> {
         match self.native_type {
             NativeType::Int64 => Ok(self.get_i64_unchecked()?.try_into()?),
             NativeType::UInt64 => self.get_u64_unchecked(),
-            NativeType::Float => flt_to_int!(self.get_f32_unchecked()?, f32, u64),
-            NativeType::Double => flt_to_int!(self.get_f64_unchecked()?, f64, u64),
+            NativeType::Float => flt_to_int!(self, self.get_f32_unchecked()?, f32, u64),
+            NativeType::Double => flt_to_int!(self, self.get_f64_unchecked()?, f64, u64),
             NativeType::Char => Ok(self.get_cow_str_unchecked()?.parse()?),
             NativeType::Number => Ok(self.get_str_unchecked()?.parse()?),
             NativeType::Clob => Ok(self.get_clob_as_string_unchecked()?.parse()?),
@@ -1026,15 +1313,36 @@ impl SqlValue<'_> {
         }
     }
 
+    define_fn_to_int128!(
+        /// Gets the SQL value as i128. The Oracle type must be
+        /// numeric or string (excluding LOB) types.
+        : to_i128, i128);
+    define_fn_to_int128!(
+        /// Gets the SQL value as u128. The Oracle type must be
+        /// numeric or string (excluding LOB) types.
+        : to_u128, u128);
+
     /// Gets the SQL value as f32. The Oracle type must be
     /// numeric or string (excluding LOB) types.
     pub(crate) fn to_f32(&self) -> Result<f32> {
         match self.native_type {
+            NativeType::Int64 if self.is_strict() => exact_f32(self.get_i64_unchecked()?),
             NativeType::Int64 => Ok(self.get_i64_unchecked()? as f32),
+            NativeType::UInt64 if self.is_strict() => exact_f32(self.get_u64_unchecked()?),
             NativeType::UInt64 => Ok(self.get_u64_unchecked()? as f32),
             NativeType::Float => self.get_f32_unchecked(),
+            NativeType::Double if self.is_strict() => exact_f32(self.get_f64_unchecked()?),
             NativeType::Double => Ok(self.get_f64_unchecked()? as f32),
             NativeType::Char => Ok(self.get_cow_str_unchecked()?.parse()?),
+            NativeType::Number if self.is_strict() => {
+                let s = self.get_str_unchecked()?;
+                let f: f32 = s.parse()?;
+                if number_matches_float(s, f as f64, &f.to_string()) {
+                    Ok(f)
+                } else {
+                    Err(lossy_conversion(s, "f32"))
+                }
+            }
             NativeType::Number => Ok(self.get_str_unchecked()?.parse()?),
             NativeType::Clob => Ok(self.get_clob_as_string_unchecked()?.parse()?),
             _ => self.invalid_conversion_to_rust_type("f32"),
@@ -1045,11 +1353,22 @@ impl SqlValue<'_> {
     /// numeric or string (excluding LOB) types.
     pub(crate) fn to_f64(&self) -> Result<f64> {
         match self.native_type {
+            NativeType::Int64 if self.is_strict() => exact_f64(self.get_i64_unchecked()?),
             NativeType::Int64 => Ok(self.get_i64_unchecked()? as f64),
+            NativeType::UInt64 if self.is_strict() => exact_f64(self.get_u64_unchecked()?),
             NativeType::UInt64 => Ok(self.get_u64_unchecked()? as f64),
             NativeType::Float => Ok(self.get_f32_unchecked()? as f64),
             NativeType::Double => self.get_f64_unchecked(),
             NativeType::Char => Ok(self.get_cow_str_unchecked()?.parse()?),
+            NativeType::Number if self.is_strict() => {
+                let s = self.get_str_unchecked()?;
+                let f: f64 = s.parse()?;
+                if number_matches_float(s, f, &f.to_string()) {
+                    Ok(f)
+                } else {
+                    Err(lossy_conversion(s, "f64"))
+                }
+            }
             NativeType::Number => Ok(self.get_str_unchecked()?.parse()?),
             NativeType::Clob => Ok(self.get_clob_as_string_unchecked()?.parse()?),
             _ => self.invalid_conversion_to_rust_type("f64"),
@@ -1356,6 +1675,14 @@ impl SqlValue<'_> {
         /// Sets usize to the SQL value. The Oracle type must be
         /// numeric or string (excluding LOB) types.
         : set_usize, usize);
+    define_fn_set_int128!(
+        /// Sets i128 to the SQL value. The Oracle type must be
+        /// numeric or string (excluding LOB) types.
+        : set_i128, i128);
+    define_fn_set_int128!(
+        /// Sets u128 to the SQL value. The Oracle type must be
+        /// numeric or string (excluding LOB) types.
+        : set_u128, u128);
     define_fn_set_int!(
         /// Sets f32 to the SQL value. The Oracle type must be
         /// numeric or string (excluding LOB) types.
@@ -1387,6 +1714,23 @@ impl SqlValue<'_> {
         }
     }
 
+    /// Sets &str to the SQL value, honoring the connection's
+    /// [`EmptyStringMode`](crate::EmptyStringMode) when it is empty.
+    pub(crate) fn set_str_with_empty_string_mode(&mut self, val: &str) -> Result<()> {
+        if val.is_empty() {
+            match self.conn.empty_string_mode() {
+                EmptyStringMode::Null => (),
+                EmptyStringMode::Blank => return self.set_string(" "),
+                EmptyStringMode::Error => {
+                    return Err(Error::invalid_argument(
+                        "empty string is not allowed by EmptyStringMode::Error",
+                    ))
+                }
+            }
+        }
+        self.set_string(val)
+    }
+
     /// Sets &[u8] to the SQL value. ...
     pub(crate) fn set_bytes(&mut self, val: &[u8]) -> Result<()> {
         match self.native_type {
@@ -1561,3 +1905,42 @@ impl fmt::Debug for SqlValue<'_> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_num() {
+        assert_eq!(3i32.exact_u64(), Some(3));
+        assert_eq!((-3i32).exact_u64(), None);
+        assert_eq!(u64::MAX.exact_i64(), None);
+        assert_eq!((1i64 << 53).exact_f64(), Some(9007199254740992.0));
+        assert_eq!(((1i64 << 53) + 1).exact_f64(), None);
+        assert_eq!(i64::MAX.exact_f64(), None);
+        assert_eq!(16777217i32.exact_f32(), None);
+        assert_eq!(2.0f64.exact_i64(), Some(2));
+        assert_eq!(2.5f64.exact_i64(), None);
+        assert_eq!(9223372036854775808.0f64.exact_i64(), None);
+        assert_eq!((-1.0f64).exact_u64(), None);
+        assert_eq!(0.5f64.exact_f32(), Some(0.5));
+        assert_eq!(0.1f64.exact_f32(), None);
+        assert!(f64::NAN.exact_f32().unwrap().is_nan());
+    }
+
+    #[test]
+    fn number_matches_float_test() {
+        let check = |num: &str| {
+            let f: f64 = num.parse().unwrap();
+            number_matches_float(num, f, &f.to_string())
+        };
+        assert!(check("0"));
+        assert!(check("0.5"));
+        assert!(check("-0.001"));
+        assert!(check("0.1"));
+        assert!(check("1152921504606846976")); // 2^60
+        assert!(!check("1152921504606846977"));
+        assert!(!check("0.12345678901234567891"));
+        assert!(!check("123456789012345678.5"));
+    }
+}
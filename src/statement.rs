@@ -13,25 +13,31 @@
 // (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
 //-----------------------------------------------------------------------------
 
+use crate::batch::check_identifier;
 use crate::chkerr;
 use crate::connection::Conn;
+use crate::observer;
 use crate::oci_attr::data_type::{AttrValue, DataType};
 use crate::oci_attr::mode::{ReadMode, WriteMode};
-use crate::oci_attr::{self, OciAttr, SqlFnCode};
+use crate::oci_attr::{self, DefaultLobPrefetchSize, OciAttr, SqlFnCode};
 use crate::private;
 use crate::sql_type::FromSql;
+use crate::sql_type::ObjectType;
 use crate::sql_type::OracleType;
 use crate::sql_type::RefCursor;
 use crate::sql_type::ToSql;
 #[cfg(doc)]
 use crate::sql_type::{Blob, Clob, Nclob};
 use crate::sql_value::BufferRowIndex;
+use crate::to_rust_slice;
 use crate::to_rust_str;
 use crate::AssertSend;
 use crate::Connection;
 use crate::Context;
+use crate::DbError;
 use crate::DpiStmt;
 use crate::Error;
+use crate::FetchStats;
 use crate::OdpiStr;
 use crate::Result;
 use crate::ResultSet;
@@ -46,8 +52,10 @@ use std::io::Read;
 use std::mem::MaybeUninit;
 use std::os::raw::c_char;
 use std::ptr;
+use std::str;
 use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
 
 // https://www.oracle.com/pls/topic/lookup?ctx=dblatest&id=GUID-A251CF91-EB9F-4DBC-8BB8-FB5EA92C20DE
 const SQLFNCODE_CREATE_TYPE: u16 = 77;
@@ -60,11 +68,35 @@ pub enum LobBindType {
     Bytes,
 }
 
-#[derive(Clone, Debug)]
+/// Parameters used by statements to fetch rows and to bind LOBs
+///
+/// The default values of statements created by a connection are set by
+/// [`Connection::set_default_query_params`]. Each of them can be overwritten
+/// per statement by the same-named method of [`StatementBuilder`].
+///
+/// # Examples
+///
+/// ```
+/// # use oracle::test_util;
+/// use oracle::QueryParams;
+/// let mut conn = test_util::connect()?;
+/// let params = QueryParams::new().fetch_array_size(1000).prefetch_rows(1000);
+/// conn.set_default_query_params(params.clone());
+/// assert_eq!(conn.default_query_params(), params);
+///
+/// // Rows are fetched 1000 at a time unless the statement overwrites it.
+/// for row in conn.query_as::<i32>("select IntCol from TestStrings", &[])? {
+///     let _int_col = row?;
+/// }
+/// # Ok::<(), oracle::Error>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct QueryParams {
-    pub fetch_array_size: u32,
-    pub prefetch_rows: Option<u32>,
-    pub lob_bind_type: LobBindType,
+    pub(crate) fetch_array_size: u32,
+    pub(crate) prefetch_rows: Option<u32>,
+    pub(crate) lob_bind_type: LobBindType,
+    pub(crate) lob_prefetch_size: Option<u32>,
+    pub(crate) max_rows: Option<u64>,
 }
 
 impl QueryParams {
@@ -73,8 +105,51 @@ impl QueryParams {
             fetch_array_size: DPI_DEFAULT_FETCH_ARRAY_SIZE,
             prefetch_rows: None,
             lob_bind_type: LobBindType::Bytes,
+            lob_prefetch_size: None,
+            max_rows: None,
         }
     }
+
+    /// Changes the array size used for performing fetches.
+    /// See [`StatementBuilder::fetch_array_size`].
+    pub fn fetch_array_size(mut self, size: u32) -> Self {
+        self.fetch_array_size = size;
+        self
+    }
+
+    /// Changes the number of rows prefetched by the Oracle client library.
+    /// See [`StatementBuilder::prefetch_rows`].
+    pub fn prefetch_rows(mut self, size: u32) -> Self {
+        self.prefetch_rows = Some(size);
+        self
+    }
+
+    /// Enables LOB data types to be fetched or bound as LOB locators.
+    /// See [`StatementBuilder::lob_locator`].
+    pub fn lob_locator(mut self) -> Self {
+        self.lob_bind_type = LobBindType::Locator;
+        self
+    }
+
+    /// Changes the prefetch buffer size for each LOB locator.
+    /// See [`StatementBuilder::lob_prefetch_size`].
+    pub fn lob_prefetch_size(mut self, size: u32) -> Self {
+        self.lob_prefetch_size = Some(size);
+        self
+    }
+
+    /// Changes the maximum number of rows returned by a query.
+    /// See [`StatementBuilder::max_rows`].
+    pub fn max_rows(mut self, rows: u64) -> Self {
+        self.max_rows = Some(rows);
+        self
+    }
+}
+
+impl Default for QueryParams {
+    fn default() -> QueryParams {
+        QueryParams::new()
+    }
 }
 
 /// A builder to create a [`Statement`][] with various configuration
@@ -84,7 +159,12 @@ pub struct StatementBuilder<'conn, 'sql> {
     query_params: QueryParams,
     scrollable: bool,
     tag: String,
+    tag_with_sql_hash: bool,
     exclude_from_cache: bool,
+    log_errors: Option<(String, Option<String>)>,
+    // Some(include_values) when executions are recorded
+    record_execution: Option<bool>,
+    check_bind_type_changes: bool,
 }
 
 impl<'conn, 'sql> StatementBuilder<'conn, 'sql> {
@@ -92,10 +172,14 @@ impl<'conn, 'sql> StatementBuilder<'conn, 'sql> {
         StatementBuilder {
             conn,
             sql,
-            query_params: QueryParams::new(),
+            query_params: conn.default_query_params(),
             scrollable: false,
             tag: "".into(),
+            tag_with_sql_hash: false,
             exclude_from_cache: false,
+            log_errors: None,
+            record_execution: None,
+            check_bind_type_changes: false,
         }
     }
 
@@ -183,6 +267,79 @@ impl<'conn, 'sql> StatementBuilder<'conn, 'sql> {
         self
     }
 
+    /// Changes the prefetch buffer size for each LOB locator fetched by the statement.
+    ///
+    /// This overwrites [`DefaultLobPrefetchSize`] set to the connection only for
+    /// this statement. When LOB columns are fetched by [`lob_locator`](StatementBuilder::lob_locator),
+    /// the specified size of data is fetched along with each locator.
+    /// That reduces network round trips when the LOB data are read.
+    ///
+    /// Note that the length of each LOB is always fetched along with
+    /// the locator. So [`Lob::size`] doesn't require a network round trip
+    /// regardless of this parameter.
+    ///
+    /// The Oracle client library refers to the connection-wide
+    /// [`DefaultLobPrefetchSize`] when columns are defined. So the attribute
+    /// is changed to the specified size while columns are defined and rows
+    /// are fetched, and then restored. Statements defining LOB columns on the
+    /// same connection in other threads at the same time may use the
+    /// specified size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::sql_type::{Clob, Lob};
+    /// # use oracle::test_util;
+    /// # use std::io::Read;
+    /// # let conn = test_util::connect()?;
+    /// # conn.execute("delete from TestClobs", &[])?;
+    /// # conn.execute("insert into TestClobs values (:1, :2)", &[&1i32, &"clob data"])?;
+    /// let mut stmt = conn
+    ///     .statement("select ClobCol from TestClobs where IntCol = :1")
+    ///     .lob_locator()
+    ///     .lob_prefetch_size(64 * 1024)
+    ///     .build()?;
+    /// let mut clob = stmt.query_row_as::<Clob>(&[&1i32])?;
+    ///
+    /// // Allocate a buffer using the prefetched length.
+    /// let mut buf = String::with_capacity(clob.size()? as usize);
+    /// clob.read_to_string(&mut buf)?;
+    /// assert_eq!(buf, "clob data");
+    /// # Ok::<(), Box::<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [`Lob::size`]: crate::sql_type::Lob::size
+    pub fn lob_prefetch_size(&mut self, size: u32) -> &mut StatementBuilder<'conn, 'sql> {
+        self.query_params.lob_prefetch_size = Some(size);
+        self
+    }
+
+    /// Stops fetching rows after `rows` rows are returned by a query.
+    ///
+    /// The limit is applied on the client side. Rows after the limit
+    /// are not fetched from the server but the query itself isn't changed.
+    /// Use `FETCH FIRST n ROWS ONLY` in the SQL statement or
+    /// [`Connection::query_page`] when the server should stop producing
+    /// rows early.
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let mut stmt = conn
+    ///     .statement("select IntCol from TestNumbers order by IntCol")
+    ///     .max_rows(3)
+    ///     .build()?;
+    /// let nums = stmt.query_collect::<i32>(&[])?;
+    /// assert_eq!(nums, vec![1, 2, 3]);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn max_rows(&mut self, rows: u64) -> &mut StatementBuilder<'conn, 'sql> {
+        self.query_params.max_rows = Some(rows);
+        self
+    }
+
     /// Specifies the key to be used for searching for the statement in the statement cache.
     /// If the key is not found, the SQL text specified by [`Connection::statement`] is used
     /// to create a statement.
@@ -240,6 +397,56 @@ impl<'conn, 'sql> StatementBuilder<'conn, 'sql> {
         T: Into<String>,
     {
         self.tag = tag_name.into();
+        self.tag_with_sql_hash = false;
+        self
+    }
+
+    /// Specifies the key combined with a hash of the SQL text to be used for
+    /// searching for the statement in the statement cache.
+    ///
+    /// Unlike [`StatementBuilder::tag`], a statement prepared with other SQL text
+    /// is never returned because the hash is a part of the key. Building
+    /// a statement fails with an error whose kind is [`ErrorKind::InvalidArgument`](crate::ErrorKind::InvalidArgument)
+    /// when the tag was used for other SQL text on the connection. The SQL text
+    /// cannot be empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::ErrorKind;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let mut stmt = conn
+    ///     .statement("select 1 from dual")
+    ///     .tag_with_sql_hash("query one")
+    ///     .build()?;
+    /// assert_eq!(stmt.query_row_as::<i32>(&[])?, 1);
+    /// stmt.close()?;
+    ///
+    /// // The statement cached above is used.
+    /// let mut stmt = conn
+    ///     .statement("select 1 from dual")
+    ///     .tag_with_sql_hash("query one")
+    ///     .build()?;
+    /// assert_eq!(stmt.query_row_as::<i32>(&[])?, 1);
+    /// stmt.close()?;
+    ///
+    /// // The same tag with other SQL text is an error.
+    /// let err = conn
+    ///     .statement("select 2 from dual")
+    ///     .tag_with_sql_hash("query one")
+    ///     .build()
+    ///     .unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::InvalidArgument);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn tag_with_sql_hash<T>(&mut self, tag_name: T) -> &mut StatementBuilder<'conn, 'sql>
+    where
+        T: Into<String>,
+    {
+        self.tag = tag_name.into();
+        self.tag_with_sql_hash = true;
         self
     }
 
@@ -249,6 +456,99 @@ impl<'conn, 'sql> StatementBuilder<'conn, 'sql> {
         self
     }
 
+    /// Appends the DML error logging clause `LOG ERRORS INTO table ('tag') REJECT LIMIT UNLIMITED`
+    /// to the SQL statement.
+    ///
+    /// Rows which cannot be inserted, updated or deleted are logged into the
+    /// error logging table instead of making the statement fail. The table
+    /// must be created beforehand by `DBMS_ERRLOG.CREATE_ERROR_LOG`. Use
+    /// [`Connection::dml_error_log`] to read the logged rows.
+    ///
+    /// `table` must be an SQL identifier optionally qualified by a schema name.
+    /// Otherwise, [`build`](StatementBuilder::build) fails.
+    ///
+    /// ```no_run
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// // execute dbms_errlog.create_error_log('TestTempTable', 'TestTempTable_Err')
+    /// let mut stmt = conn
+    ///     .statement("insert into TestTempTable select IntCol, StringCol from TestStrings")
+    ///     .log_errors_into("TestTempTable_Err", Some("load 1"))
+    ///     .build()?;
+    /// stmt.execute(&[])?;
+    /// for rec in conn.dml_error_log("TestTempTable_Err", Some("load 1"))? {
+    ///     println!("ORA-{:05}: {}", rec.code, rec.message);
+    /// }
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn log_errors_into(
+        &mut self,
+        table: &str,
+        tag: Option<&str>,
+    ) -> &mut StatementBuilder<'conn, 'sql> {
+        self.log_errors = Some((table.into(), tag.map(|s| s.into())));
+        self
+    }
+
+    /// Records the SQL statement and bind values on each execution
+    ///
+    /// The last record is got by [`Statement::last_execution`].
+    /// Bind values are formatted as strings when `include_values` is true.
+    /// Otherwise, only their names and types are recorded so that passwords
+    /// and personal data don't leak into audit logs.
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let mut stmt = conn
+    ///     .statement("select StringCol from TestStrings where IntCol = :id")
+    ///     .record_execution(false)
+    ///     .build()?;
+    /// stmt.query_row(&[&1])?;
+    /// let rec = stmt.last_execution().unwrap();
+    /// assert_eq!(rec.sql(), "select StringCol from TestStrings where IntCol = :id");
+    /// assert_eq!(rec.binds()[0].name(), "ID");
+    /// assert_eq!(rec.binds()[0].value(), None);
+    /// assert!(rec.succeeded());
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn record_execution(&mut self, include_values: bool) -> &mut StatementBuilder<'conn, 'sql> {
+        self.record_execution = Some(include_values);
+        self
+    }
+
+    /// Detects changes of bind types between consecutive executions
+    ///
+    /// Oracle creates a new child cursor server-side when the same SQL
+    /// statement is executed with different bind types or sizes, such as
+    /// `VARCHAR2(10)` followed by `VARCHAR2(4000)` or `NUMBER` followed by
+    /// `BINARY_DOUBLE`. Too many child cursors degrade cursor sharing.
+    /// When this is set, changes from the previous execution are got by
+    /// [`Statement::bind_type_changes`].
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let mut stmt = conn
+    ///     .statement("select count(*) from TestStrings where StringCol = :1")
+    ///     .check_bind_type_changes()
+    ///     .build()?;
+    /// stmt.query_row(&[&"abc"])?;
+    /// assert!(stmt.bind_type_changes().is_empty());
+    /// stmt.query_row(&[&"x".repeat(5000)])?;
+    /// let changes = stmt.bind_type_changes();
+    /// assert_eq!(changes.len(), 1);
+    /// assert_eq!(changes[0].name(), "1");
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn check_bind_type_changes(&mut self) -> &mut StatementBuilder<'conn, 'sql> {
+        self.check_bind_type_changes = true;
+        self
+    }
+
     pub fn build(&self) -> Result<Statement> {
         Statement::new(self)
     }
@@ -361,8 +661,37 @@ pub(crate) struct Stmt {
     shared_buffer_row_index: Arc<AtomicU32>,
     last_buffer_row_index: u32,
     more_rows: bool,
+    // the number of rows returned by `next()` after the statement was executed
+    returned_rows: u64,
     pub(crate) query_params: QueryParams,
     tag: String,
+    pub(crate) fetch_stats: FetchStats,
+}
+
+// Restores OCI_ATTR_DEFAULT_LOBPREFETCH_SIZE changed by `Stmt::with_lob_prefetch_size`
+// also when the function called with the changed size panics.
+struct LobPrefetchSizeGuard {
+    conn: Connection,
+    orig_size: u32,
+    restored: bool,
+}
+
+impl LobPrefetchSizeGuard {
+    fn restore(mut self) -> Result<()> {
+        self.restored = true;
+        self.conn
+            .set_oci_attr::<DefaultLobPrefetchSize>(&self.orig_size)
+    }
+}
+
+impl Drop for LobPrefetchSizeGuard {
+    fn drop(&mut self) {
+        if !self.restored {
+            let _ = self
+                .conn
+                .set_oci_attr::<DefaultLobPrefetchSize>(&self.orig_size);
+        }
+    }
 }
 
 impl Stmt {
@@ -374,8 +703,10 @@ impl Stmt {
             shared_buffer_row_index: Arc::new(AtomicU32::new(0)),
             last_buffer_row_index: 0,
             more_rows: false,
+            returned_rows: 0,
             query_params,
             tag,
+            fetch_stats: FetchStats::default(),
         }
     }
 
@@ -401,37 +732,83 @@ impl Stmt {
         self.shared_buffer_row_index.store(0, Ordering::Relaxed);
         self.last_buffer_row_index = 0;
         self.more_rows = true;
+        self.returned_rows = 0;
+        self.fetch_stats = FetchStats::default();
         if self.row.is_some() {
             return Ok(());
         }
-        let mut column_info = Vec::with_capacity(num_cols);
-        let mut column_values = Vec::with_capacity(num_cols);
-
-        for i in 0..num_cols {
-            let info = ColumnInfo::new(self, i)?;
-            let val = SqlValue::for_column(
-                self.conn.clone(),
-                self.query_params.clone(),
-                self.shared_buffer_row_index.clone(),
-                info.oracle_type(),
-                self.handle(),
-                (i + 1) as u32,
-            )?;
-            column_info.push(info);
-            column_values.push(val);
-        }
+        let (column_info, column_values) = self.with_lob_prefetch_size(|stmt| {
+            let mut column_info = Vec::with_capacity(num_cols);
+            let mut column_values = Vec::with_capacity(num_cols);
+            for i in 0..num_cols {
+                let info = ColumnInfo::new(stmt, i)?;
+                let val = SqlValue::for_column(
+                    stmt.conn.clone(),
+                    stmt.query_params.clone(),
+                    stmt.shared_buffer_row_index.clone(),
+                    &stmt.conn.define_type(&info),
+                    stmt.handle(),
+                    (i + 1) as u32,
+                )?;
+                column_info.push(info);
+                column_values.push(val);
+            }
+            Ok((column_info, column_values))
+        })?;
         self.row = Some(Row::new(column_info, column_values)?);
         Ok(())
     }
 
+    // Calls `f` with OCI_ATTR_DEFAULT_LOBPREFETCH_SIZE temporarily changed
+    // to `lob_prefetch_size` because it is referred when columns are defined.
+    // ODPI-C doesn't expose define handles, which have a per-column attribute.
+    fn with_lob_prefetch_size<T, F>(&mut self, f: F) -> Result<T>
+    where
+        F: FnOnce(&mut Stmt) -> Result<T>,
+    {
+        let size = match self.query_params.lob_prefetch_size {
+            Some(size) => size,
+            None => return f(self),
+        };
+        let mut conn = Connection::from_conn(self.conn.clone());
+        let orig_size = conn.oci_attr::<DefaultLobPrefetchSize>()?;
+        if orig_size == size {
+            return f(self);
+        }
+        conn.set_oci_attr::<DefaultLobPrefetchSize>(&size)?;
+        let guard = LobPrefetchSizeGuard {
+            conn,
+            orig_size,
+            restored: false,
+        };
+        let result = f(self);
+        // An error in `f` takes precedence over an error restoring the size.
+        let restored = guard.restore();
+        let value = result?;
+        restored?;
+        Ok(value)
+    }
+
+    // Returns the number of rows which can be returned until `max_rows` is reached
+    fn remaining_rows(&self) -> Option<u64> {
+        self.query_params
+            .max_rows
+            .map(|max_rows| max_rows.saturating_sub(self.returned_rows))
+    }
+
     fn try_next(&mut self) -> Result<Option<&Row>> {
+        if self.remaining_rows() == Some(0) {
+            return Ok(None);
+        }
         let index = self.shared_buffer_row_index.load(Ordering::Relaxed);
         let last_index = self.last_buffer_row_index;
         if index + 1 < last_index {
             self.shared_buffer_row_index
                 .store(index + 1, Ordering::Relaxed);
+            self.returned_rows += 1;
             Ok(Some(self.row.as_ref().unwrap()))
         } else if self.more_rows && self.fetch_rows()? {
+            self.returned_rows += 1;
             Ok(Some(self.row.as_ref().unwrap()))
         } else {
             Ok(None)
@@ -442,38 +819,60 @@ impl Stmt {
         self.try_next().transpose()
     }
 
+    // Returns the number of rows fetched into the buffer and not returned by `next()` yet
+    pub(crate) fn buffered_rows(&self) -> usize {
+        let index = self.shared_buffer_row_index.load(Ordering::Relaxed);
+        self.last_buffer_row_index.saturating_sub(index + 1) as usize
+    }
+
     pub fn fetch_rows(&mut self) -> Result<bool> {
         let handle = self.handle();
-        let row = self.row.as_mut().unwrap();
-        for i in 0..(row.column_info.len()) {
-            // If fetch array buffer is referenced only by self, it is reusable.
-            // Otherwise, a new SqlValue must be created to allocate a new buffer
-            // because dpiStmt_fetchRows() overwrites the buffer.
-            if row.column_values[i].fetch_array_buffer_shared_count()? > 1 {
-                let oratype = row.column_info[i].oracle_type();
-                row.column_values[i] = SqlValue::for_column(
-                    self.conn.clone(),
-                    self.query_params.clone(),
-                    self.shared_buffer_row_index.clone(),
-                    oratype,
-                    handle,
-                    (i + 1) as u32,
-                )?;
+        self.with_lob_prefetch_size(|stmt| {
+            let row = stmt.row.as_mut().unwrap();
+            for i in 0..(row.column_info.len()) {
+                // If fetch array buffer is referenced only by self, it is reusable.
+                // Otherwise, a new SqlValue must be created to allocate a new buffer
+                // because dpiStmt_fetchRows() overwrites the buffer.
+                if row.column_values[i].fetch_array_buffer_shared_count()? > 1 {
+                    // Use the type defined in init_row(), which may differ from
+                    // the column type by the output type handler.
+                    let oratype = row.column_values[i].oracle_type()?.clone();
+                    row.column_values[i] = SqlValue::for_column(
+                        stmt.conn.clone(),
+                        stmt.query_params.clone(),
+                        stmt.shared_buffer_row_index.clone(),
+                        &oratype,
+                        handle,
+                        (i + 1) as u32,
+                    )?;
+                }
             }
-        }
+            Ok(())
+        })?;
+        let fetch_array_size = match self.remaining_rows() {
+            Some(rows) if rows < u64::from(self.query_params.fetch_array_size) => rows as u32,
+            _ => self.query_params.fetch_array_size,
+        };
         let mut new_index = 0;
         let mut num_rows = 0;
         let mut more_rows = 0;
-        chkerr!(
-            self.ctxt(),
-            dpiStmt_fetchRows(
-                handle,
-                self.query_params.fetch_array_size,
-                &mut new_index,
-                &mut num_rows,
-                &mut more_rows
-            )
-        );
+        let start = Instant::now();
+        {
+            let _guard = self.conn.round_trip_guard();
+            chkerr!(
+                self.ctxt(),
+                dpiStmt_fetchRows(
+                    handle,
+                    fetch_array_size,
+                    &mut new_index,
+                    &mut num_rows,
+                    &mut more_rows
+                )
+            );
+        }
+        self.fetch_stats.fetches += 1;
+        self.fetch_stats.rows += u64::from(num_rows);
+        self.fetch_stats.elapsed += start.elapsed();
         self.shared_buffer_row_index
             .store(new_index, Ordering::Relaxed);
         self.last_buffer_row_index = new_index + num_rows;
@@ -505,13 +904,40 @@ pub struct Statement {
     bind_count: usize,
     bind_names: Vec<String>,
     bind_values: Vec<SqlValue<'static>>,
+    last_warning: Option<DbError>,
+    // SQL text and whether bind values are included when executions are recorded
+    record_execution: Option<(String, bool)>,
+    last_execution: Option<ExecutionRecord>,
+    // bind types of the previous execution when bind type changes are checked
+    last_bind_types: Option<Vec<Option<OracleType>>>,
+    bind_type_changes: Vec<BindTypeChange>,
 }
 
 impl Statement {
     fn new(builder: &StatementBuilder<'_, '_>) -> Result<Statement> {
         let conn = builder.conn;
-        let sql = OdpiStr::new(builder.sql);
-        let tag = OdpiStr::new(&builder.tag);
+        let sql_with_log_errors;
+        let sql_text = if let Some((table, tag)) = &builder.log_errors {
+            sql_with_log_errors =
+                builder.sql.to_string() + &log_errors_clause(table, tag.as_deref())?;
+            &sql_with_log_errors
+        } else {
+            builder.sql
+        };
+        let sql = OdpiStr::new(sql_text);
+        let tag = if builder.tag_with_sql_hash {
+            if builder.sql.is_empty() {
+                return Err(Error::invalid_argument(
+                    "SQL text is required for a statement tag with SQL hash",
+                ));
+            }
+            let hash = sql_hash(sql_text);
+            conn.conn.check_sql_hash_tag(&builder.tag, hash)?;
+            format!("{}#{:016x}", builder.tag, hash)
+        } else {
+            builder.tag.clone()
+        };
+        let tag_str = OdpiStr::new(&tag);
         let mut handle = DpiStmt::null();
         chkerr!(
             conn.ctxt(),
@@ -520,8 +946,8 @@ impl Statement {
                 i32::from(builder.scrollable),
                 sql.ptr,
                 sql.len,
-                tag.ptr,
-                tag.len,
+                tag_str.ptr,
+                tag_str.len,
                 &mut handle.raw
             )
         );
@@ -559,7 +985,7 @@ impl Statement {
             chkerr!(conn.ctxt(), dpiStmt_deleteFromCache(handle.raw));
             String::new()
         } else {
-            builder.tag.clone()
+            tag
         };
         Ok(Statement {
             stmt: Stmt::new(conn.conn.clone(), handle, builder.query_params.clone(), tag),
@@ -568,6 +994,13 @@ impl Statement {
             bind_count,
             bind_names,
             bind_values,
+            last_warning: None,
+            record_execution: builder
+                .record_execution
+                .map(|include_values| (builder.sql.to_string(), include_values)),
+            last_execution: None,
+            last_bind_types: builder.check_bind_type_changes.then(Vec::new),
+            bind_type_changes: Vec::new(),
         })
     }
 
@@ -621,6 +1054,43 @@ impl Statement {
         Ok(ResultSet::new(&mut self.stmt))
     }
 
+    /// Executes the prepared statement and returns all rows as a vector of [`RowValue`]s.
+    ///
+    /// This is the same as `stmt.query_as::<T>(params)?.collect::<Result<Vec<T>>>()`
+    /// except that the vector is allocated with capacity of
+    /// [prefetch rows](StatementBuilder::prefetch_rows) when it is set.
+    /// Use it for small result sets.
+    ///
+    /// See [Query Methods][].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let mut stmt = conn
+    ///     .statement("select IntCol, StringCol from TestStrings where IntCol <= :1 order by IntCol")
+    ///     .prefetch_rows(3)
+    ///     .build()?;
+    /// let rows = stmt.query_collect::<(i32, String)>(&[&2])?;
+    /// assert_eq!(rows, vec![(1, "String 1".to_string()), (2, "String 2".to_string())]);
+    /// # Ok::<(), Error>(())
+    /// ```
+    ///
+    /// [Query Methods]: https://github.com/kubo/rust-oracle/blob/master/docs/query-methods.md
+    pub fn query_collect<T>(&mut self, params: &[&dyn ToSql]) -> Result<Vec<T>>
+    where
+        T: RowValue,
+    {
+        let capacity = self.stmt.query_params.prefetch_rows.unwrap_or(0) as usize;
+        let mut rows = Vec::with_capacity(capacity);
+        for row in self.query_as::<T>(params)? {
+            rows.push(row?);
+        }
+        Ok(rows)
+    }
+
     /// Executes the prepared statement and returns a result set containing [`RowValue`]s.
     ///
     /// This is the same as [`Statement::query_as()`], but takes ownership of the [`Statement`].
@@ -713,6 +1183,43 @@ impl Statement {
         rows.next().unwrap_or(Err(Error::no_data_found()))
     }
 
+    /// Gets at most one row from the prepared statement using positoinal bind parameters.
+    ///
+    /// This returns `Ok(None)` when no rows are found and an
+    /// [`ErrorKind::InvalidOperation`](crate::ErrorKind::InvalidOperation) error
+    /// when more than one row is found.
+    ///
+    /// See [Query Methods][].
+    ///
+    /// [Query Methods]: https://github.com/kubo/rust-oracle/blob/master/docs/query-methods.md
+    pub fn query_row_opt(&mut self, params: &[&dyn ToSql]) -> Result<Option<Row>> {
+        self.query_row_as_opt::<Row>(params)
+    }
+
+    /// Gets at most one row from the prepared statement as specified type using positoinal bind parameters.
+    ///
+    /// This returns `Ok(None)` when no rows are found and an
+    /// [`ErrorKind::InvalidOperation`](crate::ErrorKind::InvalidOperation) error
+    /// when more than one row is found.
+    ///
+    /// See [Query Methods][].
+    ///
+    /// [Query Methods]: https://github.com/kubo/rust-oracle/blob/master/docs/query-methods.md
+    pub fn query_row_as_opt<T>(&mut self, params: &[&dyn ToSql]) -> Result<Option<T>>
+    where
+        T: RowValue,
+    {
+        let row = match self.query_as::<T>(params)?.next() {
+            Some(row) => row?,
+            None => return Ok(None),
+        };
+        match self.stmt.next() {
+            Some(Ok(_)) => Err(Error::invalid_operation("query returned more than one row")),
+            Some(Err(err)) => Err(err),
+            None => Ok(Some(row)),
+        }
+    }
+
     /// Binds values by position and executes the statement.
     /// It will retunrs `Err` when the statemnet is a select statement.
     ///
@@ -743,6 +1250,29 @@ impl Statement {
         self.exec(params, false, "execute")
     }
 
+    /// Binds values by position, executes the statement and returns the number of affected rows.
+    ///
+    /// This is the same as [`Statement::execute`] followed by [`Statement::row_count`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let mut stmt = conn
+    ///     .statement("update TestStrings set StringCol = StringCol where IntCol <= :1")
+    ///     .build()?;
+    /// assert_eq!(stmt.execute_update(&[&3])?, 3);
+    /// assert_eq!(stmt.execute_update(&[&0])?, 0);
+    /// # conn.rollback()?;
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn execute_update(&mut self, params: &[&dyn ToSql]) -> Result<u64> {
+        self.exec(params, false, "execute_update")?;
+        self.row_count()
+    }
+
     /// Binds values by name and executes the statement.
     /// It will retunrs `Err` when the statemnet is a select statement.
     ///
@@ -830,11 +1360,28 @@ impl Statement {
                 dpiStmt_setPrefetchRows(self.handle(), prefetch_rows)
             );
         }
-        chkerr!(
-            self.ctxt(),
-            dpiStmt_execute(self.handle(), exec_mode, &mut num_query_columns)
-        );
-        self.ctxt().set_warning();
+        self.last_warning = None;
+        let handle = self.handle();
+        let mut record = self.new_execution_record();
+        self.check_bind_type_changes();
+        let start = Instant::now();
+        let result = {
+            let _guard = self.conn().round_trip_guard();
+            observer::observe_execute(
+                self.statement_type,
+                self.ctxt(),
+                "dpiStmt_execute",
+                || unsafe { dpiStmt_execute(handle, exec_mode, &mut num_query_columns) },
+            )
+        };
+        if let Some(ref mut record) = record {
+            record.elapsed = start.elapsed();
+            record.succeeded = result.is_ok();
+        }
+        self.last_execution = record;
+        let warning = result?;
+        self.last_warning = warning.clone();
+        self.ctxt().store_warning(warning);
         if self.is_ddl() {
             let fncode = self.oci_attr::<SqlFnCode>()?;
             match fncode {
@@ -855,6 +1402,78 @@ impl Statement {
         Ok(())
     }
 
+    fn new_execution_record(&self) -> Option<ExecutionRecord> {
+        let (sql, include_values) = self.record_execution.as_ref()?;
+        let binds = self
+            .bind_names
+            .iter()
+            .zip(self.bind_values.iter())
+            .map(|(name, val)| BindRecord {
+                name: name.clone(),
+                oracle_type: val.oracle_type().ok().cloned(),
+                value: include_values.then(|| format!("{}", val)),
+            })
+            .collect();
+        Some(ExecutionRecord {
+            sql: sql.clone(),
+            binds,
+            started_at: SystemTime::now(),
+            elapsed: Duration::ZERO,
+            succeeded: false,
+        })
+    }
+
+    /// Returns the effective types of bind variables in the order of
+    /// [`Statement::bind_names`]
+    ///
+    /// The type is `None` when no value is bound to the variable yet.
+    pub fn bind_types(&self) -> Vec<Option<&OracleType>> {
+        self.bind_values
+            .iter()
+            .map(|val| val.oracle_type().ok())
+            .collect()
+    }
+
+    fn check_bind_type_changes(&mut self) {
+        if let Some(last_bind_types) = &mut self.last_bind_types {
+            let bind_types = self
+                .bind_values
+                .iter()
+                .map(|val| val.oracle_type().ok().cloned())
+                .collect::<Vec<_>>();
+            self.bind_type_changes.clear();
+            if !last_bind_types.is_empty() {
+                for (i, (previous, current)) in last_bind_types.iter().zip(&bind_types).enumerate()
+                {
+                    if previous != current {
+                        self.bind_type_changes.push(BindTypeChange {
+                            name: self.bind_names[i].clone(),
+                            previous: previous.clone(),
+                            current: current.clone(),
+                        });
+                    }
+                }
+            }
+            *last_bind_types = bind_types;
+        }
+    }
+
+    /// Returns bind variables whose types differ from the previous execution when
+    /// [`StatementBuilder::check_bind_type_changes`] is set.
+    ///
+    /// This is empty after the first execution.
+    pub fn bind_type_changes(&self) -> &[BindTypeChange] {
+        &self.bind_type_changes
+    }
+
+    /// Returns the record of the last execution when
+    /// [`StatementBuilder::record_execution`] is set.
+    ///
+    /// The record is kept even when the execution fails.
+    pub fn last_execution(&self) -> Option<&ExecutionRecord> {
+        self.last_execution.as_ref()
+    }
+
     /// Returns the number of bind variables in the statement.
     ///
     /// In SQL statements this is the total number of bind variables whereas in
@@ -930,7 +1549,8 @@ impl Statement {
     {
         let pos = bindidx.idx(self)?;
         let conn = Connection::from_conn(self.conn().clone());
-        if self.bind_values[pos].init_handle(&value.oratype(&conn)?)? {
+        let oratype = self.conn().bind_type(value.oratype(&conn)?);
+        if self.bind_values[pos].init_handle(&oratype)? {
             chkerr!(
                 self.ctxt(),
                 bindidx.bind(self.handle(), self.bind_values[pos].handle()?)
@@ -939,6 +1559,53 @@ impl Statement {
         self.bind_values[pos].set(value)
     }
 
+    /// Sets NULL whose data type is `oratype` to a bind variable in the statement.
+    ///
+    /// This is the same as `stmt.bind(bindidx, oratype)` but makes the intent clear.
+    /// The data type is used to allocate a buffer for an OUT value returned
+    /// by PL/SQL. See [`Statement::bind`] about `bindidx`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// use oracle::sql_type::OracleType;
+    /// # let conn = test_util::connect()?;
+    /// let mut stmt = conn.statement("begin :outval := upper(:inval); end;").build()?;
+    ///
+    /// // Sets NULL whose data type is VARCHAR2(60) to the first bind value.
+    /// stmt.bind_null(1, &OracleType::Varchar2(60))?;
+    /// stmt.bind("inval", &"to be upper-case")?;
+    /// stmt.execute(&[])?;
+    /// assert_eq!(stmt.bind_value::<_, String>(1)?, "TO BE UPPER-CASE");
+    ///
+    /// // Sets NULL to the second bind value, keeping its data type.
+    /// stmt.set_null_value("inval")?;
+    /// stmt.execute(&[])?;
+    /// assert_eq!(stmt.bind_value::<_, Option<String>>(1)?, None);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn bind_null<I>(&mut self, bindidx: I, oratype: &OracleType) -> Result<()>
+    where
+        I: BindIndex,
+    {
+        self.bind(bindidx, oratype)
+    }
+
+    /// Sets NULL to a bind variable which has been bound already, keeping its data type.
+    ///
+    /// An error is returned when no value has been bound to the variable yet.
+    /// Use [`Statement::bind_null`] in that case. See [`Statement::bind`] about `bindidx`.
+    pub fn set_null_value<I>(&mut self, bindidx: I) -> Result<()>
+    where
+        I: BindIndex,
+    {
+        let pos = bindidx.idx(self)?;
+        self.bind_values[pos].oracle_type()?;
+        self.bind_values[pos].set_null()
+    }
+
     /// Gets a bind value in the statement.
     ///
     /// The position starts from one when the bind index type is `usize`.
@@ -1069,6 +1736,32 @@ impl Statement {
         self.stmt.row_count()
     }
 
+    /// Gets the warning produced by the last execution of this statement.
+    ///
+    /// Unlike [`Connection::last_warning`], this isn't overwritten by
+    /// other statements executed after this one. It is cleared when the
+    /// statement is executed again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// // create a procedure with compilation error
+    /// let stmt = conn.execute("create or replace procedure my_proc is begin; null; end;", &[])?;
+    /// // another statement executed in the meantime
+    /// conn.execute("select 1 from dual", &[])?;
+    /// match stmt.last_warning() {
+    ///     Some(Error::OciError(dberr)) if dberr.code() == 24344 => (),
+    ///     warn => panic!("Unexpected last warning: {:?}", warn),
+    /// }
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn last_warning(&self) -> Option<Error> {
+        self.last_warning.clone().map(Error::oci_error)
+    }
+
     /// Returns the next implicit result returned by [`dbms_sql.return_result()`]
     /// in a PL/SQL block or a stored procedure.
     ///
@@ -1139,6 +1832,112 @@ impl Statement {
         }
     }
 
+    /// Returns all result sets output by OUT parameters and then returned
+    /// as implicit results, in that order, with their sources
+    ///
+    /// OUT parameters are ordered by their positions in the statement.
+    /// Parameters whose values are NULL, such as cursors not opened by the
+    /// PL/SQL block, are skipped. Implicit results are ordered as they are
+    /// returned by `dbms_sql.return_result()`. Note that implicit results are
+    /// consumed by this method as [`Statement::implicit_result`] does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util::{self, check_version, VER12_1};
+    /// use oracle::sql_type::OutRefCursor;
+    /// use oracle::ResultSource;
+    /// # let conn = test_util::connect()?;
+    /// # if !check_version(&conn, &VER12_1, &VER12_1)? {
+    /// #     return Ok(()); // skip this test
+    /// # }
+    /// let sql = r#"
+    /// declare
+    ///   cursor1 SYS_REFCURSOR;
+    /// begin
+    ///   open cursor1 for select StringCol from TestStrings where IntCol = 1;
+    ///   dbms_sql.return_result(cursor1);
+    ///   open :out_cursor for select StringCol from TestStrings where IntCol = 2;
+    /// end;
+    /// "#;
+    /// let mut stmt = conn.statement(sql).build()?;
+    /// stmt.execute(&[&OutRefCursor])?;
+    /// let mut results = stmt.result_sets()?;
+    /// assert_eq!(results.len(), 2);
+    /// assert_eq!(results[0].0, ResultSource::OutParam("OUT_CURSOR".into()));
+    /// assert_eq!(results[0].1.query_row_as::<String>()?, "String 2");
+    /// assert_eq!(results[1].0, ResultSource::ImplicitResult(0));
+    /// assert_eq!(results[1].1.query_row_as::<String>()?, "String 1");
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn result_sets(&self) -> Result<Vec<(ResultSource, RefCursor)>> {
+        let mut results = Vec::new();
+        for (name, val) in self.bind_names.iter().zip(self.bind_values.iter()) {
+            if let Ok(OracleType::RefCursor) = val.oracle_type() {
+                if let Some(cursor) = val.get::<Option<RefCursor>>()? {
+                    results.push((ResultSource::OutParam(name.clone()), cursor));
+                }
+            }
+        }
+        let mut n = 0;
+        while let Some(cursor) = self.implicit_result()? {
+            results.push((ResultSource::ImplicitResult(n), cursor));
+            n += 1;
+        }
+        Ok(results)
+    }
+
+    /// Returns column information of the query
+    ///
+    /// When the query hasn't been executed yet, this executes it in
+    /// describe-only mode, which requires a round trip but neither runs
+    /// the query nor requires bind values. The information is kept and
+    /// reused by later executions.
+    ///
+    /// This returns an error whose kind is [`ErrorKind::InvalidOperation`](crate::ErrorKind::InvalidOperation)
+    /// for non-select statements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let mut stmt = conn
+    ///     .statement("select IntCol, StringCol from TestStrings where IntCol = :1")
+    ///     .build()?;
+    /// let names = stmt
+    ///     .column_info()?
+    ///     .iter()
+    ///     .map(|info| info.name().to_string())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(names, ["INTCOL", "STRINGCOL"]);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn column_info(&mut self) -> Result<&[ColumnInfo]> {
+        self.check_stmt_type(true, "column_info")?;
+        if self.stmt.row.is_none() {
+            let mut num_query_columns = 0;
+            {
+                let _guard = self.conn().round_trip_guard();
+                chkerr!(
+                    self.ctxt(),
+                    dpiStmt_execute(
+                        self.handle(),
+                        DPI_MODE_EXEC_DESCRIBE_ONLY,
+                        &mut num_query_columns
+                    )
+                );
+            }
+            self.stmt.init_row(num_query_columns as usize)?;
+        }
+        match &self.stmt.row {
+            Some(row) => Ok(row.column_info()),
+            None => Err(Error::internal_error("no column information")),
+        }
+    }
+
     /// Returns statement type
     pub fn statement_type(&self) -> StatementType {
         self.statement_type
@@ -1215,6 +2014,60 @@ impl Statement {
         }
     }
 
+    /// Returns the rowids of rows affected by the last execution of the DML statement.
+    ///
+    /// When the statement has a `RETURNING ROWID INTO :bind` clause and `:bind`
+    /// is bound as [`OracleType::Rowid`], rowids returned by the clause are returned.
+    /// Otherwise, the result of [`Statement::last_row_id`] is returned when
+    /// just one row is affected and an empty vector when no rows are affected.
+    /// An error is returned when more than one row is affected without
+    /// the `RETURNING ROWID INTO` clause because Oracle doesn't tell rowids other
+    /// than the last one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::sql_type::OracleType;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// // one row
+    /// let stmt = conn.execute("update TestStrings set StringCol = StringCol where IntCol = :1", &[&1])?;
+    /// let rowids = stmt.affected_rowids()?;
+    /// assert_eq!(rowids.len(), 1);
+    ///
+    /// // more than one row
+    /// let stmt = conn.execute(
+    ///     "update TestStrings set StringCol = StringCol where IntCol >= :1 returning rowid into :2",
+    ///     &[&6, &OracleType::Rowid],
+    /// )?;
+    /// let rowids = stmt.affected_rowids()?;
+    /// assert_eq!(rowids.len(), 5);
+    /// # conn.rollback()?;
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn affected_rowids(&self) -> Result<Vec<String>> {
+        if !self.is_dml() {
+            return Err(Error::invalid_operation(
+                "could not get affected rowids of non-DML statements",
+            ));
+        }
+        if self.is_returning {
+            for (i, val) in self.bind_values.iter().enumerate() {
+                if matches!(val.oracle_type(), Ok(OracleType::Rowid)) {
+                    return self.returned_values(i + 1);
+                }
+            }
+        }
+        match self.row_count()? {
+            0 => Ok(vec![]),
+            1 => Ok(self.last_row_id()?.into_iter().collect()),
+            _ => Err(Error::invalid_operation(
+                "could not get rowids of more than one affected row without `RETURNING ROWID INTO` clause",
+            )),
+        }
+    }
+
     /// Gets an OCI handle attribute corresponding to the specified type parameter
     /// See the [`oci_attr` module][crate::oci_attr] for details.
     pub fn oci_attr<T>(&self) -> Result<<<T::DataType as DataType>::Type as ToOwned>::Owned>
@@ -1281,9 +2134,20 @@ pub struct ColumnInfo {
     name: String,
     oracle_type: OracleType,
     nullable: bool,
+    // true when the trailing incomplete multi-byte character was dropped from the name
+    name_truncated: bool,
 }
 
 impl ColumnInfo {
+    pub(crate) fn from_parts(name: String, oracle_type: OracleType, nullable: bool) -> ColumnInfo {
+        ColumnInfo {
+            name,
+            oracle_type,
+            nullable,
+            name_truncated: false,
+        }
+    }
+
     fn new(stmt: &Stmt, idx: usize) -> Result<ColumnInfo> {
         let mut info = MaybeUninit::uninit();
         chkerr!(
@@ -1291,10 +2155,12 @@ impl ColumnInfo {
             dpiStmt_getQueryInfo(stmt.handle(), (idx + 1) as u32, info.as_mut_ptr())
         );
         let info = unsafe { info.assume_init() };
+        let (name, name_truncated) = column_name(to_rust_slice(info.name, info.nameLength));
         Ok(ColumnInfo {
-            name: to_rust_str(info.name, info.nameLength),
+            name,
             oracle_type: OracleType::from_type_info(stmt.conn(), &info.typeInfo)?,
             nullable: info.nullOk != 0,
+            name_truncated,
         })
     }
 
@@ -1313,6 +2179,189 @@ impl ColumnInfo {
     pub fn nullable(&self) -> bool {
         self.nullable
     }
+
+    /// Gets the object type when the column type is an object or a collection
+    ///
+    /// This is a shortcut to get [`ObjectType`] in [`OracleType::Object`].
+    /// Use it to create objects of the same type as the column without
+    /// looking up the type by name.
+    ///
+    /// ```
+    /// # use oracle::Error;
+    /// # use oracle::test_util;
+    /// # let conn = test_util::connect()?;
+    /// let mut stmt = conn
+    ///     .statement("select ObjectCol from TestObjects where 1 = 0")
+    ///     .build()?;
+    /// let rows = stmt.query(&[])?;
+    /// let objtype = rows.column_info()[0].object_type().unwrap();
+    /// assert_eq!(objtype.name(), "UDT_OBJECT");
+    /// let obj = objtype.new_object()?;
+    /// # Ok::<(), Error>(())
+    /// ```
+    ///
+    /// [`ObjectType`]: crate::sql_type::ObjectType
+    pub fn object_type(&self) -> Option<&ObjectType> {
+        match self.oracle_type {
+            OracleType::Object(ref objtype) => Some(objtype),
+            _ => None,
+        }
+    }
+}
+
+/// Source of a result set returned by [`Statement::result_sets`]
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResultSource {
+    /// OUT parameter with the bind variable name
+    OutParam(String),
+
+    /// Implicit result with the zero-based position in the returned order
+    ImplicitResult(usize),
+}
+
+impl fmt::Display for ResultSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ResultSource::OutParam(name) => write!(f, "OUT parameter :{}", name),
+            ResultSource::ImplicitResult(n) => write!(f, "implicit result #{}", n + 1),
+        }
+    }
+}
+
+/// Record of a statement execution returned by [`Statement::last_execution`]
+///
+/// The [`Display`](fmt::Display) implementation formats it as one line
+/// suitable for audit logs.
+#[derive(Debug, Clone)]
+pub struct ExecutionRecord {
+    sql: String,
+    binds: Vec<BindRecord>,
+    started_at: SystemTime,
+    elapsed: Duration,
+    succeeded: bool,
+}
+
+impl ExecutionRecord {
+    /// Gets the SQL statement
+    pub fn sql(&self) -> &str {
+        &self.sql
+    }
+
+    /// Gets bind variables in the order of [`Statement::bind_names`]
+    pub fn binds(&self) -> &[BindRecord] {
+        &self.binds
+    }
+
+    /// Gets the time when the execution started
+    pub fn started_at(&self) -> SystemTime {
+        self.started_at
+    }
+
+    /// Gets the time spent to execute the statement
+    ///
+    /// This is measured by a monotonic clock and isn't affected by changes
+    /// of the system time unlike [`ExecutionRecord::started_at`].
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Gets whether the execution succeeded
+    pub fn succeeded(&self) -> bool {
+        self.succeeded
+    }
+}
+
+impl fmt::Display for ExecutionRecord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.sql)?;
+        for (i, bind) in self.binds.iter().enumerate() {
+            f.write_str(if i == 0 { " -- " } else { ", " })?;
+            write!(f, "{}", bind)?;
+        }
+        Ok(())
+    }
+}
+
+/// Bind variable in an [`ExecutionRecord`]
+#[derive(Debug, Clone)]
+pub struct BindRecord {
+    name: String,
+    oracle_type: Option<OracleType>,
+    value: Option<String>,
+}
+
+impl BindRecord {
+    /// Gets the bind variable name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the Oracle type. This is `None` when no value is bound.
+    pub fn oracle_type(&self) -> Option<&OracleType> {
+        self.oracle_type.as_ref()
+    }
+
+    /// Gets the value formatted as a string. This is `None` when values are redacted.
+    ///
+    /// A null value is formatted as `NULL`.
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+}
+
+impl fmt::Display for BindRecord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, ":{}", self.name)?;
+        if let Some(ref oratype) = self.oracle_type {
+            write!(f, " {}", oratype)?;
+        }
+        match self.value {
+            Some(ref value) => write!(f, " = {}", value),
+            None => write!(f, " = <redacted>"),
+        }
+    }
+}
+
+/// Change of a bind type returned by [`Statement::bind_type_changes`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BindTypeChange {
+    name: String,
+    previous: Option<OracleType>,
+    current: Option<OracleType>,
+}
+
+impl BindTypeChange {
+    /// Gets the bind variable name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the type in the previous execution. This is `None` when no value was bound.
+    pub fn previous(&self) -> Option<&OracleType> {
+        self.previous.as_ref()
+    }
+
+    /// Gets the type in the current execution. This is `None` when no value is bound.
+    pub fn current(&self) -> Option<&OracleType> {
+        self.current.as_ref()
+    }
+}
+
+impl fmt::Display for BindTypeChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let fmt_type = |t: &Option<OracleType>| match t {
+            Some(t) => t.to_string(),
+            None => "unbound".to_string(),
+        };
+        write!(
+            f,
+            ":{} {} -> {}",
+            self.name,
+            fmt_type(&self.previous),
+            fmt_type(&self.current)
+        )
+    }
 }
 
 impl fmt::Display for ColumnInfo {
@@ -1390,18 +2439,75 @@ impl ColumnIndex for usize {
 impl ColumnIndex for &str {
     fn idx(&self, column_info: &[ColumnInfo]) -> Result<usize> {
         for (idx, info) in column_info.iter().enumerate() {
-            if info.name.as_str().eq_ignore_ascii_case(self) {
+            if column_name_eq(&info.name, self) {
                 return Ok(idx);
             }
         }
+        let upper = self.to_uppercase();
+        if let Some(info) = column_info
+            .iter()
+            .find(|info| info.name_truncated && upper.starts_with(&info.name.to_uppercase()))
+        {
+            return Err(Error::invalid_column_name(format!(
+                "{} (column name {:?} was truncated by the database in the middle of a multi-byte character. Use a shorter column alias.)",
+                self, info.name
+            )));
+        }
         Err(Error::invalid_column_name(*self))
     }
 }
 
+// Converts a column name got from the database to String.
+//
+// The database truncates column names longer than the maximum identifier
+// length (30 bytes before Oracle 12.2, 128 bytes since then) such as names
+// derived from expressions. It may split a multi-byte character. The split
+// character is dropped and the second value is true in that case.
+fn column_name(bytes: &[u8]) -> (String, bool) {
+    match str::from_utf8(bytes) {
+        Ok(name) => (name.to_string(), false),
+        Err(err) if err.error_len().is_none() => (
+            String::from_utf8_lossy(&bytes[..err.valid_up_to()]).into_owned(),
+            true,
+        ),
+        Err(_) => (String::from_utf8_lossy(bytes).into_owned(), false),
+    }
+}
+
+// Compares column names case-insensitively.
+// Non-ASCII characters are also compared case-insensitively because
+// the database converts them to upper case in unquoted identifiers.
+fn column_name_eq(name: &str, other: &str) -> bool {
+    name.eq_ignore_ascii_case(other)
+        || (!(name.is_ascii() && other.is_ascii()) && name.to_uppercase() == other.to_uppercase())
+}
+
+// 64-bit FNV-1a hash, which doesn't depend on the rust version unlike
+// `std::collections::hash_map::DefaultHasher`.
+fn sql_hash(sql: &str) -> u64 {
+    sql.bytes().fold(0xcbf29ce484222325, |hash, b| {
+        (hash ^ u64::from(b)).wrapping_mul(0x100000001b3)
+    })
+}
+
+// Makes the DML error logging clause appended to INSERT, UPDATE, MERGE and DELETE statements.
+pub(crate) fn log_errors_clause(table: &str, tag: Option<&str>) -> Result<String> {
+    check_identifier(table, true)?;
+    Ok(match tag {
+        Some(tag) => format!(
+            " LOG ERRORS INTO {} ('{}') REJECT LIMIT UNLIMITED",
+            table,
+            tag.replace('\'', "''")
+        ),
+        None => format!(" LOG ERRORS INTO {} REJECT LIMIT UNLIMITED", table),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::test_util;
+    use crate::ErrorKind;
 
     #[test]
     fn column_info() -> Result<()> {
@@ -1505,4 +2611,65 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn log_errors_clause_sql() {
+        assert_eq!(
+            log_errors_clause("err$_emp", None).unwrap(),
+            " LOG ERRORS INTO err$_emp REJECT LIMIT UNLIMITED"
+        );
+        assert_eq!(
+            log_errors_clause("scott.err$_emp", Some("it's")).unwrap(),
+            " LOG ERRORS INTO scott.err$_emp ('it''s') REJECT LIMIT UNLIMITED"
+        );
+        assert!(log_errors_clause("emp; drop table emp", None).is_err());
+    }
+
+    #[test]
+    fn column_name_from_bytes() {
+        assert_eq!(column_name(b"EMPNO"), ("EMPNO".to_string(), false));
+        // a 128-byte name consisting of 3-byte characters and two ASCII characters
+        let long_name = "\u{3042}".repeat(42) + "AB";
+        assert_eq!(long_name.len(), 128);
+        assert_eq!(
+            column_name(long_name.as_bytes()),
+            (long_name.clone(), false)
+        );
+        // truncated to 31 bytes in the middle of the 11th character
+        let truncated = &"\u{3042}".repeat(11).into_bytes()[..31];
+        assert_eq!(column_name(truncated), ("\u{3042}".repeat(10), true));
+        // invalid bytes in the middle are replaced
+        assert_eq!(column_name(b"A\xffB"), ("A\u{FFFD}B".to_string(), false));
+    }
+
+    #[test]
+    fn column_index_by_name() {
+        let mut truncated =
+            ColumnInfo::from_parts("\u{3042}".repeat(10), OracleType::Number(0, -127), true);
+        truncated.name_truncated = true;
+        let column_info = [
+            ColumnInfo::from_parts("EMPNO".into(), OracleType::Number(4, 0), false),
+            ColumnInfo::from_parts("CAF\u{C9}".into(), OracleType::Varchar2(10), true),
+            truncated,
+        ];
+        let idx = |name: &str| ColumnIndex::idx(&name, &column_info);
+        assert_eq!(idx("empno").unwrap(), 0);
+        assert_eq!(idx("caf\u{e9}").unwrap(), 1);
+        assert_eq!(idx(&"\u{3042}".repeat(10)).unwrap(), 2);
+        let err = idx(&"\u{3042}".repeat(11)).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidColumnName);
+        assert!(err.to_string().contains("truncated"), "{}", err);
+        let err = idx("ename").unwrap_err();
+        assert_eq!(err.to_string(), "invalid column name ename");
+    }
+
+    #[test]
+    fn sql_hash_is_fnv1a() {
+        assert_eq!(sql_hash(""), 0xcbf29ce484222325);
+        assert_eq!(sql_hash("a"), 0xaf63dc4c8601ec8c);
+        assert_ne!(
+            sql_hash("select 1 from dual"),
+            sql_hash("select 2 from dual")
+        );
+    }
 }
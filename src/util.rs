@@ -28,6 +28,9 @@ use std::str;
 pub mod os;
 pub use os::*; // import all os-depend functions.
 
+mod sha256;
+pub use sha256::Sha256;
+
 pub struct Scanner<'a> {
     chars: str::Chars<'a>,
     char: Option<char>,
@@ -144,6 +144,86 @@ impl FromStr for Version {
     }
 }
 
+/// Versions of underlying libraries and features supported by them
+///
+/// This is a return value of [`client_info()`]. Use it to enable features
+/// depending on the Oracle client version in advance instead of handling errors
+/// returned when they are unavailable. Note that some features also require
+/// a server version. Use [`Connection::server_version`] to check it.
+///
+/// [`Connection::server_version`]: crate::Connection::server_version
+#[non_exhaustive]
+#[derive(Debug, PartialEq, Eq)]
+pub struct ClientInfo {
+    /// The version of ODPI-C bundled in this crate.
+    /// The fourth and fifth parts are always zero.
+    pub odpi_version: Version,
+
+    /// The version of Oracle client libraries in use
+    pub client_version: Version,
+
+    /// Oracle client libraries support native JSON data type. (21 or later)
+    pub json: bool,
+
+    /// Oracle client libraries support VECTOR data type. (23.4 or later)
+    pub vector: bool,
+
+    /// Oracle client libraries support token-based authentication. (19.14 or later, or 21.5 or later)
+    pub token_auth: bool,
+
+    /// Pipelining, which sends multiple requests without waiting for responses.
+    /// This is always `false` because ODPI-C doesn't support it.
+    pub pipelining: bool,
+}
+
+impl ClientInfo {
+    fn new(client_version: Version) -> ClientInfo {
+        let json = client_version >= Version::new(21, 0, 0, 0, 0);
+        let vector = client_version >= Version::new(23, 4, 0, 0, 0);
+        let token_auth = match client_version.major() {
+            19 => client_version.minor() >= 14,
+            20 => false,
+            21 => client_version.minor() >= 5,
+            major => major > 21,
+        };
+        ClientInfo {
+            odpi_version: Version::new(
+                DPI_MAJOR_VERSION as i32,
+                DPI_MINOR_VERSION as i32,
+                DPI_PATCH_LEVEL as i32,
+                0,
+                0,
+            ),
+            client_version,
+            json,
+            vector,
+            token_auth,
+            pipelining: false,
+        }
+    }
+}
+
+/// Returns versions of ODPI-C and Oracle client libraries and features
+/// supported by them
+///
+/// This loads Oracle client libraries if they have not been loaded yet.
+///
+/// # Examples
+///
+/// ```
+/// # use oracle::Error;
+/// let info = oracle::client_info()?;
+/// println!("ODPI-C version: {}", info.odpi_version);
+/// println!("Oracle client version: {}", info.client_version);
+/// if info.vector {
+///     println!("VECTOR data type is available.");
+/// }
+/// # Ok::<(), Error>(())
+/// ```
+pub fn client_info() -> Result<ClientInfo> {
+    Ok(ClientInfo::new(Version::client()?))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -178,6 +258,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn client_info_features() {
+        let info = ClientInfo::new(Version::new(19, 13, 0, 0, 0));
+        assert_eq!(info.odpi_version.major(), DPI_MAJOR_VERSION as i32);
+        assert!(!info.json && !info.vector && !info.token_auth && !info.pipelining);
+        assert!(ClientInfo::new(Version::new(19, 14, 0, 0, 0)).token_auth);
+        assert!(!ClientInfo::new(Version::new(21, 4, 0, 0, 0)).token_auth);
+        let info = ClientInfo::new(Version::new(21, 5, 0, 0, 0));
+        assert!(info.json && !info.vector && info.token_auth);
+        let info = ClientInfo::new(Version::new(23, 4, 0, 24, 5));
+        assert!(info.json && info.vector && info.token_auth);
+    }
+
     #[test]
     fn client_version() {
         let ver = Version::client().unwrap();
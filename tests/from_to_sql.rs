@@ -463,6 +463,35 @@ fn numeric_to_sql() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn int128_from_to_sql() -> Result<()> {
+    let conn = common::connect()?;
+    let max38 = "99999999999999999999999999999999999999";
+
+    let row = conn.query_row(&format!("select {0}, -{0} from dual", max38), &[])?;
+    assert_eq!(row.get::<_, i128>(0)?, max38.parse::<i128>().unwrap());
+    assert_eq!(row.get::<_, i128>(1)?, -max38.parse::<i128>().unwrap());
+    assert_eq!(row.get::<_, u128>(0)?, max38.parse::<u128>().unwrap());
+    let err = row.get::<_, u128>(1).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ParseError);
+
+    let row = conn.query_row("select 1e39 from dual", &[])?;
+    let err = row.get::<_, i128>(0).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ParseError);
+
+    test_to_sql!(&conn, &max38.parse::<u128>().unwrap(), "to_char(:1)", max38);
+    test_to_sql!(
+        &conn,
+        &-max38.parse::<i128>().unwrap(),
+        "to_char(:1)",
+        &format!("-{}", max38)
+    );
+    let mut stmt = conn.statement("select to_char(:1) from dual").build()?;
+    let err = stmt.query_row(&[&i128::MAX]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::OutOfRange);
+    Ok(())
+}
+
 #[test]
 fn raw_from_to_sql() -> Result<()> {
     let conn = common::connect()?;
@@ -476,10 +505,57 @@ fn raw_from_to_sql() -> Result<()> {
         &raw
     );
 
+    test_from_sql!(
+        &conn,
+        &format!("hextoraw('{}')", hex),
+        &OracleType::Raw(10),
+        b"0123456789"
+    );
+    let row = conn.query_row(&format!("select hextoraw('{}') from dual", hex), &[])?;
+    let err = row.get_as::<[u8; 8]>().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::OutOfRange);
+
     test_to_sql!(&conn, &raw, "rawtohex(:1)", hex);
     Ok(())
 }
 
+#[test]
+fn char_from_sql() -> Result<()> {
+    let conn = common::connect()?;
+
+    test_from_sql!(&conn, "cast('A' as char(1))", &OracleType::Char(1), &'A');
+    test_from_sql!(
+        &conn,
+        "cast(unistr('\\00e9') as nchar(1))",
+        &OracleType::NChar(1),
+        &'\u{e9}'
+    );
+
+    let row = conn.query_row("select 'AB', cast(null as char(1)) from dual", &[])?;
+    let err = row.get::<_, char>(0).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::OutOfRange);
+    let err = row.get::<_, char>(1).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::NullValue);
+    Ok(())
+}
+
+#[test]
+fn nonzero_from_sql() -> Result<()> {
+    use std::num::{NonZeroI64, NonZeroU32};
+    let conn = common::connect()?;
+
+    let row = conn.query_row("select 10, -10, 0, null from dual", &[])?;
+    assert_eq!(row.get::<_, NonZeroU32>(0)?.get(), 10);
+    assert_eq!(row.get::<_, NonZeroI64>(1)?.get(), -10);
+    let err = row.get::<_, NonZeroI64>(2).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::OutOfRange);
+    let err = row.get::<_, NonZeroI64>(3).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::NullValue);
+    let err = row.get::<_, NonZeroU32>(1).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ParseError);
+    Ok(())
+}
+
 //
 // Timestamp
 //
@@ -130,6 +130,10 @@ fn collection_udt_nestedarray() -> Result<()> {
     obj2.push(&subobj)?; // When obj2 is changed,
     assert_eq!(obj.size()?, 2); // obj is also changed.
     assert_eq!(obj2.size()?, 2);
+    let mut obj3 = obj.try_clone()?; // deep copy
+    obj3.push(&subobj)?; // When obj3 is changed,
+    assert_eq!(obj.size()?, 2); // obj isn't changed.
+    assert_eq!(obj3.size()?, 3);
     Ok(())
 }
 
@@ -223,6 +227,52 @@ fn udt_object() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn udt_object_sql_values() -> Result<()> {
+    let conn = common::connect()?;
+    let objtype = conn.object_type("UDT_OBJECT")?;
+    let subobjtype = conn.object_type("UDT_SUBOBJECT")?;
+    let mut obj = objtype.new_object()?;
+    let mut subobj = subobjtype.new_object()?;
+
+    subobj.set("SUBNUMBERVALUE", &10)?;
+    obj.set("NUMBERVALUE", &1)?;
+    obj.set("STRINGVALUE", &"STRVAL:1")?;
+    obj.set("DATEVALUE", &Timestamp::new(2012, 3, 4, 5, 6, 7, 0)?)?;
+    obj.set("SUBOBJECTVALUE", &subobj)?;
+
+    assert_eq!(obj.get_sql_value("NUMBERVALUE")?.get::<i32>()?, 1);
+    assert_eq!(
+        obj.get_sql_value("STRINGVALUE")?.get::<String>()?,
+        "STRVAL:1"
+    );
+    assert_eq!(
+        obj.get_sql_value("DATEVALUE")?.get::<Timestamp>()?,
+        Timestamp::new(2012, 3, 4, 5, 6, 7, 0)?
+    );
+    assert!(obj.get_sql_value("FIXEDCHARVALUE")?.is_null()?);
+    assert_eq!(
+        obj.get_sql_value("SUBOBJECTVALUE")?
+            .get::<Object>()?
+            .get::<i32>("SUBNUMBERVALUE")?,
+        10
+    );
+
+    let attrs = subobj
+        .attributes()
+        .map(|attr| attr.map(|(name, val)| (name.to_string(), val.to_string())))
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(
+        attrs,
+        [
+            ("SUBNUMBERVALUE".to_string(), "10".to_string()),
+            ("SUBSTRINGVALUE".to_string(), "NULL".to_string()),
+        ]
+    );
+    assert_eq!(obj.attributes().len(), objtype.num_attributes());
+    Ok(())
+}
+
 #[test]
 fn udt_stringlist() -> Result<()> {
     let conn = common::connect()?;
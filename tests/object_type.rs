@@ -111,6 +111,7 @@ fn udt_object_in_query() -> Result<()> {
         &OracleType::Object(ref objtype) => assert_udt_object(objtype),
         _ => panic!(),
     }
+    assert_udt_object(rows.column_info()[0].object_type().unwrap());
     Ok(())
 }
 
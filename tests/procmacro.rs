@@ -14,3 +14,103 @@ fn procmacro_ok_shadow() {
     #[derive(Debug, RowValue)]
     struct Foo {}
 }
+
+#[test]
+fn procmacro_flatten() {
+    #[allow(dead_code)]
+    #[derive(Debug, RowValue)]
+    struct Inner {
+        a: i32,
+        b: Option<String>,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Debug, RowValue)]
+    struct Outer {
+        id: i32,
+        #[row_value(flatten)]
+        inner: Inner,
+        #[row_value(flatten)]
+        optional_inner: Option<Inner>,
+        #[row_value(flatten)]
+        tuple: Option<(i32, String)>,
+    }
+}
+
+#[test]
+fn procmacro_default_and_skip() {
+    fn zero_if_negative(row: &oracle::Row, column_name: &str) -> oracle::Result<u32> {
+        let val: i64 = row.get(column_name)?;
+        Ok(if val < 0 { 0 } else { val as u32 })
+    }
+
+    #[allow(dead_code)]
+    #[derive(Debug, RowValue)]
+    struct Foo {
+        id: i32,
+        #[row_value(default)]
+        legacy_flag: bool,
+        #[row_value(default, rename = "OLD_NAME")]
+        name: String,
+        #[row_value(default, with = "zero_if_negative")]
+        count: u32,
+        #[row_value(skip)]
+        not_a_column: Vec<u8>,
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn procmacro_json() {
+    #[allow(dead_code)]
+    #[derive(Debug, RowValue)]
+    struct Foo {
+        id: i32,
+        #[row_value(json)]
+        tags: Vec<String>,
+        #[row_value(json, rename = "ATTRS_JSON")]
+        attrs: Option<serde_json::Value>,
+        #[row_value(json, default)]
+        scores: Vec<f64>,
+    }
+}
+
+#[test]
+fn procmacro_sql_enum() {
+    use oracle::sql_type::SqlEnum;
+
+    #[allow(dead_code)]
+    #[derive(Debug, PartialEq, SqlEnum)]
+    enum Gender {
+        #[sql_enum(value = "M")]
+        Male,
+        #[sql_enum(value = "F")]
+        Female,
+        Other,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Debug, PartialEq, SqlEnum)]
+    enum Status {
+        #[sql_enum(value = 1)]
+        Active,
+        #[sql_enum(value = 0)]
+        Inactive,
+    }
+}
+
+#[test]
+fn procmacro_sql_enum_negative_value() {
+    use oracle::sql_type::SqlEnum;
+
+    #[allow(dead_code)]
+    #[derive(Debug, PartialEq, SqlEnum)]
+    enum Sign {
+        #[sql_enum(value = -1)]
+        Negative,
+        #[sql_enum(value = 0)]
+        Zero,
+        #[sql_enum(value = 1)]
+        Positive,
+    }
+}
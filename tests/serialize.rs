@@ -0,0 +1,39 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2025 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+#![cfg(feature = "serde")]
+mod common;
+
+use oracle::Result;
+
+#[test]
+fn serialize_rows_to_json() -> Result<()> {
+    let conn = common::connect()?;
+    let sql = "select IntCol, StringCol, NullableCol, to_date('2012-03-04 05:06:07', 'yyyy-mm-dd hh24:mi:ss') DateCol \
+               from TestStrings where IntCol between 1 and 2 order by IntCol";
+
+    let row = conn.query_row(sql, &[])?;
+    assert_eq!(
+        serde_json::to_string(&row).unwrap(),
+        r#"{"INTCOL":1,"STRINGCOL":"String 1","NULLABLECOL":"Nullable 1","DATECOL":"2012-03-04 05:06:07"}"#
+    );
+
+    let rows = conn.query(sql, &[])?;
+    assert_eq!(
+        serde_json::to_string(&rows.into_serializable()).unwrap(),
+        r#"[{"INTCOL":1,"STRINGCOL":"String 1","NULLABLECOL":"Nullable 1","DATECOL":"2012-03-04 05:06:07"},{"INTCOL":2,"STRINGCOL":"String 2","NULLABLECOL":null,"DATECOL":"2012-03-04 05:06:07"}]"#
+    );
+    Ok(())
+}
@@ -15,8 +15,8 @@
 
 mod common;
 
-use oracle::sql_type::{IntervalDS, Timestamp};
-use oracle::{Result, StatementType};
+use oracle::sql_type::{IntervalDS, RefCursor, Timestamp};
+use oracle::{ErrorKind, Result, StatementType};
 use std::{thread, time};
 
 #[test]
@@ -455,3 +455,82 @@ fn iterate_rows_by_ref_and_check_fused() -> Result<()> {
     assert!(rows.next().is_none());
     Ok(())
 }
+
+#[test]
+fn nested_cursor_columns() -> Result<()> {
+    let conn = common::connect()?;
+    let sql = "select IntCol, cursor(select IntCol * 10 from TestNumbers d where d.IntCol <= m.IntCol order by IntCol) from TestNumbers m order by IntCol";
+    // Fetch all parent rows before reading nested cursors. Nested cursors
+    // must be kept valid while the parent fetches next rows.
+    let mut stmt = conn.statement(sql).fetch_array_size(3).build()?;
+    let rows = stmt.query_collect::<(i32, RefCursor)>(&[])?;
+    assert_eq!(rows.len(), 10);
+    for (int_col, mut cursor) in rows {
+        let values = cursor.query_as::<i32>()?.collect::<Result<Vec<_>>>()?;
+        assert_eq!(values, (1..=int_col).map(|n| n * 10).collect::<Vec<_>>());
+    }
+    Ok(())
+}
+
+#[test]
+fn query_row_opt() -> Result<()> {
+    let conn = common::connect()?;
+    let mut stmt = conn
+        .statement("select IntCol from TestNumbers where IntCol between :1 and :2")
+        .build()?;
+    assert_eq!(stmt.query_row_as_opt::<i32>(&[&3, &3])?, Some(3));
+    assert_eq!(stmt.query_row_as_opt::<i32>(&[&11, &20])?, None);
+    let err = stmt.query_row_as_opt::<i32>(&[&1, &2]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidOperation);
+    let row = stmt.query_row_opt(&[&5, &5])?.unwrap();
+    assert_eq!(row.get::<_, i32>(0)?, 5);
+    assert!(conn
+        .query_row_opt("select * from TestNumbers where 1 = 0", &[])?
+        .is_none());
+    Ok(())
+}
+
+#[test]
+fn query_collect() -> Result<()> {
+    let conn = common::connect()?;
+    let sql = "select IntCol from TestNumbers where IntCol <= :1 order by IntCol";
+
+    let rows = conn.query_collect::<usize>(sql, &[&3])?;
+    assert_eq!(rows, vec![1, 2, 3]);
+
+    let mut stmt = conn.statement(sql).prefetch_rows(10).build()?;
+    assert_eq!(stmt.query_collect::<usize>(&[&0])?, Vec::<usize>::new());
+    assert_eq!(stmt.query_collect::<usize>(&[&10])?.len(), 10);
+
+    let err = conn
+        .query_collect::<usize>("select -IntCol from TestNumbers", &[])
+        .unwrap_err();
+    assert_eq!(err.kind(), oracle::ErrorKind::ParseError);
+    Ok(())
+}
+
+#[test]
+fn hierarchical_query_pseudo_columns() -> Result<()> {
+    let conn = common::connect()?;
+    let sql = "select level, connect_by_isleaf, sys_connect_by_path(IntCol, '/'), \
+                      sys_connect_by_path(rpad('x', 300, 'x'), '/') \
+                 from TestNumbers \
+                start with IntCol = 1 \
+              connect by IntCol = prior IntCol + 1 \
+                order siblings by IntCol";
+    let rows = conn.query_as::<(u32, i32, String, String)>(sql, &[])?;
+    let mut path = String::new();
+    let mut n = 0;
+    for row_result in rows {
+        let (level, is_leaf, int_path, long_path) = row_result?;
+        n += 1;
+        path.push_str(&format!("/{}", n));
+        assert_eq!(level, n);
+        assert_eq!(is_leaf, if n == 10 { 1 } else { 0 });
+        assert_eq!(int_path, path);
+        // The path isn't truncated by the column size.
+        assert_eq!(long_path.len(), 301 * n as usize);
+    }
+    assert_eq!(n, 10);
+    Ok(())
+}